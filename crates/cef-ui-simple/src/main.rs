@@ -2,10 +2,11 @@ use anyhow::Result;
 use cef_ui::{
     App, AppCallbacks, Browser, BrowserHost, BrowserProcessHandler, BrowserSettings, Client,
     ClientCallbacks, CommandLine, Context, ContextMenuHandler, ContextMenuHandlerCallbacks,
-    ContextMenuParams, DictionaryValue, EventFlags, Frame, KeyboardHandler, LifeSpanHandler,
-    LifeSpanHandlerCallbacks, LogSeverity, MainArgs, MenuCommandId, MenuModel, Point,
-    PopupFeatures, QuickMenuEditStateFlags, RenderHandler, RunContextMenuCallback,
-    RunQuickMenuCallback, Settings, Size, WindowInfo, WindowOpenDisposition
+    ContextMenuParams, DictionaryValue, EventFlags, Frame, FrameHandler, KeyboardHandler,
+    LifeSpanHandler, LifeSpanHandlerCallbacks, LogSeverity, MainArgs, MenuCommandId, MenuModel,
+    Point, PopupFeatures, ProcessId, ProcessMessage, QuickMenuEditStateFlags, RenderHandler,
+    RenderProcessHandler, RequestHandler, RunContextMenuCallback, RunQuickMenuCallback, Settings,
+    Size, WindowInfo, WindowOpenDisposition
 };
 use cef_ui_sys::cef_quit_message_loop;
 use std::{fs::create_dir_all, path::PathBuf, process::exit};
@@ -137,6 +138,10 @@ impl ClientCallbacks for MyClientCallbacks {
         Some(ContextMenuHandler::new(MyContextMenuHandler {}))
     }
 
+    fn get_frame_handler(&mut self) -> Option<FrameHandler> {
+        None
+    }
+
     fn get_keyboard_handler(&mut self) -> Option<KeyboardHandler> {
         None
     }
@@ -148,6 +153,20 @@ impl ClientCallbacks for MyClientCallbacks {
     fn get_render_handler(&mut self) -> Option<RenderHandler> {
         None
     }
+
+    fn get_request_handler(&mut self) -> Option<RequestHandler> {
+        None
+    }
+
+    fn on_process_message_received(
+        &mut self,
+        _browser: Browser,
+        _frame: Frame,
+        _source_process: ProcessId,
+        _message: ProcessMessage
+    ) -> bool {
+        false
+    }
 }
 
 /// Application callbacks.
@@ -176,6 +195,10 @@ impl AppCallbacks for MyAppCallbacks {
     fn get_browser_process_handler(&mut self) -> Option<BrowserProcessHandler> {
         None
     }
+
+    fn get_render_process_handler(&mut self) -> Option<RenderProcessHandler> {
+        None
+    }
 }
 
 fn main() {
@@ -235,15 +258,16 @@ fn try_main() -> Result<()> {
     // The browser-specific client.
     let client = Client::new(MyClientCallbacks);
 
-    // Create a new browser.
-    BrowserHost::create_browser_sync(
+    // Create a new browser. The browser is created asynchronously
+    // on the UI thread, which works with every message loop mode.
+    BrowserHost::create_browser(
         &window_info,
         client,
         "https://www.google.com/",
         &browser_settings,
         None,
         None
-    );
+    )?;
 
     info!("Running CEF message loop.");
 
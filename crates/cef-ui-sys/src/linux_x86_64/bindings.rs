@@ -3532,30 +3532,26 @@ pub struct _cef_cursor_info_t {
     pub size:               cef_size_t
 }
 pub type cef_cursor_info_t = _cef_cursor_info_t;
-#[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_uri_unescape_rule_t {
-    UU_NONE = 0,
-    UU_NORMAL = 1,
-    UU_SPACES = 2,
-    UU_PATH_SEPARATORS = 4,
-    UU_URL_SPECIAL_CHARS_EXCEPT_PATH_SEPARATORS = 8,
-    UU_REPLACE_PLUS_WITH_SPACE = 16
-}
+pub const cef_uri_unescape_rule_t_UU_NONE: cef_uri_unescape_rule_t = 0;
+pub const cef_uri_unescape_rule_t_UU_NORMAL: cef_uri_unescape_rule_t = 1;
+pub const cef_uri_unescape_rule_t_UU_SPACES: cef_uri_unescape_rule_t = 2;
+pub const cef_uri_unescape_rule_t_UU_PATH_SEPARATORS: cef_uri_unescape_rule_t = 4;
+pub const cef_uri_unescape_rule_t_UU_URL_SPECIAL_CHARS_EXCEPT_PATH_SEPARATORS:
+    cef_uri_unescape_rule_t = 8;
+pub const cef_uri_unescape_rule_t_UU_REPLACE_PLUS_WITH_SPACE: cef_uri_unescape_rule_t = 16;
+pub type cef_uri_unescape_rule_t = ::std::os::raw::c_uint;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum cef_json_parser_options_t {
     JSON_PARSER_RFC = 0,
     JSON_PARSER_ALLOW_TRAILING_COMMAS = 1
 }
-#[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_json_writer_options_t {
-    JSON_WRITER_DEFAULT = 0,
-    JSON_WRITER_OMIT_BINARY_VALUES = 1,
-    JSON_WRITER_OMIT_DOUBLE_TYPE_PRESERVATION = 2,
-    JSON_WRITER_PRETTY_PRINT = 4
-}
+pub const cef_json_writer_options_t_JSON_WRITER_DEFAULT: cef_json_writer_options_t = 0;
+pub const cef_json_writer_options_t_JSON_WRITER_OMIT_BINARY_VALUES: cef_json_writer_options_t = 1;
+pub const cef_json_writer_options_t_JSON_WRITER_OMIT_DOUBLE_TYPE_PRESERVATION:
+    cef_json_writer_options_t = 2;
+pub const cef_json_writer_options_t_JSON_WRITER_PRETTY_PRINT: cef_json_writer_options_t = 4;
+pub type cef_json_writer_options_t = ::std::os::raw::c_uint;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum cef_pdf_print_margin_type_t {
@@ -1,14 +1,25 @@
+#[cfg(feature = "futures")]
+use crate::DevToolsMessageObserverCallbacks;
 use crate::{
-    free_cef_string, ref_counted_ptr, try_c, CefString, CefStringList, Client, Color, CommandId,
-    CompositionUnderline, DictionaryValue, DragData, DragOperations, Extension, Frame, KeyEvent,
-    MouseButtonType, MouseEvent, NativeWindowHandle, NavigationEntry, NavigationEntryVisitor,
-    PaintElementType, Point, Range, RequestContext, Size, State, TouchEvent, WindowInfo,
+    currently_on, free_cef_string, ref_counted_ptr, try_c, CefString, CefStringList, Client, Color,
+    CommandId, CompositionUnderline, DevToolsMessageObserver, DictionaryValue, DragData,
+    DragOperations, Extension, FfiError, Frame, KeyEvent, MouseButtonType, MouseEvent,
+    NativeWindowHandle, NavigationEntry, NavigationEntryVisitor, PaintElementType, Point, Range,
+    Registration, RequestContext, Size, State, ThreadId, TouchEvent, WindowInfo,
     WindowOpenDisposition, ZoomCommand
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use cef_ui_sys::{
-    cef_browser_host_create_browser_sync, cef_browser_host_t, cef_browser_settings_t,
-    cef_browser_t, cef_composition_underline_t, cef_point_t, cef_range_t, cef_string_t
+    cef_browser_host_create_browser, cef_browser_host_create_browser_sync, cef_browser_host_t,
+    cef_browser_settings_t, cef_browser_t, cef_composition_underline_t, cef_point_t, cef_range_t,
+    cef_string_t
+};
+#[cfg(feature = "futures")]
+use futures::channel::oneshot;
+#[cfg(feature = "futures")]
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc
 };
 use std::{
     ffi::{c_int, c_void},
@@ -466,7 +477,7 @@ impl BrowserHost {
     /// additional usage information. This function must be called on the browser
     /// process UI thread.
     pub fn try_close_browser(&self) -> Result<bool> {
-        try_c!(self, try_close_browser, {
+        try_c!(self, try_close_browser, ThreadId::Ui, {
             Ok(try_close_browser(self.as_ptr()) != 0)
         })
     }
@@ -522,7 +533,7 @@ impl BrowserHost {
     /// Returns true (1) if this browser can execute the specified zoom command.
     /// This function can only be called on the UI thread.
     pub fn can_zoom(&self, command: ZoomCommand) -> Result<bool> {
-        try_c!(self, can_zoom, {
+        try_c!(self, can_zoom, ThreadId::Ui, {
             Ok(can_zoom(self.as_ptr(), command.into()) != 0)
         })
     }
@@ -711,7 +722,7 @@ impl BrowserHost {
     /// Returns true (1) if this browser currently has an associated DevTools
     /// browser. Must be called on the browser process UI thread.
     pub fn has_dev_tools(&self) -> Result<bool> {
-        try_c!(self, has_dev_tools, {
+        try_c!(self, has_dev_tools, ThreadId::Ui, {
             Ok(has_dev_tools(self.as_ptr()) != 0)
         })
     }
@@ -773,7 +784,7 @@ impl BrowserHost {
         message_id: i32,
         method: &str,
         params: Option<DictionaryValue>
-    ) -> Result<bool> {
+    ) -> Result<i32> {
         try_c!(self, execute_dev_tools_method, {
             let method = CefString::new(method);
 
@@ -784,21 +795,55 @@ impl BrowserHost {
                 params
                     .map(|params| params.into_raw())
                     .unwrap_or(null_mut())
-            ) != 0)
+            ))
         })
     }
 
-    // TODO: Fix this!
+    /// Same as execute_dev_tools_method, but resolves to the |success| flag and
+    /// the UTF8-encoded JSON "result" or "error" dictionary value once the
+    /// method result with the assigned message ID arrives. Must be called on
+    /// the UI thread.
+    #[cfg(feature = "futures")]
+    pub async fn execute_dev_tools_method_async(
+        &self,
+        message_id: i32,
+        method: &str,
+        params: Option<DictionaryValue>
+    ) -> Result<(bool, Vec<u8>)> {
+        let (sender, receiver) = oneshot::channel();
+        let expected_id = Arc::new(AtomicI32::new(message_id));
+        let _registration = self
+            .add_dev_tools_message_observer(DevToolsMessageObserver::new(
+                DevToolsMethodResultSender {
+                    message_id: expected_id.clone(),
+                    sender:     Some(sender)
+                }
+            ))?
+            .ok_or_else(|| anyhow!("Failed to add a DevTools message observer."))?;
+
+        match self.execute_dev_tools_method(message_id, method, params)? {
+            0 => return Err(anyhow!("Failed to execute DevTools method {}.", method)),
+            message_id => expected_id.store(message_id, Ordering::SeqCst)
+        }
 
-    // ///
-    // /// Add an observer for DevTools protocol messages (function results and
-    // /// events). The observer will remain registered until the returned
-    // /// Registration object is destroyed. See the SendDevToolsMessage
-    // /// documentation for additional usage information.
-    // ///
-    // struct _cef_registration_t*(CEF_CALLBACK* add_dev_tools_message_observer)(
-    // struct _cef_browser_host_t* self,
-    // struct _cef_dev_tools_message_observer_t* observer);
+        Ok(receiver.await?)
+    }
+
+    /// Add an observer for DevTools protocol messages (function results and
+    /// events). The observer will remain registered until the returned
+    /// Registration object is destroyed. See the SendDevToolsMessage
+    /// documentation for additional usage information.
+    pub fn add_dev_tools_message_observer(
+        &self,
+        observer: DevToolsMessageObserver
+    ) -> Result<Option<Registration>> {
+        try_c!(self, add_dev_tools_message_observer, {
+            Ok(Registration::from_ptr(add_dev_tools_message_observer(
+                self.as_ptr(),
+                observer.into_raw()
+            )))
+        })
+    }
 
     /// Retrieve a snapshot of current navigation entries as values sent to the
     /// specified visitor. If |current_only| is true (1) only the current
@@ -979,7 +1024,7 @@ impl BrowserHost {
     /// requested rate. The minimum value is 1 and the maximum value is 60
     /// (default 30). This function can only be called on the UI thread.
     pub fn get_windowless_frame_rate(&self) -> Result<i32> {
-        try_c!(self, get_windowless_frame_rate, {
+        try_c!(self, get_windowless_frame_rate, ThreadId::Ui, {
             Ok(get_windowless_frame_rate(self.as_ptr()))
         })
     }
@@ -1217,7 +1262,7 @@ impl BrowserHost {
     /// Returns the current visible navigation entry for this browser. This
     /// function can only be called on the UI thread.
     pub fn get_visible_navigation_entry(&self) -> Result<NavigationEntry> {
-        try_c!(self, get_visible_navigation_entry, {
+        try_c!(self, get_visible_navigation_entry, ThreadId::Ui, {
             Ok(NavigationEntry::from_ptr_unchecked(
                 get_visible_navigation_entry(self.as_ptr())
             ))
@@ -1302,7 +1347,7 @@ impl BrowserHost {
     /// Returns true (1) if the browser's audio is muted.  This function can only
     /// be called on the UI thread.
     pub fn is_audio_muted(&self) -> Result<bool> {
-        try_c!(self, is_audio_muted, {
+        try_c!(self, is_audio_muted, ThreadId::Ui, {
             Ok(is_audio_muted(self.as_ptr()) != 0)
         })
     }
@@ -1313,7 +1358,7 @@ impl BrowserHost {
     /// ::backdrop pseudo-element and :fullscreen pseudo-structure. This function
     /// can only be called on the UI thread.
     pub fn is_fullscreen(&self) -> Result<bool> {
-        try_c!(self, is_fullscreen, {
+        try_c!(self, is_fullscreen, ThreadId::Ui, {
             Ok(is_fullscreen(self.as_ptr()) != 0)
         })
     }
@@ -1337,7 +1382,7 @@ impl BrowserHost {
     /// |command_id| can be found in the cef_command_ids.h file. This function can
     /// only be called on the UI thread. Only used with the Chrome runtime.
     pub fn can_execute_chrome_command(&self, command_id: CommandId) -> Result<bool> {
-        try_c!(self, can_execute_chrome_command, {
+        try_c!(self, can_execute_chrome_command, ThreadId::Ui, {
             Ok(can_execute_chrome_command(self.as_ptr(), command_id.into()) != 0)
         })
     }
@@ -1359,25 +1404,46 @@ impl BrowserHost {
         })
     }
 
-    // TODO: Fix this!
+    /// Create a new browser using the window parameters specified by |windowInfo|.
+    /// All values will be copied internally and the actual window (if any) will be
+    /// created on the UI thread. If |request_context| is NULL the global request
+    /// context will be used. This function can be called on any browser process
+    /// thread and will not block. The optional |extra_info| parameter provides an
+    /// opportunity to specify extra information specific to the created browser
+    /// that will be passed to cef_render_process_handler_t::on_browser_created() in
+    /// the render process.
+    pub fn create_browser(
+        window_info: &WindowInfo,
+        client: Client,
+        url: &str,
+        settings: &BrowserSettings,
+        extra_info: Option<DictionaryValue>,
+        request_context: Option<RequestContext>
+    ) -> Result<()> {
+        let created = unsafe {
+            let url = CefString::new(url);
+            let extra_info = extra_info
+                .map(|extra_info| extra_info.into_raw())
+                .unwrap_or_else(null_mut);
+            let request_context = request_context
+                .map(|request_context| request_context.into_raw())
+                .unwrap_or_else(null_mut);
 
-    // ///
-    // /// Create a new browser using the window parameters specified by |windowInfo|.
-    // /// All values will be copied internally and the actual window (if any) will be
-    // /// created on the UI thread. If |request_context| is NULL the global request
-    // /// context will be used. This function can be called on any browser process
-    // /// thread and will not block. The optional |extra_info| parameter provides an
-    // /// opportunity to specify extra information specific to the created browser
-    // /// that will be passed to cef_render_process_handler_t::on_browser_created() in
-    // /// the render process.
-    // ///
-    // CEF_EXPORT int cef_browser_host_create_browser(
-    // const cef_window_info_t* windowInfo,
-    // struct _cef_client_t* client,
-    // const cef_string_t* url,
-    // const struct _cef_browser_settings_t* settings,
-    // struct _cef_dictionary_value_t* extra_info,
-    // struct _cef_request_context_t* request_context);
+            cef_browser_host_create_browser(
+                window_info.as_raw(),
+                client.into_raw(),
+                url.as_ptr(),
+                settings.as_raw(),
+                extra_info,
+                request_context
+            )
+        };
+
+        match created {
+            0 => Err(anyhow!("Failed to create browser.")),
+            _ => Ok(())
+        }
+    }
 
     /// Create a new browser using the window parameters specified by |windowInfo|.
     /// If |request_context| is NULL the global request context will be used. This
@@ -1385,6 +1451,10 @@ impl BrowserHost {
     /// |extra_info| parameter provides an opportunity to specify extra information
     /// specific to the created browser that will be passed to
     /// cef_render_process_handler_t::on_browser_created() in the render process.
+    ///
+    /// Returns an error when called from any other thread, including the main
+    /// application thread when using a multi-threaded message loop, in every
+    /// build. Use create_browser() there instead.
     pub fn create_browser_sync(
         window_info: &WindowInfo,
         client: Client,
@@ -1392,7 +1462,14 @@ impl BrowserHost {
         settings: &BrowserSettings,
         extra_info: Option<DictionaryValue>,
         request_context: Option<RequestContext>
-    ) -> Browser {
+    ) -> Result<Option<Browser>> {
+        if !currently_on(ThreadId::Ui) {
+            return Err(anyhow!(FfiError::WrongThread(
+                "create_browser_sync",
+                ThreadId::Ui
+            )));
+        }
+
         unsafe {
             let url = CefString::new(url);
             let extra_info = extra_info
@@ -1402,14 +1479,56 @@ impl BrowserHost {
                 .map(|request_context| request_context.into_raw())
                 .unwrap_or_else(null_mut);
 
-            Browser::from_ptr_unchecked(cef_browser_host_create_browser_sync(
+            Ok(Browser::from_ptr(cef_browser_host_create_browser_sync(
                 window_info.as_raw(),
                 client.into_raw(),
                 url.as_ptr(),
                 settings.as_raw(),
                 extra_info,
                 request_context
-            ))
+            )))
         }
     }
 }
+
+/// Sends the result of a single DevTools method call to a future.
+#[cfg(feature = "futures")]
+struct DevToolsMethodResultSender {
+    message_id: Arc<AtomicI32>,
+    sender:     Option<oneshot::Sender<(bool, Vec<u8>)>>
+}
+
+#[cfg(feature = "futures")]
+impl DevToolsMessageObserverCallbacks for DevToolsMethodResultSender {
+    fn on_dev_tools_message(&mut self, _browser: Browser, _message: &[u8]) -> bool {
+        false
+    }
+
+    fn on_dev_tools_method_result(
+        &mut self,
+        _browser: Browser,
+        message_id: i32,
+        success: bool,
+        result: &[u8]
+    ) {
+        if message_id
+            != self
+                .message_id
+                .load(Ordering::SeqCst)
+        {
+            return;
+        }
+
+        if let Some(sender) = self.sender.take() {
+            let _ = sender.send((success, result.to_vec()));
+        }
+    }
+
+    fn on_dev_tools_event(&mut self, _browser: Browser, _method: &str, _params: &[u8]) {}
+
+    fn on_dev_tools_agent_attached(&mut self, _browser: Browser) {}
+
+    fn on_dev_tools_agent_detached(&mut self, _browser: Browser) {
+        self.sender.take();
+    }
+}
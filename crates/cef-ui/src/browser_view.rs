@@ -0,0 +1,327 @@
+use crate::{
+    ref_counted_ptr, try_c, view_delegate, view_deref, Browser, BrowserSettings, CefString,
+    ChromeToolbarType, Client, DictionaryValue, GestureCommand, RefCountedPtr, RequestContext,
+    ThreadId, View, ViewDelegateCallbacks, ViewDelegateWrappable, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_browser_settings_t, cef_browser_t, cef_browser_view_create, cef_browser_view_delegate_t,
+    cef_browser_view_get_for_browser, cef_browser_view_t, cef_chrome_toolbar_type_t, cef_client_t,
+    cef_gesture_command_t
+};
+use std::{ffi::c_int, ptr::null_mut};
+
+/// Implement this structure to handle BrowserView events. The functions of
+/// this structure will be called on the browser process UI thread unless
+/// otherwise indicated.
+pub trait BrowserViewDelegateCallbacks: ViewDelegateCallbacks {
+    /// Called when |browser| associated with |browser_view| is created. This
+    /// function will be called after cef_life_span_handler_t::on_after_created()
+    /// is called for |browser| and before on_popup_browser_view_created() is
+    /// called for |browser|'s parent delegate if |browser| is a popup.
+    fn on_browser_created(&mut self, browser_view: BrowserView, browser: Browser);
+
+    /// Called when |browser| associated with |browser_view| is destroyed. Release
+    /// all references to |browser| and do not attempt to execute any functions on
+    /// |browser| after this callback returns. This function will be called before
+    /// cef_life_span_handler_t::on_before_close() is called for |browser|.
+    fn on_browser_destroyed(&mut self, browser_view: BrowserView, browser: Browser);
+
+    /// Called before a new popup BrowserView is created. The popup originated
+    /// from |browser_view|. |settings| and |client| are the values returned from
+    /// cef_life_span_handler_t::on_before_popup(). |is_devtools| will be true (1)
+    /// if the popup will be a DevTools browser. Return the delegate that will be
+    /// used for the new popup BrowserView.
+    fn get_delegate_for_popup_browser_view(
+        &mut self,
+        browser_view: BrowserView,
+        settings: &BrowserSettings,
+        client: Client,
+        is_devtools: bool
+    ) -> Option<BrowserViewDelegate>;
+
+    /// Called after |popup_browser_view| is created. This function will be
+    /// called after cef_life_span_handler_t::on_after_created() and
+    /// on_browser_created() are called for the new popup browser. The popup
+    /// originated from |browser_view|. |is_devtools| will be true (1) if the
+    /// popup is a DevTools browser. Optionally add |popup_browser_view| to the
+    /// views hierarchy yourself and return true (1). Otherwise return false (0)
+    /// and a default cef_window_t will be created for the popup.
+    fn on_popup_browser_view_created(
+        &mut self,
+        browser_view: BrowserView,
+        popup_browser_view: BrowserView,
+        is_devtools: bool
+    ) -> bool;
+
+    /// Returns the Chrome toolbar type that will be available via
+    /// cef_browser_view_t::get_chrome_toolbar(). See that function for related
+    /// documentation.
+    fn get_chrome_toolbar_type(&mut self, browser_view: BrowserView) -> ChromeToolbarType;
+
+    /// Return true (1) to create frameless windows for Document picture-in-
+    /// picture popups. Content in frameless windows should specify draggable
+    /// regions using "-webkit-app-region: drag" CSS.
+    fn use_frameless_window_for_picture_in_picture(&mut self, browser_view: BrowserView) -> bool;
+
+    /// Called when |browser_view| receives a gesture command. Return true (1) to
+    /// handle (or disable) a |gesture_command| or false (0) to propagate the
+    /// gesture to the browser for default handling. With the Chrome runtime these
+    /// commands can also be handled via cef_command_handler_t::OnChromeCommand.
+    fn on_gesture_command(
+        &mut self,
+        browser_view: BrowserView,
+        gesture_command: GestureCommand
+    ) -> bool;
+}
+
+// Implement this structure to handle BrowserView events. The functions of this
+// structure will be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(BrowserViewDelegate, cef_browser_view_delegate_t);
+
+impl BrowserViewDelegate {
+    pub fn new<C: BrowserViewDelegateCallbacks>(delegate: C) -> Self {
+        Self(BrowserViewDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct BrowserViewDelegateWrapper(Box<dyn BrowserViewDelegateCallbacks>);
+
+impl BrowserViewDelegateWrapper {
+    pub fn new<C: BrowserViewDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Called when |browser| associated with |browser_view| is created.
+    unsafe extern "C" fn c_on_browser_created(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t,
+        browser: *mut cef_browser_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+        let browser = Browser::from_ptr_unchecked(browser);
+
+        this.0
+            .on_browser_created(browser_view, browser);
+    }
+
+    /// Called when |browser| associated with |browser_view| is destroyed.
+    unsafe extern "C" fn c_on_browser_destroyed(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t,
+        browser: *mut cef_browser_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+        let browser = Browser::from_ptr_unchecked(browser);
+
+        this.0
+            .on_browser_destroyed(browser_view, browser);
+    }
+
+    /// Called before a new popup BrowserView is created.
+    unsafe extern "C" fn c_get_delegate_for_popup_browser_view(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t,
+        settings: *const cef_browser_settings_t,
+        client: *mut cef_client_t,
+        is_devtools: c_int
+    ) -> *mut cef_browser_view_delegate_t {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+        let settings = BrowserSettings::from_ptr_unchecked(settings);
+        let client = Client::from_ptr_unchecked(client);
+
+        this.0
+            .get_delegate_for_popup_browser_view(browser_view, settings, client, is_devtools != 0)
+            .map(|delegate| delegate.into_raw())
+            .unwrap_or_else(null_mut)
+    }
+
+    /// Called after |popup_browser_view| is created.
+    unsafe extern "C" fn c_on_popup_browser_view_created(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t,
+        popup_browser_view: *mut cef_browser_view_t,
+        is_devtools: c_int
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+        let popup_browser_view = BrowserView::from_ptr_unchecked(popup_browser_view);
+
+        this.0
+            .on_popup_browser_view_created(browser_view, popup_browser_view, is_devtools != 0)
+            as c_int
+    }
+
+    /// Returns the Chrome toolbar type that will be available via
+    /// cef_browser_view_t::get_chrome_toolbar().
+    unsafe extern "C" fn c_get_chrome_toolbar_type(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t
+    ) -> cef_chrome_toolbar_type_t {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+
+        this.0
+            .get_chrome_toolbar_type(browser_view)
+            .into()
+    }
+
+    /// Return true (1) to create frameless windows for Document picture-in-
+    /// picture popups.
+    unsafe extern "C" fn c_use_frameless_window_for_picture_in_picture(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+
+        this.0
+            .use_frameless_window_for_picture_in_picture(browser_view) as c_int
+    }
+
+    /// Called when |browser_view| receives a gesture command.
+    unsafe extern "C" fn c_on_gesture_command(
+        this: *mut cef_browser_view_delegate_t,
+        browser_view: *mut cef_browser_view_t,
+        gesture_command: cef_gesture_command_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser_view = BrowserView::from_ptr_unchecked(browser_view);
+
+        this.0
+            .on_gesture_command(browser_view, gesture_command.into()) as c_int
+    }
+}
+
+impl ViewDelegateWrappable for BrowserViewDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for BrowserViewDelegateWrapper {
+    type Cef = cef_browser_view_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_browser_view_delegate_t> {
+        RefCountedPtr::wrap(
+            cef_browser_view_delegate_t {
+                base: view_delegate::<Self>(),
+                on_browser_created: Some(Self::c_on_browser_created),
+                on_browser_destroyed: Some(Self::c_on_browser_destroyed),
+                get_delegate_for_popup_browser_view: Some(
+                    Self::c_get_delegate_for_popup_browser_view
+                ),
+                on_popup_browser_view_created: Some(Self::c_on_popup_browser_view_created),
+                get_chrome_toolbar_type: Some(Self::c_get_chrome_toolbar_type),
+                use_frameless_window_for_picture_in_picture: Some(
+                    Self::c_use_frameless_window_for_picture_in_picture
+                ),
+                on_gesture_command: Some(Self::c_on_gesture_command)
+            },
+            self
+        )
+    }
+}
+
+// A View hosting a cef_browser_t instance. Methods must be called on the
+// browser process UI thread unless otherwise indicated.
+ref_counted_ptr!(BrowserView, cef_browser_view_t);
+
+view_deref!(BrowserView, View);
+
+impl BrowserView {
+    /// Create a new BrowserView. The underlying cef_browser_t will not be created
+    /// until this view is added to the views hierarchy. The optional |extra_info|
+    /// parameter provides an opportunity to specify extra information specific to
+    /// the created browser that will be passed to
+    /// cef_render_process_handler_t::on_browser_created() in the render process.
+    pub fn create(
+        client: Client,
+        url: &str,
+        settings: &BrowserSettings,
+        extra_info: Option<DictionaryValue>,
+        request_context: Option<RequestContext>,
+        delegate: Option<BrowserViewDelegate>
+    ) -> Option<BrowserView> {
+        unsafe {
+            let url = CefString::new(url);
+            let extra_info = extra_info
+                .map(|extra_info| extra_info.into_raw())
+                .unwrap_or_else(null_mut);
+            let request_context = request_context
+                .map(|request_context| request_context.into_raw())
+                .unwrap_or_else(null_mut);
+            let delegate = delegate
+                .map(|delegate| delegate.into_raw())
+                .unwrap_or_else(null_mut);
+
+            BrowserView::from_ptr(cef_browser_view_create(
+                client.into_raw(),
+                url.as_ptr(),
+                settings.as_raw(),
+                extra_info,
+                request_context,
+                delegate
+            ))
+        }
+    }
+
+    /// Returns the BrowserView associated with |browser|.
+    pub fn get_for_browser(browser: Browser) -> Option<BrowserView> {
+        unsafe { BrowserView::from_ptr(cef_browser_view_get_for_browser(browser.into_raw())) }
+    }
+
+    /// Returns the cef_browser_t hosted by this BrowserView. Will return NULL if
+    /// the browser has not yet been created or has already been destroyed.
+    pub fn get_browser(&self) -> Result<Option<Browser>> {
+        try_c!(self, get_browser, ThreadId::Ui, {
+            Ok(Browser::from_ptr(get_browser(self.as_ptr())))
+        })
+    }
+
+    /// Returns the Chrome toolbar associated with this BrowserView. Only
+    /// supported when using the Chrome runtime. The cef_browser_view_delegate_t::
+    /// get_chrome_toolbar_type() function must return a value other than
+    /// CEF_CTT_NONE and the toolbar will not be available until after this
+    /// BrowserView is added to a cef_window_t and
+    /// cef_view_delegate_t::on_window_changed() has been called.
+    pub fn get_chrome_toolbar(&self) -> Result<Option<View>> {
+        try_c!(self, get_chrome_toolbar, ThreadId::Ui, {
+            Ok(View::from_ptr(get_chrome_toolbar(self.as_ptr())))
+        })
+    }
+
+    /// Sets whether normal priority accelerators are first forwarded to the web
+    /// content (`keydown` event handler) or cef_keyboard_handler_t. Normal
+    /// priority accelerators can be registered via cef_window_t::SetAccelerator
+    /// (with |high_priority|=false (0)) or internally for standard accelerators
+    /// supported by the Chrome runtime. If |prefer_accelerators| is true (1) then
+    /// the matching accelerator will be triggered immediately (calling
+    /// cef_window_delegate_t::OnAccelerator or
+    /// cef_command_handler_t::OnChromeCommand respectively) and the event will
+    /// not be forwarded to the web content or cef_keyboard_handler_t first. If
+    /// |prefer_accelerators| is false (0) then the matching accelerator will only
+    /// be triggered if the event is not handled by web content (`keydown` event
+    /// handler that calls `event.preventDefault()`) or by
+    /// cef_keyboard_handler_t. The default value is false (0).
+    pub fn set_prefer_accelerators(&self, prefer_accelerators: bool) -> Result<()> {
+        try_c!(self, set_prefer_accelerators, ThreadId::Ui, {
+            Ok(set_prefer_accelerators(
+                self.as_ptr(),
+                prefer_accelerators as c_int
+            ))
+        })
+    }
+}
+
+impl From<BrowserView> for View {
+    fn from(value: BrowserView) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    ref_counted_ptr, try_c, view_delegate, view_deref, ButtonState, CefString, Color,
+    HorizontalAlignment, Image, MenuButton, RefCountedPtr, Size, ThreadId, View,
+    ViewDelegateCallbacks, ViewDelegateWrappable, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_button_delegate_t, cef_button_t, cef_label_button_create, cef_label_button_t, cef_size_t
+};
+use std::{ffi::c_int, ptr::null_mut};
+
+/// Implement this structure to handle Button events. The functions of this
+/// structure will be called on the browser process UI thread unless otherwise
+/// indicated.
+pub trait ButtonDelegateCallbacks: ViewDelegateCallbacks {
+    /// Called when |button| is pressed.
+    fn on_button_pressed(&mut self, button: Button);
+
+    /// Called when the state of |button| changes.
+    fn on_button_state_changed(&mut self, button: Button);
+}
+
+// Implement this structure to handle Button events. The functions of this
+// structure will be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(ButtonDelegate, cef_button_delegate_t);
+
+impl ButtonDelegate {
+    pub fn new<C: ButtonDelegateCallbacks>(delegate: C) -> Self {
+        Self(ButtonDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct ButtonDelegateWrapper(Box<dyn ButtonDelegateCallbacks>);
+
+impl ButtonDelegateWrapper {
+    pub fn new<C: ButtonDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+}
+
+impl ViewDelegateWrappable for ButtonDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl ButtonDelegateWrappable for ButtonDelegateWrapper {
+    fn button_delegate(&mut self) -> &mut dyn ButtonDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for ButtonDelegateWrapper {
+    type Cef = cef_button_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_button_delegate_t> {
+        RefCountedPtr::wrap(button_delegate::<Self>(), self)
+    }
+}
+
+/// Implemented by the wrappers of every button delegate so that the
+/// cef_button_delegate_t callbacks can be shared between them.
+pub(crate) trait ButtonDelegateWrappable: ViewDelegateWrappable {
+    /// Returns the button delegate callbacks.
+    fn button_delegate(&mut self) -> &mut dyn ButtonDelegateCallbacks;
+}
+
+/// Returns a cef_button_delegate_t that forwards to the wrapper |W|. The
+/// wrapped CEF structure must start with a cef_button_delegate_t.
+pub(crate) fn button_delegate<W: ButtonDelegateWrappable>() -> cef_button_delegate_t {
+    cef_button_delegate_t {
+        base:                    view_delegate::<W>(),
+        on_button_pressed:       Some(c_on_button_pressed::<W>),
+        on_button_state_changed: Some(c_on_button_state_changed::<W>)
+    }
+}
+
+/// Called when |button| is pressed.
+unsafe extern "C" fn c_on_button_pressed<W: ButtonDelegateWrappable>(
+    this: *mut cef_button_delegate_t,
+    button: *mut cef_button_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let button = Button::from_ptr_unchecked(button);
+
+    this.button_delegate()
+        .on_button_pressed(button);
+}
+
+/// Called when the state of |button| changes.
+unsafe extern "C" fn c_on_button_state_changed<W: ButtonDelegateWrappable>(
+    this: *mut cef_button_delegate_t,
+    button: *mut cef_button_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let button = Button::from_ptr_unchecked(button);
+
+    this.button_delegate()
+        .on_button_state_changed(button);
+}
+
+// A View representing a button. Depending on the specific type, the button
+// could be implemented by a native control or custom rendered. Methods must be
+// called on the browser process UI thread unless otherwise indicated.
+ref_counted_ptr!(Button, cef_button_t);
+
+view_deref!(Button, View);
+
+impl Button {
+    /// Returns this Button as a LabelButton or NULL if this is not a
+    /// LabelButton.
+    pub fn as_label_button(&self) -> Result<Option<LabelButton>> {
+        try_c!(self, as_label_button, ThreadId::Ui, {
+            Ok(LabelButton::from_ptr(as_label_button(self.as_ptr())))
+        })
+    }
+
+    /// Sets the current display state of the Button.
+    pub fn set_state(&self, state: ButtonState) -> Result<()> {
+        try_c!(self, set_state, ThreadId::Ui, {
+            Ok(set_state(self.as_ptr(), state.into()))
+        })
+    }
+
+    /// Returns the current display state of the Button.
+    pub fn get_state(&self) -> Result<ButtonState> {
+        try_c!(self, get_state, ThreadId::Ui, {
+            Ok(get_state(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the Button will use an ink drop effect for displaying state changes.
+    pub fn set_ink_drop_enabled(&self, enabled: bool) -> Result<()> {
+        try_c!(self, set_ink_drop_enabled, ThreadId::Ui, {
+            Ok(set_ink_drop_enabled(self.as_ptr(), enabled as c_int))
+        })
+    }
+
+    /// Sets the tooltip text that will be displayed when the user hovers the
+    /// mouse cursor over the Button.
+    pub fn set_tooltip_text(&self, tooltip_text: &str) -> Result<()> {
+        try_c!(self, set_tooltip_text, ThreadId::Ui, {
+            let tooltip_text = CefString::new(tooltip_text);
+
+            Ok(set_tooltip_text(self.as_ptr(), tooltip_text.as_ptr()))
+        })
+    }
+
+    /// Sets the accessible name that will be exposed to assistive technology
+    /// (AT).
+    pub fn set_accessible_name(&self, name: &str) -> Result<()> {
+        try_c!(self, set_accessible_name, ThreadId::Ui, {
+            let name = CefString::new(name);
+
+            Ok(set_accessible_name(self.as_ptr(), name.as_ptr()))
+        })
+    }
+}
+
+impl From<Button> for View {
+    fn from(value: Button) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
+
+// LabelButton is a button with optional text and/or icon. Methods must be
+// called on the browser process UI thread unless otherwise indicated.
+ref_counted_ptr!(LabelButton, cef_label_button_t);
+
+view_deref!(LabelButton, Button);
+
+impl LabelButton {
+    /// Create a new LabelButton. A |delegate| must be provided to handle the
+    /// button click. |text| will be shown on the LabelButton and used as the
+    /// default accessible name.
+    pub fn create(delegate: ButtonDelegate, text: &str) -> Option<LabelButton> {
+        unsafe {
+            let text = CefString::new(text);
+
+            LabelButton::from_ptr(cef_label_button_create(delegate.into_raw(), text.as_ptr()))
+        }
+    }
+
+    /// Returns this LabelButton as a MenuButton or NULL if this is not a
+    /// MenuButton.
+    pub fn as_menu_button(&self) -> Result<Option<MenuButton>> {
+        try_c!(self, as_menu_button, ThreadId::Ui, {
+            Ok(MenuButton::from_ptr(as_menu_button(self.as_ptr())))
+        })
+    }
+
+    /// Sets the text shown on the LabelButton. By default |text| will also be
+    /// used as the accessible name.
+    pub fn set_text(&self, text: &str) -> Result<()> {
+        try_c!(self, set_text, ThreadId::Ui, {
+            let text = CefString::new(text);
+
+            Ok(set_text(self.as_ptr(), text.as_ptr()))
+        })
+    }
+
+    /// Returns the text shown on the LabelButton.
+    pub fn get_text(&self) -> Result<String> {
+        try_c!(self, get_text, ThreadId::Ui, {
+            let s = get_text(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Sets the image shown for |button_state|. When this Button is drawn if no
+    /// image exists for the current state then the image for
+    /// CEF_BUTTON_STATE_NORMAL, if any, will be shown.
+    pub fn set_image(&self, button_state: ButtonState, image: Option<Image>) -> Result<()> {
+        try_c!(self, set_image, ThreadId::Ui, {
+            let image = image
+                .map(|image| image.into_raw())
+                .unwrap_or_else(null_mut);
+
+            Ok(set_image(self.as_ptr(), button_state.into(), image))
+        })
+    }
+
+    /// Returns the image shown for |button_state|. If no image exists for that
+    /// state then the image for CEF_BUTTON_STATE_NORMAL will be returned.
+    pub fn get_image(&self, button_state: ButtonState) -> Result<Option<Image>> {
+        try_c!(self, get_image, ThreadId::Ui, {
+            Ok(Image::from_ptr(get_image(
+                self.as_ptr(),
+                button_state.into()
+            )))
+        })
+    }
+
+    /// Sets the text color shown for the specified button |for_state| to |color|.
+    pub fn set_text_color(&self, for_state: ButtonState, color: &Color) -> Result<()> {
+        try_c!(self, set_text_color, ThreadId::Ui, {
+            Ok(set_text_color(
+                self.as_ptr(),
+                for_state.into(),
+                color.into()
+            ))
+        })
+    }
+
+    /// Sets the text colors shown for the non-disabled states to |color|.
+    pub fn set_enabled_text_colors(&self, color: &Color) -> Result<()> {
+        try_c!(self, set_enabled_text_colors, ThreadId::Ui, {
+            Ok(set_enabled_text_colors(self.as_ptr(), color.into()))
+        })
+    }
+
+    /// Sets the font list. The format is "<FONT_FAMILY_LIST>,[STYLES] <SIZE>",
+    /// where:
+    /// - FONT_FAMILY_LIST is a comma-separated list of font family names,
+    /// - STYLES is an optional space-separated list of style names (case-
+    ///   sensitive "Bold" and "Italic" are supported), and
+    /// - SIZE is an integer font size in pixels with the suffix "px".
+    ///
+    /// Here are examples of valid font description strings:
+    /// - "Arial, Helvetica, Bold Italic 14px"
+    /// - "Arial, 14px"
+    pub fn set_font_list(&self, font_list: &str) -> Result<()> {
+        try_c!(self, set_font_list, ThreadId::Ui, {
+            let font_list = CefString::new(font_list);
+
+            Ok(set_font_list(self.as_ptr(), font_list.as_ptr()))
+        })
+    }
+
+    /// Sets the horizontal alignment; reversed in RTL. Default is
+    /// CEF_HORIZONTAL_ALIGNMENT_CENTER.
+    pub fn set_horizontal_alignment(&self, alignment: HorizontalAlignment) -> Result<()> {
+        try_c!(self, set_horizontal_alignment, ThreadId::Ui, {
+            Ok(set_horizontal_alignment(self.as_ptr(), alignment.into()))
+        })
+    }
+
+    /// Reset the minimum size of this LabelButton to |size|.
+    pub fn set_minimum_size(&self, size: &Size) -> Result<()> {
+        try_c!(self, set_minimum_size, ThreadId::Ui, {
+            let size: cef_size_t = size.into();
+
+            Ok(set_minimum_size(self.as_ptr(), &size))
+        })
+    }
+
+    /// Reset the maximum size of this LabelButton to |size|.
+    pub fn set_maximum_size(&self, size: &Size) -> Result<()> {
+        try_c!(self, set_maximum_size, ThreadId::Ui, {
+            let size: cef_size_t = size.into();
+
+            Ok(set_maximum_size(self.as_ptr(), &size))
+        })
+    }
+}
+
+impl From<LabelButton> for Button {
+    fn from(value: LabelButton) -> Self {
+        unsafe { Button::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
+
+impl From<LabelButton> for View {
+    fn from(value: LabelButton) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
-    keyboard_handler::KeyboardHandler, ref_counted_ptr, ContextMenuHandler, LifeSpanHandler,
-    RefCountedPtr, RenderHandler, Wrappable, Wrapped
+    keyboard_handler::KeyboardHandler, ref_counted_ptr, Browser, ContextMenuHandler, Frame,
+    FrameHandler, LifeSpanHandler, ProcessId, ProcessMessage, RefCountedPtr, RenderHandler,
+    RequestHandler, Wrappable, Wrapped
 };
 use cef_ui_sys::{
     cef_audio_handler_t, cef_browser_t, cef_client_t, cef_command_handler_t,
@@ -55,11 +56,10 @@ pub trait ClientCallbacks: Send + Sync + 'static {
     // struct _cef_focus_handler_t*(CEF_CALLBACK* get_focus_handler)(
     // struct _cef_client_t* self);
 
-    // /// Return the handler for events related to cef_frame_t lifespan. This
-    // /// function will be called once during cef_browser_t creation and the result
-    // /// will be cached for performance reasons.
-    // struct _cef_frame_handler_t*(CEF_CALLBACK* get_frame_handler)(
-    // struct _cef_client_t* self);
+    /// Return the handler for events related to cef_frame_t lifespan. This
+    /// function will be called once during cef_browser_t creation and the result
+    /// will be cached for performance reasons.
+    fn get_frame_handler(&mut self) -> Option<FrameHandler>;
 
     // /// Return the handler for permission requests.
     // struct _cef_permission_handler_t*(CEF_CALLBACK* get_permission_handler)(
@@ -88,19 +88,19 @@ pub trait ClientCallbacks: Send + Sync + 'static {
     /// Return the handler for off-screen rendering events.
     fn get_render_handler(&mut self) -> Option<RenderHandler>;
 
-    // /// Return the handler for browser request events.
-    // struct _cef_request_handler_t*(CEF_CALLBACK* get_request_handler)(
-    // struct _cef_client_t* self);
+    /// Return the handler for browser request events.
+    fn get_request_handler(&mut self) -> Option<RequestHandler>;
 
-    // /// Called when a new message is received from a different process. Return
-    // /// true (1) if the message was handled or false (0) otherwise.  It is safe to
-    // /// keep a reference to |message| outside of this callback.
-    // int(CEF_CALLBACK* on_process_message_received)(
-    // struct _cef_client_t* self,
-    // struct _cef_browser_t* browser,
-    // struct _cef_frame_t* frame,
-    // cef_process_id_t source_process,
-    // struct _cef_process_message_t* message);
+    /// Called when a new message is received from a different process. Return
+    /// true (1) if the message was handled or false (0) otherwise.  It is safe to
+    /// keep a reference to |message| outside of this callback.
+    fn on_process_message_received(
+        &mut self,
+        browser: Browser,
+        frame: Frame,
+        source_process: ProcessId,
+        message: ProcessMessage
+    ) -> bool;
 }
 
 // Implement this structure to provide handler implementations.
@@ -192,7 +192,12 @@ impl ClientWrapper {
     /// function will be called once during cef_browser_t creation and the result
     /// will be cached for performance reasons.
     unsafe extern "C" fn c_get_frame_handler(this: *mut cef_client_t) -> *mut cef_frame_handler_t {
-        todo!()
+        let this: &mut Self = Wrapped::wrappable(this);
+
+        this.0
+            .get_frame_handler()
+            .map(|handler| handler.into_raw())
+            .unwrap_or_else(null_mut)
     }
 
     /// Return the handler for permission requests.
@@ -261,7 +266,12 @@ impl ClientWrapper {
     unsafe extern "C" fn c_get_request_handler(
         this: *mut cef_client_t
     ) -> *mut cef_request_handler_t {
-        todo!()
+        let this: &mut Self = Wrapped::wrappable(this);
+
+        this.0
+            .get_request_handler()
+            .map(|handler| handler.into_raw())
+            .unwrap_or_else(null_mut)
     }
 
     /// Called when a new message is received from a different process. Return
@@ -274,7 +284,14 @@ impl ClientWrapper {
         source_process: cef_process_id_t,
         message: *mut cef_process_message_t
     ) -> c_int {
-        todo!()
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser = Browser::from_ptr_unchecked(browser);
+        let frame = Frame::from_ptr_unchecked(frame);
+        let message = ProcessMessage::from_ptr_unchecked(message);
+
+        this.0
+            .on_process_message_received(browser, frame, source_process.into(), message)
+            as c_int
     }
 }
 
@@ -297,7 +314,7 @@ impl Wrappable for ClientWrapper {
                 get_drag_handler:            None,
                 get_find_handler:            None,
                 get_focus_handler:           None,
-                get_frame_handler:           None,
+                get_frame_handler:           Some(Self::c_get_frame_handler),
                 get_permission_handler:      None,
                 get_jsdialog_handler:        None,
                 get_keyboard_handler:        Some(Self::c_get_keyboard_handler),
@@ -305,8 +322,8 @@ impl Wrappable for ClientWrapper {
                 get_load_handler:            None,
                 get_print_handler:           None,
                 get_render_handler:          Some(Self::c_get_render_handler),
-                get_request_handler:         None,
-                on_process_message_received: None
+                get_request_handler:         Some(Self::c_get_request_handler),
+                on_process_message_received: Some(Self::c_process_message_received)
             },
             self
         )
@@ -1,4 +1,4 @@
-use crate::{App, MainArgs, Settings};
+use crate::{post_task, App, MainArgs, Settings, ThreadId, WaitableEvent};
 use anyhow::{anyhow, Result};
 use cef_ui_sys::{
     cef_do_message_loop_work, cef_execute_process, cef_initialize, cef_quit_message_loop,
@@ -6,6 +6,24 @@ use cef_ui_sys::{
 };
 use std::{ffi::c_void, ptr::null_mut};
 
+/// How the browser process message loop is run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MessageLoopMode {
+    /// The message loop runs on the main application thread, either through
+    /// run_message_loop() or by calling do_message_loop_work() periodically.
+    SingleThreaded,
+
+    /// The message loop runs on the main application thread and is scheduled
+    /// through on_schedule_message_pump_work(), see MessagePump.
+    ExternalPump,
+
+    /// The message loop runs in a separate UI thread created by CEF. Browsers
+    /// must be created with BrowserHost::create_browser() or from a task posted
+    /// to the UI thread. BrowserHost::create_browser_sync() returns an error
+    /// when called from the main application thread.
+    MultiThreaded
+}
+
 pub struct Context {
     pub main_args:            MainArgs,
     pub settings:             Settings,
@@ -80,6 +98,23 @@ impl Context {
         }
     }
 
+    /// Returns how the browser process message loop is run.
+    pub fn message_loop_mode(&self) -> MessageLoopMode {
+        if self
+            .settings
+            .is_multi_threaded_message_loop()
+        {
+            MessageLoopMode::MultiThreaded
+        } else if self
+            .settings
+            .is_external_message_pump()
+        {
+            MessageLoopMode::ExternalPump
+        } else {
+            MessageLoopMode::SingleThreaded
+        }
+    }
+
     /// Run the CEF message loop. Use this function instead of an application-
     /// provided message loop to get the best balance between performance and CPU
     /// usage. This function should only be called on the main application thread
@@ -99,11 +134,31 @@ impl Context {
 
     /// This function should be called on the main application thread to shut down
     /// the CEF browser process before the application exits. Do not call any other
-    /// CEF functions after calling this function.
+    /// CEF functions after calling this function. When using a multi-threaded
+    /// message loop this first waits for the tasks already posted to the UI
+    /// thread to run.
     pub fn shutdown(&self) {
+        if self.message_loop_mode() == MessageLoopMode::MultiThreaded {
+            self.wait_for_ui_thread();
+        }
+
         unsafe { cef_shutdown() };
     }
 
+    /// Blocks until every task posted to the UI thread so far has run.
+    fn wait_for_ui_thread(&self) {
+        let event = WaitableEvent::new(false, false);
+        let signal = event.clone();
+        let posted = post_task(ThreadId::Ui, move || {
+            let _ = signal.signal();
+        });
+
+        // If the task couldn't be posted the UI thread is already gone.
+        if posted.is_ok() {
+            let _ = event.wait();
+        }
+    }
+
     /// Perform a single iteration of CEF message loop processing. This function is
     /// provided for cases where the CEF message loop must be integrated into an
     /// existing application message loop. Use of this function is not recommended
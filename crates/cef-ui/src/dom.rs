@@ -0,0 +1,507 @@
+use crate::{
+    ref_counted_ptr, try_c, CefString, CefStringMap, Rect, RefCountedPtr, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_dom_document_type_t, cef_dom_node_type_t, cef_domdocument_t, cef_domnode_t,
+    cef_domvisitor_t
+};
+use parking_lot::Mutex;
+use std::{collections::HashMap, mem::zeroed};
+
+/// DOM document types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DomDocumentType {
+    Unknown,
+    Html,
+    Xhtml,
+    Plugin
+}
+
+impl From<cef_dom_document_type_t> for DomDocumentType {
+    fn from(value: cef_dom_document_type_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_dom_document_type_t> for DomDocumentType {
+    fn from(value: &cef_dom_document_type_t) -> Self {
+        match value {
+            cef_dom_document_type_t::DOM_DOCUMENT_TYPE_UNKNOWN => Self::Unknown,
+            cef_dom_document_type_t::DOM_DOCUMENT_TYPE_HTML => Self::Html,
+            cef_dom_document_type_t::DOM_DOCUMENT_TYPE_XHTML => Self::Xhtml,
+            cef_dom_document_type_t::DOM_DOCUMENT_TYPE_PLUGIN => Self::Plugin
+        }
+    }
+}
+
+impl From<DomDocumentType> for cef_dom_document_type_t {
+    fn from(value: DomDocumentType) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&DomDocumentType> for cef_dom_document_type_t {
+    fn from(value: &DomDocumentType) -> Self {
+        match value {
+            DomDocumentType::Unknown => Self::DOM_DOCUMENT_TYPE_UNKNOWN,
+            DomDocumentType::Html => Self::DOM_DOCUMENT_TYPE_HTML,
+            DomDocumentType::Xhtml => Self::DOM_DOCUMENT_TYPE_XHTML,
+            DomDocumentType::Plugin => Self::DOM_DOCUMENT_TYPE_PLUGIN
+        }
+    }
+}
+
+/// DOM node types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DomNodeType {
+    Unsupported,
+    Element,
+    Attribute,
+    Text,
+    CdataSection,
+    ProcessingInstructions,
+    Comment,
+    Document,
+    DocumentType,
+    DocumentFragment
+}
+
+impl From<cef_dom_node_type_t> for DomNodeType {
+    fn from(value: cef_dom_node_type_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_dom_node_type_t> for DomNodeType {
+    fn from(value: &cef_dom_node_type_t) -> Self {
+        match value {
+            cef_dom_node_type_t::DOM_NODE_TYPE_UNSUPPORTED => Self::Unsupported,
+            cef_dom_node_type_t::DOM_NODE_TYPE_ELEMENT => Self::Element,
+            cef_dom_node_type_t::DOM_NODE_TYPE_ATTRIBUTE => Self::Attribute,
+            cef_dom_node_type_t::DOM_NODE_TYPE_TEXT => Self::Text,
+            cef_dom_node_type_t::DOM_NODE_TYPE_CDATA_SECTION => Self::CdataSection,
+            cef_dom_node_type_t::DOM_NODE_TYPE_PROCESSING_INSTRUCTIONS => {
+                Self::ProcessingInstructions
+            },
+            cef_dom_node_type_t::DOM_NODE_TYPE_COMMENT => Self::Comment,
+            cef_dom_node_type_t::DOM_NODE_TYPE_DOCUMENT => Self::Document,
+            cef_dom_node_type_t::DOM_NODE_TYPE_DOCUMENT_TYPE => Self::DocumentType,
+            cef_dom_node_type_t::DOM_NODE_TYPE_DOCUMENT_FRAGMENT => Self::DocumentFragment
+        }
+    }
+}
+
+impl From<DomNodeType> for cef_dom_node_type_t {
+    fn from(value: DomNodeType) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&DomNodeType> for cef_dom_node_type_t {
+    fn from(value: &DomNodeType) -> Self {
+        match value {
+            DomNodeType::Unsupported => Self::DOM_NODE_TYPE_UNSUPPORTED,
+            DomNodeType::Element => Self::DOM_NODE_TYPE_ELEMENT,
+            DomNodeType::Attribute => Self::DOM_NODE_TYPE_ATTRIBUTE,
+            DomNodeType::Text => Self::DOM_NODE_TYPE_TEXT,
+            DomNodeType::CdataSection => Self::DOM_NODE_TYPE_CDATA_SECTION,
+            DomNodeType::ProcessingInstructions => Self::DOM_NODE_TYPE_PROCESSING_INSTRUCTIONS,
+            DomNodeType::Comment => Self::DOM_NODE_TYPE_COMMENT,
+            DomNodeType::Document => Self::DOM_NODE_TYPE_DOCUMENT,
+            DomNodeType::DocumentType => Self::DOM_NODE_TYPE_DOCUMENT_TYPE,
+            DomNodeType::DocumentFragment => Self::DOM_NODE_TYPE_DOCUMENT_FRAGMENT
+        }
+    }
+}
+
+// Structure to implement for visiting the DOM. The functions of this structure
+// will be called on the render process main thread.
+ref_counted_ptr!(DomVisitor, cef_domvisitor_t);
+
+impl DomVisitor {
+    pub fn new(f: impl FnOnce(DomDocument) + Send + 'static) -> Self {
+        Self(DomVisitorWrapper::new(f).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct DomVisitorWrapper(Mutex<Option<Box<dyn FnOnce(DomDocument) + Send + 'static>>>);
+
+impl DomVisitorWrapper {
+    pub fn new(f: impl FnOnce(DomDocument) + Send + 'static) -> Self {
+        Self(Mutex::new(Some(Box::new(f))))
+    }
+
+    /// Method executed for visiting the DOM. The document object passed to this
+    /// function represents a snapshot of the DOM at the time this function is
+    /// executed. DOM objects are only valid for the scope of this function. Do
+    /// not keep references to or attempt to access any DOM objects outside the
+    /// scope of this function.
+    unsafe extern "C" fn c_visit(this: *mut cef_domvisitor_t, document: *mut cef_domdocument_t) {
+        let this: &Self = Wrapped::wrappable(this);
+        let document = DomDocument::from_ptr_unchecked(document);
+
+        if let Some(f) = this.0.lock().take() {
+            f(document);
+        }
+    }
+}
+
+impl Wrappable for DomVisitorWrapper {
+    type Cef = cef_domvisitor_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<Self::Cef> {
+        RefCountedPtr::wrap(
+            cef_domvisitor_t {
+                base:  unsafe { zeroed() },
+                visit: Some(Self::c_visit)
+            },
+            self
+        )
+    }
+}
+
+// Structure used to represent a DOM document. The functions of this structure
+// should only be called on the render process main thread thread.
+ref_counted_ptr!(DomDocument, cef_domdocument_t);
+
+impl DomDocument {
+    /// Returns the document type.
+    pub fn get_type(&self) -> Result<DomDocumentType> {
+        try_c!(self, get_type, { Ok(get_type(self.as_ptr()).into()) })
+    }
+
+    /// Returns the root document node.
+    pub fn get_document(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_document, {
+            Ok(DomNode::from_ptr(get_document(self.as_ptr())))
+        })
+    }
+
+    /// Returns the BODY node of an HTML document.
+    pub fn get_body(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_body, {
+            Ok(DomNode::from_ptr(get_body(self.as_ptr())))
+        })
+    }
+
+    /// Returns the HEAD node of an HTML document.
+    pub fn get_head(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_head, {
+            Ok(DomNode::from_ptr(get_head(self.as_ptr())))
+        })
+    }
+
+    /// Returns the title of an HTML document.
+    pub fn get_title(&self) -> Result<Option<String>> {
+        try_c!(self, get_title, {
+            let s = get_title(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns the document element with the specified ID value.
+    pub fn get_element_by_id(&self, id: &str) -> Result<Option<DomNode>> {
+        try_c!(self, get_element_by_id, {
+            let id = CefString::new(id);
+
+            Ok(DomNode::from_ptr(get_element_by_id(
+                self.as_ptr(),
+                id.as_ptr()
+            )))
+        })
+    }
+
+    /// Returns the node that currently has keyboard focus.
+    pub fn get_focused_node(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_focused_node, {
+            Ok(DomNode::from_ptr(get_focused_node(self.as_ptr())))
+        })
+    }
+
+    /// Returns true (1) if a portion of the document is selected.
+    pub fn has_selection(&self) -> Result<bool> {
+        try_c!(self, has_selection, {
+            Ok(has_selection(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the selection offset within the start node.
+    pub fn get_selection_start_offset(&self) -> Result<i32> {
+        try_c!(self, get_selection_start_offset, {
+            Ok(get_selection_start_offset(self.as_ptr()))
+        })
+    }
+
+    /// Returns the selection offset within the end node.
+    pub fn get_selection_end_offset(&self) -> Result<i32> {
+        try_c!(self, get_selection_end_offset, {
+            Ok(get_selection_end_offset(self.as_ptr()))
+        })
+    }
+
+    /// Returns the contents of this selection as markup.
+    pub fn get_selection_as_markup(&self) -> Result<Option<String>> {
+        try_c!(self, get_selection_as_markup, {
+            let s = get_selection_as_markup(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns the contents of this selection as text.
+    pub fn get_selection_as_text(&self) -> Result<Option<String>> {
+        try_c!(self, get_selection_as_text, {
+            let s = get_selection_as_text(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns the base URL for the document.
+    pub fn get_base_url(&self) -> Result<Option<String>> {
+        try_c!(self, get_base_url, {
+            let s = get_base_url(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns a complete URL based on the document base URL and the specified
+    /// partial URL.
+    pub fn get_complete_url(&self, partial_url: &str) -> Result<Option<String>> {
+        try_c!(self, get_complete_url, {
+            let partial_url = CefString::new(partial_url);
+            let s = get_complete_url(self.as_ptr(), partial_url.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+}
+
+// Structure used to represent a DOM node. The functions of this structure
+// should only be called on the render process main thread.
+ref_counted_ptr!(DomNode, cef_domnode_t);
+
+impl DomNode {
+    /// Returns the type for this node.
+    pub fn get_type(&self) -> Result<DomNodeType> {
+        try_c!(self, get_type, { Ok(get_type(self.as_ptr()).into()) })
+    }
+
+    /// Returns true (1) if this is a text node.
+    pub fn is_text(&self) -> Result<bool> {
+        try_c!(self, is_text, { Ok(is_text(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this is an element node.
+    pub fn is_element(&self) -> Result<bool> {
+        try_c!(self, is_element, { Ok(is_element(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this is an editable node.
+    pub fn is_editable(&self) -> Result<bool> {
+        try_c!(self, is_editable, { Ok(is_editable(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this is a form control element node.
+    pub fn is_form_control_element(&self) -> Result<bool> {
+        try_c!(self, is_form_control_element, {
+            Ok(is_form_control_element(self.as_ptr()) != 0)
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Returns the type of this form control element node.
+    // ///
+    // cef_dom_form_control_type_t(CEF_CALLBACK* get_form_control_element_type)(
+    // struct _cef_domnode_t* self);
+
+    /// Returns true (1) if this object is pointing to the same handle as |that|
+    /// object.
+    pub fn is_same(&self, that: DomNode) -> Result<bool> {
+        try_c!(self, is_same, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    /// Returns the name of this node.
+    pub fn get_name(&self) -> Result<Option<String>> {
+        try_c!(self, get_name, {
+            let s = get_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns the value of this node.
+    pub fn get_value(&self) -> Result<Option<String>> {
+        try_c!(self, get_value, {
+            let s = get_value(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Set the value of this node. Returns true (1) on success.
+    pub fn set_value(&self, value: &str) -> Result<bool> {
+        try_c!(self, set_value, {
+            let value = CefString::new(value);
+
+            Ok(set_value(self.as_ptr(), value.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the contents of this node as markup.
+    pub fn get_as_markup(&self) -> Result<Option<String>> {
+        try_c!(self, get_as_markup, {
+            let s = get_as_markup(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns the document associated with this node.
+    pub fn get_document(&self) -> Result<Option<DomDocument>> {
+        try_c!(self, get_document, {
+            Ok(DomDocument::from_ptr(get_document(self.as_ptr())))
+        })
+    }
+
+    /// Returns the parent node.
+    pub fn get_parent(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_parent, {
+            Ok(DomNode::from_ptr(get_parent(self.as_ptr())))
+        })
+    }
+
+    /// Returns the previous sibling node.
+    pub fn get_previous_sibling(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_previous_sibling, {
+            Ok(DomNode::from_ptr(get_previous_sibling(self.as_ptr())))
+        })
+    }
+
+    /// Returns the next sibling node.
+    pub fn get_next_sibling(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_next_sibling, {
+            Ok(DomNode::from_ptr(get_next_sibling(self.as_ptr())))
+        })
+    }
+
+    /// Returns true (1) if this node has child nodes.
+    pub fn has_children(&self) -> Result<bool> {
+        try_c!(self, has_children, { Ok(has_children(self.as_ptr()) != 0) })
+    }
+
+    /// Return the first child node.
+    pub fn get_first_child(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_first_child, {
+            Ok(DomNode::from_ptr(get_first_child(self.as_ptr())))
+        })
+    }
+
+    /// Returns the last child node.
+    pub fn get_last_child(&self) -> Result<Option<DomNode>> {
+        try_c!(self, get_last_child, {
+            Ok(DomNode::from_ptr(get_last_child(self.as_ptr())))
+        })
+    }
+
+    /// Returns the child nodes of this node, in document order.
+    pub fn get_children(&self) -> Result<Vec<DomNode>> {
+        let mut children = Vec::new();
+        let mut child = self.get_first_child()?;
+
+        while let Some(node) = child {
+            child = node.get_next_sibling()?;
+            children.push(node);
+        }
+
+        Ok(children)
+    }
+
+    /// Returns the tag name of this element.
+    pub fn get_element_tag_name(&self) -> Result<Option<String>> {
+        try_c!(self, get_element_tag_name, {
+            let s = get_element_tag_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns true (1) if this element has attributes.
+    pub fn has_element_attributes(&self) -> Result<bool> {
+        try_c!(self, has_element_attributes, {
+            Ok(has_element_attributes(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if this element has an attribute named |attr_name|.
+    pub fn has_element_attribute(&self, attr_name: &str) -> Result<bool> {
+        try_c!(self, has_element_attribute, {
+            let attr_name = CefString::new(attr_name);
+
+            Ok(has_element_attribute(self.as_ptr(), attr_name.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the element attribute named |attr_name|.
+    pub fn get_element_attribute(&self, attr_name: &str) -> Result<Option<String>> {
+        try_c!(self, get_element_attribute, {
+            let attr_name = CefString::new(attr_name);
+            let s = get_element_attribute(self.as_ptr(), attr_name.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns a map of all element attributes.
+    pub fn get_element_attributes(&self) -> Result<HashMap<String, String>> {
+        try_c!(self, get_element_attributes, {
+            let mut attributes = CefStringMap::new();
+
+            get_element_attributes(self.as_ptr(), attributes.as_mut_ptr());
+
+            let attributes = attributes
+                .iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect();
+
+            Ok(attributes)
+        })
+    }
+
+    /// Set the value for the element attribute named |attr_name|. Returns true
+    /// (1) on success.
+    pub fn set_element_attribute(&self, attr_name: &str, value: &str) -> Result<bool> {
+        try_c!(self, set_element_attribute, {
+            let attr_name = CefString::new(attr_name);
+            let value = CefString::new(value);
+
+            Ok(set_element_attribute(self.as_ptr(), attr_name.as_ptr(), value.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the inner text of the element.
+    pub fn get_element_inner_text(&self) -> Result<Option<String>> {
+        try_c!(self, get_element_inner_text, {
+            let s = get_element_inner_text(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map(|s| s.into()))
+        })
+    }
+
+    /// Returns the bounds of the element in device pixels. Use
+    /// "window.devicePixelRatio" to convert to/from CSS pixels.
+    pub fn get_element_bounds(&self) -> Result<Rect> {
+        try_c!(self, get_element_bounds, {
+            Ok(get_element_bounds(self.as_ptr()).into())
+        })
+    }
+}
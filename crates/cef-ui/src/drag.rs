@@ -1,4 +1,4 @@
-use crate::{ref_counted_ptr, try_c, CefString, CefStringList, Point};
+use crate::{ref_counted_ptr, try_c, CefString, CefStringList, Point, StreamWriter};
 use anyhow::Result;
 use bitflags::bitflags;
 use cef_ui_sys::{
@@ -11,6 +11,7 @@ use cef_ui_sys::{
     cef_drag_operations_mask_t_DRAG_OPERATION_NONE,
     cef_drag_operations_mask_t_DRAG_OPERATION_PRIVATE
 };
+use std::ptr::null_mut;
 
 bitflags! {
     /// "Verb" of a drag-and-drop operation as negotiated between the source and
@@ -155,16 +156,19 @@ impl DragData {
         })
     }
 
-    // TODO: Fix this!
-
-    //     /// Write the contents of the file being dragged out of the web view into
-    //     /// |writer|. Returns the number of bytes sent to |writer|. If |writer| is
-    //     /// NULL this function will return the size of the file contents in bytes.
-    //     /// Call get_file_name() to get a suggested name for the file.
-    //     ///
-    //     size_t(CEF_CALLBACK* get_file_contents)(struct _cef_drag_data_t* self,
-    //     struct _cef_stream_writer_t* writer);
-    //
+    /// Write the contents of the file being dragged out of the web view into
+    /// |writer|. Returns the number of bytes sent to |writer|. If |writer| is
+    /// NULL this function will return the size of the file contents in bytes.
+    /// Call get_file_name() to get a suggested name for the file.
+    pub fn get_file_contents(&self, writer: Option<StreamWriter>) -> Result<usize> {
+        try_c!(self, get_file_contents, {
+            let writer = writer
+                .map(|writer| writer.into_raw())
+                .unwrap_or_else(null_mut);
+
+            Ok(get_file_contents(self.as_ptr(), writer))
+        })
+    }
 
     /// Retrieve the list of file names that are being dragged into the browser
     /// window.
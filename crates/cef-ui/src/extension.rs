@@ -1,4 +1,4 @@
-use crate::{ref_counted_ptr, try_c, CefString, DictionaryValue, RequestContext};
+use crate::{ref_counted_ptr, try_c, CefString, DictionaryValue, RequestContext, ThreadId};
 use anyhow::Result;
 use cef_ui_sys::cef_extension_t;
 
@@ -63,7 +63,7 @@ impl Extension {
     /// cef_request_context_t::LoadExtension documentation for more information
     /// about loader contexts. Must be called on the browser process UI thread.
     pub fn get_loader_context(&self) -> Result<Option<RequestContext>> {
-        try_c!(self, get_loader_context, {
+        try_c!(self, get_loader_context, ThreadId::Ui, {
             let context = get_loader_context(self.as_ptr());
 
             Ok(RequestContext::from_ptr(context))
@@ -73,7 +73,9 @@ impl Extension {
     /// Returns true (1) if this extension is currently loaded. Must be called on
     /// the browser process UI thread.
     pub fn is_loaded(&self) -> Result<bool> {
-        try_c!(self, is_loaded, { Ok(is_loaded(self.as_ptr()) != 0) })
+        try_c!(self, is_loaded, ThreadId::Ui, {
+            Ok(is_loaded(self.as_ptr()) != 0)
+        })
     }
 
     /// Unload this extension if it is not an internal extension and is currently
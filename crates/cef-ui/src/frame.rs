@@ -1,9 +1,13 @@
+#[cfg(feature = "futures")]
+use crate::StringVisitorCallbacks;
 use crate::{
-    ref_counted_ptr, try_c, Browser, CefString, ProcessId, ProcessMessage, Request, StringVisitor,
-    UrlRequest, UrlRequestClient
+    ref_counted_ptr, try_c, Browser, CefString, DomVisitor, ProcessId, ProcessMessage, Request,
+    StringVisitor, UrlRequest, UrlRequestClient, V8Context
 };
 use anyhow::Result;
 use cef_ui_sys::cef_frame_t;
+#[cfg(feature = "futures")]
+use futures::channel::oneshot;
 use std::ffi::c_int;
 
 // Structure used to represent a frame in the browser window. When used in the
@@ -76,6 +80,26 @@ impl Frame {
         })
     }
 
+    /// Same as get_source, but resolves to this frame's HTML source.
+    #[cfg(feature = "futures")]
+    pub async fn get_source_async(&self) -> Result<String> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.get_source(StringVisitor::new(StringSender(Some(sender))))?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Same as get_text, but resolves to this frame's display text.
+    #[cfg(feature = "futures")]
+    pub async fn get_text_async(&self) -> Result<String> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.get_text(StringVisitor::new(StringSender(Some(sender))))?;
+
+        Ok(receiver.await?)
+    }
+
     /// Load the request represented by the |request| object.
     ///
     /// WARNING: This function will fail with "bad IPC message" reason
@@ -168,20 +192,21 @@ impl Frame {
         })
     }
 
-    // TODO: Fix this!
-
-    // /// Get the V8 context associated with the frame. This function can only be
-    // /// called from the render process.
-    // ///
-    // struct _cef_v8context_t*(CEF_CALLBACK* get_v8context)(
-    // struct _cef_frame_t* self);
+    /// Get the V8 context associated with the frame. This function can only be
+    /// called from the render process.
+    pub fn get_v8context(&self) -> Result<Option<V8Context>> {
+        try_c!(self, get_v8context, {
+            Ok(V8Context::from_ptr(get_v8context(self.as_ptr())))
+        })
+    }
 
-    // ///
-    // /// Visit the DOM document. This function can only be called from the render
-    // /// process.
-    // ///
-    // void(CEF_CALLBACK* visit_dom)(struct _cef_frame_t* self,
-    // struct _cef_domvisitor_t* visitor);
+    /// Visit the DOM document. This function can only be called from the render
+    /// process.
+    pub fn visit_dom(&self, visitor: DomVisitor) -> Result<()> {
+        try_c!(self, visit_dom, {
+            Ok(visit_dom(self.as_ptr(), visitor.into_raw()))
+        })
+    }
 
     /// Create a new URL request that will be treated as originating from this
     /// frame and the associated browser. Use cef_urlrequest_t::Create instead if
@@ -232,3 +257,16 @@ impl Frame {
         })
     }
 }
+
+/// Sends the visited string through a oneshot channel.
+#[cfg(feature = "futures")]
+struct StringSender(Option<oneshot::Sender<String>>);
+
+#[cfg(feature = "futures")]
+impl StringVisitorCallbacks for StringSender {
+    fn visit(&mut self, string: &str) {
+        if let Some(sender) = self.0.take() {
+            let _ = sender.send(string.to_string());
+        }
+    }
+}
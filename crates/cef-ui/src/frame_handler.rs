@@ -0,0 +1,151 @@
+use crate::{ref_counted_ptr, Browser, Frame, RefCountedPtr, Wrappable, Wrapped};
+use cef_ui_sys::{cef_browser_t, cef_frame_handler_t, cef_frame_t};
+use std::{ffi::c_int, mem::zeroed};
+
+/// Implement this structure to handle events related to cef_frame_t life span.
+/// A frame is created, then attached once it can route commands to its peer in
+/// the renderer process, and detached once it loses that connection. Commands
+/// sent before a frame is attached are queued and commands sent after it is
+/// detached are discarded. Sub-frames are detached before the main frame. The
+/// main frame changes on initial browser creation, final browser destruction,
+/// cross-origin navigation and re-navigation after renderer process
+/// termination.
+///
+/// The functions of this structure will be called on the UI thread unless
+/// otherwise indicated.
+pub trait FrameHandlerCallbacks: Send + Sync + 'static {
+    /// Called when a new frame is created. This will be the first notification
+    /// that references |frame|. Any commands that require transport to the
+    /// associated renderer process (LoadRequest, SendProcessMessage, GetSource,
+    /// etc.) will be queued until on_frame_attached is called for |frame|.
+    fn on_frame_created(&mut self, browser: Browser, frame: Frame);
+
+    /// Called when a frame can begin routing commands to/from the associated
+    /// renderer process. |reattached| will be true (1) if the frame was re-
+    /// attached after exiting the BackForwardCache. Any commands that were
+    /// queued have now been dispatched.
+    fn on_frame_attached(&mut self, browser: Browser, frame: Frame, reattached: bool);
+
+    /// Called when a frame loses its connection to the renderer process and will
+    /// be destroyed. Any pending or future commands will be discarded and
+    /// cef_frame_t::is_valid() will now return false (0) for |frame|. If called
+    /// after cef_life_span_handler_t::on_before_close() during browser
+    /// destruction then cef_browser_t::is_valid() will return false (0) for
+    /// |browser|.
+    fn on_frame_detached(&mut self, browser: Browser, frame: Frame);
+
+    /// Called when the main frame changes due to (a) initial browser creation,
+    /// (b) final browser destruction, (c) cross-origin navigation or (d) re-
+    /// navigation after renderer process termination (due to crashes, etc).
+    /// |old_frame| will be None and |new_frame| will be Some when a main frame
+    /// is assigned to |browser| for the first time. |old_frame| will be Some and
+    /// |new_frame| will be None when a main frame is removed from |browser| for
+    /// the last time. Both |old_frame| and |new_frame| will be Some for
+    /// cross-origin navigations or re-navigation after renderer process
+    /// termination.
+    fn on_main_frame_changed(
+        &mut self,
+        browser: Browser,
+        old_frame: Option<Frame>,
+        new_frame: Option<Frame>
+    );
+}
+
+// Implement this structure to handle events related to cef_frame_t life span.
+// The functions of this structure will be called on the UI thread unless
+// otherwise indicated.
+ref_counted_ptr!(FrameHandler, cef_frame_handler_t);
+
+impl FrameHandler {
+    pub fn new<C: FrameHandlerCallbacks>(delegate: C) -> Self {
+        Self(FrameHandlerWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct FrameHandlerWrapper(Box<dyn FrameHandlerCallbacks>);
+
+impl FrameHandlerWrapper {
+    pub fn new<C: FrameHandlerCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Called when a new frame is created.
+    unsafe extern "C" fn c_on_frame_created(
+        this: *mut cef_frame_handler_t,
+        browser: *mut cef_browser_t,
+        frame: *mut cef_frame_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser = Browser::from_ptr_unchecked(browser);
+        let frame = Frame::from_ptr_unchecked(frame);
+
+        this.0
+            .on_frame_created(browser, frame);
+    }
+
+    /// Called when a frame can begin routing commands to/from the associated
+    /// renderer process.
+    unsafe extern "C" fn c_on_frame_attached(
+        this: *mut cef_frame_handler_t,
+        browser: *mut cef_browser_t,
+        frame: *mut cef_frame_t,
+        reattached: c_int
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser = Browser::from_ptr_unchecked(browser);
+        let frame = Frame::from_ptr_unchecked(frame);
+
+        this.0
+            .on_frame_attached(browser, frame, reattached != 0);
+    }
+
+    /// Called when a frame loses its connection to the renderer process and will
+    /// be destroyed.
+    unsafe extern "C" fn c_on_frame_detached(
+        this: *mut cef_frame_handler_t,
+        browser: *mut cef_browser_t,
+        frame: *mut cef_frame_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser = Browser::from_ptr_unchecked(browser);
+        let frame = Frame::from_ptr_unchecked(frame);
+
+        this.0
+            .on_frame_detached(browser, frame);
+    }
+
+    /// Called when the main frame changes.
+    unsafe extern "C" fn c_on_main_frame_changed(
+        this: *mut cef_frame_handler_t,
+        browser: *mut cef_browser_t,
+        old_frame: *mut cef_frame_t,
+        new_frame: *mut cef_frame_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let browser = Browser::from_ptr_unchecked(browser);
+        let old_frame = Frame::from_ptr(old_frame);
+        let new_frame = Frame::from_ptr(new_frame);
+
+        this.0
+            .on_main_frame_changed(browser, old_frame, new_frame);
+    }
+}
+
+impl Wrappable for FrameHandlerWrapper {
+    type Cef = cef_frame_handler_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_frame_handler_t> {
+        RefCountedPtr::wrap(
+            cef_frame_handler_t {
+                base:                  unsafe { zeroed() },
+                on_frame_created:      Some(Self::c_on_frame_created),
+                on_frame_attached:     Some(Self::c_on_frame_attached),
+                on_frame_detached:     Some(Self::c_on_frame_detached),
+                on_main_frame_changed: Some(Self::c_on_main_frame_changed)
+            },
+            self
+        )
+    }
+}
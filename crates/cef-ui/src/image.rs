@@ -0,0 +1,220 @@
+use crate::{ref_counted_ptr, try_c, BinaryValue, Size};
+use anyhow::Result;
+use cef_ui_sys::{cef_image_create, cef_image_t};
+use std::ffi::{c_int, c_void};
+
+// Container for a single image represented at different scale factors. All
+// image representations should be the same size in density independent pixel
+// (DIP) units. For example, if the image at scale factor 1.0 is 100x100 pixels
+// then the image at scale factor 2.0 should be 200x200 pixels -- both images
+// will display with a DIP size of 100x100 units. The functions of this
+// structure can be called on any browser process thread.
+ref_counted_ptr!(Image, cef_image_t);
+
+impl Image {
+    /// Create a new cef_image_t. It will initially be NULL. Use the Add*()
+    /// functions to add representations at different scale factors.
+    pub fn new() -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_image_create()) }
+    }
+
+    /// Returns true (1) if this Image is NULL.
+    pub fn is_empty(&self) -> Result<bool> {
+        try_c!(self, is_empty, { Ok(is_empty(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this Image and |that| Image share the same underlying
+    /// storage. Will also return true (1) if both images are NULL.
+    pub fn is_same(&self, that: Image) -> Result<bool> {
+        try_c!(self, is_same, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Add a bitmap image representation for |scale_factor|. Only 32-bit
+    // /// RGBA/BGRA formats are supported. |pixel_width| and |pixel_height| are the
+    // /// bitmap representation size in pixel coordinates. |pixel_data| is the array
+    // /// of pixel data and should be |pixel_width| x |pixel_height| x 4 bytes in
+    // /// size. |color_type| and |alpha_type| values specify the pixel format.
+    // ///
+    // int(CEF_CALLBACK* add_bitmap)(struct _cef_image_t* self,
+    // float scale_factor,
+    // int pixel_width,
+    // int pixel_height,
+    // cef_color_type_t color_type,
+    // cef_alpha_type_t alpha_type,
+    // const void* pixel_data,
+    // size_t pixel_data_size);
+
+    /// Add a PNG image representation for |scale_factor|. |png_data| is the image
+    /// data of size |png_data_size|. Any alpha transparency in the PNG data will
+    /// be maintained.
+    pub fn add_png(&self, scale_factor: f32, png_data: &[u8]) -> Result<bool> {
+        try_c!(self, add_png, {
+            Ok(add_png(
+                self.as_ptr(),
+                scale_factor,
+                png_data.as_ptr() as *const c_void,
+                png_data.len()
+            ) != 0)
+        })
+    }
+
+    /// Create a JPEG image representation for |scale_factor|. |jpeg_data| is the
+    /// image data of size |jpeg_data_size|. The JPEG format does not support
+    /// transparency so the alpha byte will be set to 0xFF for all pixels.
+    pub fn add_jpeg(&self, scale_factor: f32, jpeg_data: &[u8]) -> Result<bool> {
+        try_c!(self, add_jpeg, {
+            Ok(add_jpeg(
+                self.as_ptr(),
+                scale_factor,
+                jpeg_data.as_ptr() as *const c_void,
+                jpeg_data.len()
+            ) != 0)
+        })
+    }
+
+    /// Returns the image width in density independent pixel (DIP) units.
+    pub fn get_width(&self) -> Result<usize> {
+        try_c!(self, get_width, { Ok(get_width(self.as_ptr())) })
+    }
+
+    /// Returns the image height in density independent pixel (DIP) units.
+    pub fn get_height(&self) -> Result<usize> {
+        try_c!(self, get_height, { Ok(get_height(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if this image contains a representation for
+    /// |scale_factor|.
+    pub fn has_representation(&self, scale_factor: f32) -> Result<bool> {
+        try_c!(self, has_representation, {
+            Ok(has_representation(self.as_ptr(), scale_factor) != 0)
+        })
+    }
+
+    /// Removes the representation for |scale_factor|. Returns true (1) on
+    /// success.
+    pub fn remove_representation(&self, scale_factor: f32) -> Result<bool> {
+        try_c!(self, remove_representation, {
+            Ok(remove_representation(self.as_ptr(), scale_factor) != 0)
+        })
+    }
+
+    /// Returns information for the representation that most closely matches
+    /// |scale_factor|. |actual_scale_factor| is the actual scale factor for the
+    /// representation. |pixel_width| and |pixel_height| are the representation
+    /// size in pixel coordinates. Returns None if no representation exists.
+    pub fn get_representation_info(&self, scale_factor: f32) -> Result<Option<(f32, Size)>> {
+        try_c!(self, get_representation_info, {
+            let mut actual_scale_factor = 0.0;
+            let mut pixel_width: c_int = 0;
+            let mut pixel_height: c_int = 0;
+
+            let found = get_representation_info(
+                self.as_ptr(),
+                scale_factor,
+                &mut actual_scale_factor,
+                &mut pixel_width,
+                &mut pixel_height
+            ) != 0;
+
+            Ok(found.then_some((
+                actual_scale_factor,
+                Size {
+                    width:  pixel_width,
+                    height: pixel_height
+                }
+            )))
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Returns the bitmap representation that most closely matches
+    // /// |scale_factor|. Only 32-bit RGBA/BGRA formats are supported. |color_type|
+    // /// and |alpha_type| values specify the desired output pixel format.
+    // /// |pixel_width| and |pixel_height| are the output representation size in
+    // /// pixel coordinates. Returns a cef_binary_value_t containing the pixel data
+    // /// on success or NULL on failure.
+    // ///
+    // struct _cef_binary_value_t*(CEF_CALLBACK* get_as_bitmap)(
+    // struct _cef_image_t* self,
+    // float scale_factor,
+    // cef_color_type_t color_type,
+    // cef_alpha_type_t alpha_type,
+    // int* pixel_width,
+    // int* pixel_height);
+
+    /// Returns the PNG representation that most closely matches |scale_factor|.
+    /// If |with_transparency| is true (1) any alpha transparency in the image
+    /// will be represented in the resulting PNG data. Returns the PNG data and
+    /// the output representation size in pixel coordinates on success or None
+    /// on failure.
+    pub fn get_as_png(
+        &self,
+        scale_factor: f32,
+        with_transparency: bool
+    ) -> Result<Option<(BinaryValue, Size)>> {
+        try_c!(self, get_as_png, {
+            let mut pixel_width: c_int = 0;
+            let mut pixel_height: c_int = 0;
+
+            let data = BinaryValue::from_ptr(get_as_png(
+                self.as_ptr(),
+                scale_factor,
+                with_transparency as c_int,
+                &mut pixel_width,
+                &mut pixel_height
+            ));
+
+            Ok(data.map(|data| {
+                (
+                    data,
+                    Size {
+                        width:  pixel_width,
+                        height: pixel_height
+                    }
+                )
+            }))
+        })
+    }
+
+    /// Returns the JPEG representation that most closely matches |scale_factor|.
+    /// |quality| determines the compression level with 0 == lowest and 100 ==
+    /// highest. The JPEG format does not support alpha transparency and the
+    /// alpha channel, if any, will be discarded. Returns the JPEG data and the
+    /// output representation size in pixel coordinates on success or None on
+    /// failure.
+    pub fn get_as_jpeg(
+        &self,
+        scale_factor: f32,
+        quality: i32
+    ) -> Result<Option<(BinaryValue, Size)>> {
+        try_c!(self, get_as_jpeg, {
+            let mut pixel_width: c_int = 0;
+            let mut pixel_height: c_int = 0;
+
+            let data = BinaryValue::from_ptr(get_as_jpeg(
+                self.as_ptr(),
+                scale_factor,
+                quality as c_int,
+                &mut pixel_width,
+                &mut pixel_height
+            ));
+
+            Ok(data.map(|data| {
+                (
+                    data,
+                    Size {
+                        width:  pixel_width,
+                        height: pixel_height
+                    }
+                )
+            }))
+        })
+    }
+}
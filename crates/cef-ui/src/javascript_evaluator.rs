@@ -0,0 +1,357 @@
+use crate::{
+    get_argument_list, pending_call::PendingCalls, Browser, Frame, ListValue, ProcessId,
+    ProcessMessage, ThreadId, Value
+};
+use anyhow::{anyhow, Result};
+#[cfg(feature = "futures")]
+use futures::channel::oneshot;
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration
+};
+
+/// The name of the process message used to request an evaluation.
+const EVALUATE_MESSAGE_NAME: &str = "cefEvaluateJavaScriptMsg";
+
+/// The name of the process message used to return an evaluation result.
+const RESULT_MESSAGE_NAME: &str = "cefEvaluateJavaScriptResultMsg";
+
+/// The result status sent when the evaluation succeeded.
+const STATUS_SUCCESS: i32 = 0;
+
+/// The result status sent when the frame has no V8 context.
+const STATUS_NO_CONTEXT: i32 = 1;
+
+/// The result status sent when the script threw an exception.
+const STATUS_EXCEPTION: i32 = 2;
+
+/// The result status sent when the evaluation failed for any other reason.
+const STATUS_FAILED: i32 = 3;
+
+/// The maximum depth of objects and arrays converted from V8 values.
+const MAX_RESULT_DEPTH: usize = 64;
+
+/// Errors that can occur when evaluating JavaScript.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JavaScriptError {
+    /// The script threw an exception.
+    Exception {
+        message:              String,
+        source_line:          String,
+        script_resource_name: String,
+        line_number:          i32,
+        start_column:         i32,
+        end_column:           i32
+    },
+
+    /// The frame has no V8 context to evaluate in.
+    NoContext,
+
+    /// The evaluation did not complete before its deadline.
+    Timeout,
+
+    /// The evaluation was canceled because the frame was detached or navigated,
+    /// the browser closed or the render process terminated.
+    Canceled,
+
+    /// The evaluation result couldn't be sent or converted.
+    Failed(String)
+}
+
+impl Display for JavaScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JavaScriptError::Exception {
+                message,
+                script_resource_name,
+                line_number,
+                ..
+            } => write!(
+                f,
+                "Uncaught exception at {}:{}: {}",
+                script_resource_name, line_number, message
+            ),
+            JavaScriptError::NoContext => write!(f, "The frame has no JavaScript context."),
+            JavaScriptError::Timeout => write!(f, "The evaluation timed out."),
+            JavaScriptError::Canceled => write!(f, "The evaluation was canceled."),
+            JavaScriptError::Failed(message) => write!(f, "The evaluation failed: {}", message)
+        }
+    }
+}
+
+impl std::error::Error for JavaScriptError {}
+
+/// The result of an evaluation.
+type EvaluationResult = std::result::Result<Value, JavaScriptError>;
+
+/// Evaluates JavaScript in a frame and returns the result to the browser
+/// process. Code is sent to the render process in a process message, where
+/// JavaScriptEvaluatorRendererSide evaluates it and sends back the result.
+/// This object must be notified of process messages, navigation, frame
+/// detachment, browser closing and renderer termination so that pending
+/// evaluations complete.
+/// The methods of this object should be called on the browser process UI
+/// thread.
+#[derive(Clone)]
+pub struct JavaScriptEvaluatorBrowserSide(PendingCalls<EvaluationResult>);
+
+impl Default for JavaScriptEvaluatorBrowserSide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JavaScriptEvaluatorBrowserSide {
+    pub fn new() -> Self {
+        Self(PendingCalls::new())
+    }
+
+    /// Evaluates |code| in the main world of |frame| and calls |callback| with
+    /// the result. If |timeout| elapses before a result arrives the callback is
+    /// called with JavaScriptError::Timeout.
+    pub fn evaluate_javascript(
+        &self,
+        frame: &Frame,
+        code: &str,
+        timeout: Option<Duration>,
+        callback: impl FnOnce(std::result::Result<Value, JavaScriptError>) + Send + 'static
+    ) -> Result<()> {
+        let id = self.0.insert(frame, callback)?;
+
+        let message = ProcessMessage::new(EVALUATE_MESSAGE_NAME);
+        let sent = get_argument_list(&message).and_then(|args| {
+            args.set_int(0, id)?;
+            args.set_string(1, code)?;
+
+            frame.send_process_message(ProcessId::Renderer, message)
+        });
+
+        if let Err(e) = sent {
+            self.0
+                .complete(id, Err(JavaScriptError::Failed(e.to_string())));
+
+            return Ok(());
+        }
+
+        if let Some(timeout) = timeout {
+            self.0
+                .complete_after(id, ThreadId::Ui, timeout, Err(JavaScriptError::Timeout))?;
+        }
+
+        Ok(())
+    }
+
+    /// Like evaluate_javascript, but converts the result to JSON.
+    #[cfg(feature = "serde_json")]
+    pub fn evaluate_javascript_json(
+        &self,
+        frame: &Frame,
+        code: &str,
+        timeout: Option<Duration>,
+        callback: impl FnOnce(std::result::Result<serde_json::Value, JavaScriptError>) + Send + 'static
+    ) -> Result<()> {
+        self.evaluate_javascript(frame, code, timeout, move |result| {
+            callback(result.and_then(|value| {
+                serde_json::Value::try_from(&value)
+                    .map_err(|e| JavaScriptError::Failed(e.to_string()))
+            }))
+        })
+    }
+
+    /// Same as evaluate_javascript, but resolves to the result of the
+    /// evaluation.
+    #[cfg(feature = "futures")]
+    pub async fn evaluate_javascript_async(
+        &self,
+        frame: &Frame,
+        code: &str,
+        timeout: Option<Duration>
+    ) -> Result<std::result::Result<Value, JavaScriptError>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.evaluate_javascript(frame, code, timeout, move |result| {
+            let _ = sender.send(result);
+        })?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Returns the number of evaluations waiting for a result.
+    pub fn get_pending_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Cancels all pending evaluations for |frame|.
+    pub fn cancel_for_frame(&self, frame: &Frame) -> Result<()> {
+        self.0
+            .complete_for_frame(frame, Err(JavaScriptError::Canceled))
+    }
+
+    /// Cancels all pending evaluations for |browser|.
+    pub fn cancel_for_browser(&self, browser: &Browser) -> Result<()> {
+        self.0
+            .complete_for_browser(browser, Err(JavaScriptError::Canceled))
+    }
+
+    /// Call from cef_frame_handler_t::on_frame_detached. Pending evaluations
+    /// for |frame| are canceled, as its renderer will never reply.
+    pub fn on_frame_detached(&self, frame: &Frame) -> Result<()> {
+        self.cancel_for_frame(frame)
+    }
+
+    /// Call from cef_life_span_handler_t::on_before_close.
+    pub fn on_before_close(&self, browser: &Browser) -> Result<()> {
+        self.cancel_for_browser(browser)
+    }
+
+    /// Call from cef_request_handler_t::on_render_process_terminated.
+    pub fn on_render_process_terminated(&self, browser: &Browser) -> Result<()> {
+        self.cancel_for_browser(browser)
+    }
+
+    /// Call from cef_request_handler_t::on_before_browse only if the navigation
+    /// is allowed to proceed. Navigating the main frame cancels everything
+    /// pending for the browser, otherwise only |frame| is affected.
+    pub fn on_before_browse(&self, browser: &Browser, frame: &Frame) -> Result<()> {
+        self.0
+            .complete_for_navigation(browser, frame, Err(JavaScriptError::Canceled))
+    }
+
+    /// Call from cef_client_t::on_process_message_received. Returns true if the
+    /// message is handled by this evaluator or false otherwise.
+    pub fn on_process_message_received(&self, message: &ProcessMessage) -> Result<bool> {
+        if message.get_name()? != RESULT_MESSAGE_NAME {
+            return Ok(false);
+        }
+
+        let args = get_argument_list(message)?;
+        let id = args.get_int(0)?;
+
+        // A malformed result still completes the evaluation.
+        let result =
+            parse_result(&args).unwrap_or_else(|e| Err(JavaScriptError::Failed(e.to_string())));
+
+        self.0.complete(id, result);
+
+        Ok(true)
+    }
+}
+
+/// Implements the renderer side of JavaScript evaluation. The methods of this
+/// object must be called on the render process main thread.
+#[derive(Clone, Default)]
+pub struct JavaScriptEvaluatorRendererSide;
+
+impl JavaScriptEvaluatorRendererSide {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Call from cef_render_process_handler_t::on_process_message_received.
+    /// Returns true if the message is handled by this evaluator or false
+    /// otherwise.
+    pub fn on_process_message_received(
+        &self,
+        frame: Frame,
+        source_process: ProcessId,
+        message: &ProcessMessage
+    ) -> Result<bool> {
+        if source_process != ProcessId::Browser || message.get_name()? != EVALUATE_MESSAGE_NAME {
+            return Ok(false);
+        }
+
+        let id = get_argument_list(message)?.get_int(0)?;
+
+        // Always reply so that the browser side evaluation completes.
+        let response = match evaluate(&frame, id, message) {
+            Ok(response) => response,
+            Err(e) => {
+                let response = ProcessMessage::new(RESULT_MESSAGE_NAME);
+                let response_args = get_argument_list(&response)?;
+
+                response_args.set_int(0, id)?;
+                response_args.set_int(1, STATUS_FAILED)?;
+                response_args.set_string(2, &e.to_string())?;
+
+                response
+            }
+        };
+
+        frame.send_process_message(ProcessId::Browser, response)?;
+
+        Ok(true)
+    }
+}
+
+/// Converts the arguments of a result message into an evaluation result.
+fn parse_result(args: &ListValue) -> Result<std::result::Result<Value, JavaScriptError>> {
+    let result = match args.get_int(1)? {
+        STATUS_SUCCESS => args
+            .get_value(2)?
+            .ok_or_else(|| JavaScriptError::Failed(String::from("Missing result."))),
+        STATUS_NO_CONTEXT => Err(JavaScriptError::NoContext),
+        STATUS_EXCEPTION => Err(JavaScriptError::Exception {
+            message:              args
+                .get_string(2)?
+                .unwrap_or_default(),
+            source_line:          args
+                .get_string(3)?
+                .unwrap_or_default(),
+            script_resource_name: args
+                .get_string(4)?
+                .unwrap_or_default(),
+            line_number:          args.get_int(5)?,
+            start_column:         args.get_int(6)?,
+            end_column:           args.get_int(7)?
+        }),
+        _ => Err(JavaScriptError::Failed(
+            args.get_string(2)?
+                .unwrap_or_default()
+        ))
+    };
+
+    Ok(result)
+}
+
+/// Evaluates the code in an evaluation request and returns the result message.
+fn evaluate(frame: &Frame, id: i32, message: &ProcessMessage) -> Result<ProcessMessage> {
+    let code = get_argument_list(message)?
+        .get_string(1)?
+        .unwrap_or_default();
+
+    let response = ProcessMessage::new(RESULT_MESSAGE_NAME);
+    let response_args = get_argument_list(&response)?;
+
+    response_args.set_int(0, id)?;
+
+    match frame.get_v8context()? {
+        Some(context) => match context.eval(&code, "", 1)? {
+            Ok(value) => {
+                if !context.enter()? {
+                    return Err(anyhow!("Failed to enter the V8 context."));
+                }
+
+                let value = value.to_value(MAX_RESULT_DEPTH);
+
+                context.exit()?;
+
+                response_args.set_int(1, STATUS_SUCCESS)?;
+                response_args.set_value(2, value?)?;
+            },
+            Err(exception) => {
+                response_args.set_int(1, STATUS_EXCEPTION)?;
+                response_args.set_string(2, &exception.get_message()?)?;
+                response_args.set_string(3, &exception.get_source_line()?)?;
+                response_args.set_string(4, &exception.get_script_resource_name()?)?;
+                response_args.set_int(5, exception.get_line_number()?)?;
+                response_args.set_int(6, exception.get_start_column()?)?;
+                response_args.set_int(7, exception.get_end_column()?)?;
+            }
+        },
+        None => {
+            response_args.set_int(1, STATUS_NO_CONTEXT)?;
+        }
+    }
+
+    Ok(response)
+}
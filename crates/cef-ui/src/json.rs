@@ -0,0 +1,187 @@
+use crate::{CefString, DictionaryValue, ListValue, Value};
+use bitflags::bitflags;
+use cef_ui_sys::{
+    cef_json_parser_options_t, cef_json_writer_options_t,
+    cef_json_writer_options_t_JSON_WRITER_DEFAULT,
+    cef_json_writer_options_t_JSON_WRITER_OMIT_BINARY_VALUES,
+    cef_json_writer_options_t_JSON_WRITER_OMIT_DOUBLE_TYPE_PRESERVATION,
+    cef_json_writer_options_t_JSON_WRITER_PRETTY_PRINT, cef_parse_jsonand_return_error,
+    cef_write_json
+};
+use std::fmt::{Display, Formatter};
+
+/// Options that can be passed to Value::from_json.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JsonParserOptions {
+    /// Parses the input strictly according to RFC 4627. See comments in
+    /// Chromium's base/json/json_reader.h file for known limitations/
+    /// deviations from the RFC.
+    #[default]
+    Rfc,
+
+    /// Allows commas to exist after the last element in structures.
+    AllowTrailingCommas
+}
+
+impl From<cef_json_parser_options_t> for JsonParserOptions {
+    fn from(value: cef_json_parser_options_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_json_parser_options_t> for JsonParserOptions {
+    fn from(value: &cef_json_parser_options_t) -> Self {
+        match value {
+            cef_json_parser_options_t::JSON_PARSER_RFC => JsonParserOptions::Rfc,
+            cef_json_parser_options_t::JSON_PARSER_ALLOW_TRAILING_COMMAS => {
+                JsonParserOptions::AllowTrailingCommas
+            },
+        }
+    }
+}
+
+impl From<JsonParserOptions> for cef_json_parser_options_t {
+    fn from(value: JsonParserOptions) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&JsonParserOptions> for cef_json_parser_options_t {
+    fn from(value: &JsonParserOptions) -> Self {
+        match value {
+            JsonParserOptions::Rfc => cef_json_parser_options_t::JSON_PARSER_RFC,
+            JsonParserOptions::AllowTrailingCommas => {
+                cef_json_parser_options_t::JSON_PARSER_ALLOW_TRAILING_COMMAS
+            },
+        }
+    }
+}
+
+bitflags! {
+    /// Options that can be passed to Value::to_json. CEF's writer always
+    /// escapes its output, so there is no option to control escaping.
+    #[allow(non_upper_case_globals)]
+    #[derive(Default, Clone, Copy)]
+    pub struct JsonWriterOptions: cef_json_writer_options_t {
+        /// Default behavior.
+        const Default = cef_json_writer_options_t_JSON_WRITER_DEFAULT;
+
+        /// This option instructs the writer that if a Binary value is
+        /// encountered, the value (and key if within a dictionary) will be
+        /// omitted from the output, and success will be returned. Otherwise, if
+        /// a binary value is encountered, failure will be returned.
+        const OmitBinaryValues = cef_json_writer_options_t_JSON_WRITER_OMIT_BINARY_VALUES;
+
+        /// This option instructs the writer to write doubles that have no
+        /// fractional part as a normal integer (i.e., without using exponential
+        /// notation or appending a '.0') as long as the value is within the
+        /// range of a 64-bit int.
+        const OmitDoubleTypePreservation =
+            cef_json_writer_options_t_JSON_WRITER_OMIT_DOUBLE_TYPE_PRESERVATION;
+
+        /// Return a slightly nicer formatted json string (pads with whitespace
+        /// to help with readability).
+        const PrettyPrint = cef_json_writer_options_t_JSON_WRITER_PRETTY_PRINT;
+    }
+}
+
+impl From<cef_json_writer_options_t> for JsonWriterOptions {
+    fn from(value: cef_json_writer_options_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_json_writer_options_t> for JsonWriterOptions {
+    fn from(value: &cef_json_writer_options_t) -> Self {
+        Self::from_bits_truncate(*value)
+    }
+}
+
+impl From<JsonWriterOptions> for cef_json_writer_options_t {
+    fn from(value: JsonWriterOptions) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&JsonWriterOptions> for cef_json_writer_options_t {
+    fn from(value: &JsonWriterOptions) -> Self {
+        value.bits()
+    }
+}
+
+/// Errors that can occur when parsing or writing JSON.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonError {
+    /// The input couldn't be parsed. Contains the error message from CEF.
+    Parse(String),
+
+    /// The value couldn't be written, e.g. because it contains a binary value
+    /// and JsonWriterOptions::OmitBinaryValues wasn't specified.
+    Write
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonError::Parse(message) => write!(f, "Failed to parse JSON: {}", message),
+            JsonError::Write => write!(f, "Failed to write JSON.")
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl Value {
+    /// Parses the specified |json| string and returns a dictionary or list
+    /// representation. If JSON parsing fails this function returns the error
+    /// message reported by CEF.
+    pub fn from_json(json: &str, options: JsonParserOptions) -> Result<Value, JsonError> {
+        let json = CefString::new(json);
+        let mut error = CefString::default();
+
+        unsafe {
+            let value =
+                cef_parse_jsonand_return_error(json.as_ptr(), options.into(), error.as_mut_ptr());
+
+            Value::from_ptr(value).ok_or_else(|| JsonError::Parse(error.into()))
+        }
+    }
+
+    /// Generates a JSON string from the specified root |node| which should be a
+    /// dictionary or list value. Returns an error on failure. This function
+    /// requires exclusive access to |node| including any underlying data.
+    pub fn to_json(&self, options: JsonWriterOptions) -> Result<String, JsonError> {
+        unsafe {
+            let s = cef_write_json(self.clone().into_raw(), options.into());
+
+            CefString::from_userfree_ptr(s)
+                .map(|s| s.into())
+                .ok_or(JsonError::Write)
+        }
+    }
+}
+
+impl DictionaryValue {
+    /// Generates a JSON string from this dictionary. Returns an error on
+    /// failure.
+    pub fn to_json(&self, options: JsonWriterOptions) -> Result<String, JsonError> {
+        let value = Value::new();
+
+        match value.set_dictionary(self.clone()) {
+            Ok(true) => value.to_json(options),
+            _ => Err(JsonError::Write)
+        }
+    }
+}
+
+impl ListValue {
+    /// Generates a JSON string from this list. Returns an error on failure.
+    pub fn to_json(&self, options: JsonWriterOptions) -> Result<String, JsonError> {
+        let value = Value::new();
+
+        match value.set_list(self.clone()) {
+            Ok(true) => value.to_json(options),
+            _ => Err(JsonError::Write)
+        }
+    }
+}
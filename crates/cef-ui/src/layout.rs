@@ -0,0 +1,268 @@
+use crate::{ref_counted_ptr, try_c, view_deref, Insets, ThreadId, View};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_box_layout_settings_t, cef_box_layout_t, cef_cross_axis_alignment_t, cef_fill_layout_t,
+    cef_layout_t, cef_main_axis_alignment_t
+};
+use std::{ffi::c_int, mem::zeroed};
+
+/// Specifies where along the main axis the BoxLayout child views should be
+/// laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MainAxisAlignment {
+    /// Child views will be left-aligned.
+    Start,
+
+    /// Child views will be center-aligned.
+    Center,
+
+    /// Child views will be right-aligned.
+    End
+}
+
+impl From<cef_main_axis_alignment_t> for MainAxisAlignment {
+    fn from(value: cef_main_axis_alignment_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_main_axis_alignment_t> for MainAxisAlignment {
+    fn from(value: &cef_main_axis_alignment_t) -> Self {
+        match value {
+            cef_main_axis_alignment_t::CEF_MAIN_AXIS_ALIGNMENT_START => MainAxisAlignment::Start,
+            cef_main_axis_alignment_t::CEF_MAIN_AXIS_ALIGNMENT_CENTER => MainAxisAlignment::Center,
+            cef_main_axis_alignment_t::CEF_MAIN_AXIS_ALIGNMENT_END => MainAxisAlignment::End
+        }
+    }
+}
+
+impl From<MainAxisAlignment> for cef_main_axis_alignment_t {
+    fn from(value: MainAxisAlignment) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&MainAxisAlignment> for cef_main_axis_alignment_t {
+    fn from(value: &MainAxisAlignment) -> Self {
+        match value {
+            MainAxisAlignment::Start => cef_main_axis_alignment_t::CEF_MAIN_AXIS_ALIGNMENT_START,
+            MainAxisAlignment::Center => cef_main_axis_alignment_t::CEF_MAIN_AXIS_ALIGNMENT_CENTER,
+            MainAxisAlignment::End => cef_main_axis_alignment_t::CEF_MAIN_AXIS_ALIGNMENT_END
+        }
+    }
+}
+
+/// Specifies where along the cross axis the BoxLayout child views should be
+/// laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CrossAxisAlignment {
+    /// Child views will be stretched to fit.
+    Stretch,
+
+    /// Child views will be left-aligned.
+    Start,
+
+    /// Child views will be center-aligned.
+    Center,
+
+    /// Child views will be right-aligned.
+    End
+}
+
+impl From<cef_cross_axis_alignment_t> for CrossAxisAlignment {
+    fn from(value: cef_cross_axis_alignment_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_cross_axis_alignment_t> for CrossAxisAlignment {
+    fn from(value: &cef_cross_axis_alignment_t) -> Self {
+        match value {
+            cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_STRETCH => {
+                CrossAxisAlignment::Stretch
+            },
+            cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_START => CrossAxisAlignment::Start,
+            cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_CENTER => {
+                CrossAxisAlignment::Center
+            },
+            cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_END => CrossAxisAlignment::End
+        }
+    }
+}
+
+impl From<CrossAxisAlignment> for cef_cross_axis_alignment_t {
+    fn from(value: CrossAxisAlignment) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&CrossAxisAlignment> for cef_cross_axis_alignment_t {
+    fn from(value: &CrossAxisAlignment) -> Self {
+        match value {
+            CrossAxisAlignment::Stretch => {
+                cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_STRETCH
+            },
+            CrossAxisAlignment::Start => cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_START,
+            CrossAxisAlignment::Center => {
+                cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_CENTER
+            },
+            CrossAxisAlignment::End => cef_cross_axis_alignment_t::CEF_CROSS_AXIS_ALIGNMENT_END
+        }
+    }
+}
+
+/// Settings used when initializing a BoxLayout. Unset values default to 0,
+/// a vertical layout and start/stretch alignment.
+#[derive(Debug)]
+pub struct BoxLayoutSettings(cef_box_layout_settings_t);
+
+impl Default for BoxLayoutSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoxLayoutSettings {
+    pub fn new() -> Self {
+        Self(unsafe { zeroed() })
+    }
+
+    /// If true (1) the layout will be horizontal, otherwise the layout will be
+    /// vertical.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.0.horizontal = horizontal as c_int;
+        self
+    }
+
+    /// Adds additional horizontal space between the child view area and the host
+    /// view border.
+    pub fn inside_border_horizontal_spacing(mut self, spacing: i32) -> Self {
+        self.0
+            .inside_border_horizontal_spacing = spacing as c_int;
+        self
+    }
+
+    /// Adds additional vertical space between the child view area and the host
+    /// view border.
+    pub fn inside_border_vertical_spacing(mut self, spacing: i32) -> Self {
+        self.0
+            .inside_border_vertical_spacing = spacing as c_int;
+        self
+    }
+
+    /// Adds additional space around the child view area.
+    pub fn inside_border_insets(mut self, insets: &Insets) -> Self {
+        self.0.inside_border_insets = insets.into();
+        self
+    }
+
+    /// Adds additional space between child views.
+    pub fn between_child_spacing(mut self, spacing: i32) -> Self {
+        self.0.between_child_spacing = spacing as c_int;
+        self
+    }
+
+    /// Specifies where along the main axis the child views should be laid out.
+    pub fn main_axis_alignment(mut self, alignment: MainAxisAlignment) -> Self {
+        self.0.main_axis_alignment = alignment.into();
+        self
+    }
+
+    /// Specifies where along the cross axis the child views should be laid out.
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.0.cross_axis_alignment = alignment.into();
+        self
+    }
+
+    /// Minimum cross axis size.
+    pub fn minimum_cross_axis_size(mut self, size: i32) -> Self {
+        self.0.minimum_cross_axis_size = size as c_int;
+        self
+    }
+
+    /// Default flex for views when none is specified via CefBoxLayout methods.
+    /// Using the preferred size as the basis, free space along the main axis is
+    /// distributed to views in the ratio of their flex weights. Similarly, if the
+    /// views will overflow the parent, space is subtracted in these ratios. A
+    /// flex of 0 means this view is not resized. Flex values must not be
+    /// negative.
+    pub fn default_flex(mut self, flex: i32) -> Self {
+        self.0.default_flex = flex as c_int;
+        self
+    }
+
+    /// Converts to the raw cef type.
+    pub fn as_raw(&self) -> &cef_box_layout_settings_t {
+        &self.0
+    }
+}
+
+// A Layout handles the sizing of the children of a Panel according to
+// implementation-specific heuristics. Methods must be called on the browser
+// process UI thread unless otherwise indicated.
+ref_counted_ptr!(Layout, cef_layout_t);
+
+impl Layout {
+    /// Returns this Layout as a BoxLayout or NULL if this is not a BoxLayout.
+    pub fn as_box_layout(&self) -> Result<Option<BoxLayout>> {
+        try_c!(self, as_box_layout, ThreadId::Ui, {
+            Ok(BoxLayout::from_ptr(as_box_layout(self.as_ptr())))
+        })
+    }
+
+    /// Returns this Layout as a FillLayout or NULL if this is not a FillLayout.
+    pub fn as_fill_layout(&self) -> Result<Option<FillLayout>> {
+        try_c!(self, as_fill_layout, ThreadId::Ui, {
+            Ok(FillLayout::from_ptr(as_fill_layout(self.as_ptr())))
+        })
+    }
+
+    /// Returns true (1) if this Layout is valid.
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, ThreadId::Ui, {
+            Ok(is_valid(self.as_ptr()) != 0)
+        })
+    }
+}
+
+// A Layout manager that arranges child views vertically or horizontally in a
+// side-by-side fashion with spacing around and between the child views. The
+// child views are always sized according to their preferred size. If the
+// host's bounds provide insufficient space, child views will be clamped.
+// Excess space will not be distributed. Methods must be called on the browser
+// process UI thread unless otherwise indicated.
+ref_counted_ptr!(BoxLayout, cef_box_layout_t);
+
+view_deref!(BoxLayout, Layout);
+
+impl BoxLayout {
+    /// Set the flex weight for the given |view|. Using the preferred size as the
+    /// basis, free space along the main axis is distributed to views in the ratio
+    /// of their flex weights. Similarly, if the views will overflow the parent,
+    /// space is subtracted in these ratios. A flex of 0 means this view is not
+    /// resized. Flex values must not be negative.
+    pub fn set_flex_for_view(&self, view: View, flex: i32) -> Result<()> {
+        try_c!(self, set_flex_for_view, ThreadId::Ui, {
+            Ok(set_flex_for_view(
+                self.as_ptr(),
+                view.into_raw(),
+                flex as c_int
+            ))
+        })
+    }
+
+    /// Clears the flex for the given |view|, causing it to use the default flex
+    /// specified via BoxLayoutSettings::default_flex().
+    pub fn clear_flex_for_view(&self, view: View) -> Result<()> {
+        try_c!(self, clear_flex_for_view, ThreadId::Ui, {
+            Ok(clear_flex_for_view(self.as_ptr(), view.into_raw()))
+        })
+    }
+}
+
+// A simple Layout that causes the associated Panel's one child to be sized to
+// match the bounds of its parent. Methods must be called on the browser process
+// UI thread unless otherwise indicated.
+ref_counted_ptr!(FillLayout, cef_fill_layout_t);
+
+view_deref!(FillLayout, Layout);
@@ -2,81 +2,156 @@ mod accessibility_handler;
 mod app;
 mod browser;
 mod browser_process_handler;
+mod browser_view;
+mod button;
 mod callbacks;
 mod client;
 mod color;
 mod command_line;
 mod context;
 mod context_menu_handler;
+mod dev_tools_message_observer;
+mod display;
+mod dom;
 mod drag;
 mod events;
 mod extension;
 mod extension_handler;
 mod frame;
+mod frame_handler;
+mod image;
 mod ime;
+mod javascript_evaluator;
+mod json;
 mod keyboard_handler;
+mod layout;
 mod life_span_handler;
 mod macros;
+mod menu_button;
+mod message_pump;
+mod message_router;
 mod navigation_entry;
+mod overlay_controller;
+mod panel;
+mod pending_call;
 mod platform;
 mod process;
 mod refcounted;
+mod registration;
 mod render_handler;
+mod render_process_handler;
 mod request;
 mod request_context;
 mod request_context_handler;
 mod request_handler;
 mod resource_request_handler;
 mod response;
+mod rpc;
+mod scroll_view;
+mod server;
 mod settings;
 mod shared_memory_region;
+mod shared_process_message_builder;
 mod ssl;
+mod stream;
 mod string;
+mod task;
+mod textfield;
+mod thread;
 mod time;
+mod trace;
+#[cfg(feature = "tracing")]
+mod trace_layer;
 mod types;
 mod url_request;
+mod url_util;
+mod v8;
+#[cfg(feature = "serde")]
+mod value_serde;
 mod values;
+mod view;
+mod waitable_event;
+mod window;
 mod x509_certificate;
+mod xml_reader;
+mod zip_reader;
 
 pub use accessibility_handler::*;
 pub use app::*;
 pub use browser::*;
 pub use browser_process_handler::*;
+pub use browser_view::*;
+pub use button::*;
 pub use callbacks::*;
 pub use client::*;
 pub use color::*;
 pub use command_line::*;
 pub use context::*;
 pub use context_menu_handler::*;
+pub use dev_tools_message_observer::*;
+pub use display::*;
+pub use dom::*;
 pub use drag::*;
 pub use events::*;
 pub use extension::*;
 pub use extension_handler::*;
 pub use frame::*;
+pub use frame_handler::*;
+pub use image::*;
 pub use ime::*;
+pub use javascript_evaluator::*;
+pub use json::*;
 pub use keyboard_handler::*;
+pub use layout::*;
 pub use life_span_handler::*;
 pub use macros::*;
+pub use menu_button::*;
+pub use message_pump::*;
+pub use message_router::*;
 pub use navigation_entry::*;
+pub use overlay_controller::*;
+pub use panel::*;
 pub use platform::*;
 pub use process::*;
 pub use refcounted::*;
+pub use registration::*;
 pub use render_handler::*;
+pub use render_process_handler::*;
 pub use request::*;
 pub use request_context::*;
 pub use request_context_handler::*;
 pub use request_handler::*;
 pub use resource_request_handler::*;
 pub use response::*;
+pub use rpc::*;
+pub use scroll_view::*;
+pub use server::*;
 pub use settings::*;
 pub use shared_memory_region::*;
+pub use shared_process_message_builder::*;
 pub use ssl::*;
+pub use stream::*;
 pub use string::*;
+pub use task::*;
+pub use textfield::*;
+pub use thread::*;
 pub use time::*;
+pub use trace::*;
+#[cfg(feature = "tracing")]
+pub use trace_layer::*;
 pub use types::*;
 pub use url_request::*;
+pub use url_util::*;
+pub use v8::*;
+#[cfg(feature = "serde")]
+pub use value_serde::*;
 pub use values::*;
+pub use view::*;
+pub use waitable_event::*;
+pub use window::*;
 pub use x509_certificate::*;
+pub use xml_reader::*;
+pub use zip_reader::*;
 
 // TODO: Make a macro for all the pointer conversion functions.
 
@@ -1,8 +1,10 @@
+use crate::ThreadId;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
 pub enum FfiError {
-    MissingFunctionPointer(&'static str)
+    MissingFunctionPointer(&'static str),
+    WrongThread(&'static str, ThreadId)
 }
 
 impl Display for FfiError {
@@ -10,6 +12,13 @@ impl Display for FfiError {
         match *self {
             FfiError::MissingFunctionPointer(name) => {
                 write!(f, "Missing function pointer: {}", name)
+            },
+            FfiError::WrongThread(name, thread_id) => {
+                write!(
+                    f,
+                    "Function {} must be called on thread {:?}",
+                    name, thread_id
+                )
             }
         }
     }
@@ -19,7 +28,9 @@ impl std::error::Error for FfiError {}
 
 /// A macro to simplify the process of calling of calling CEF functions.
 /// This will check if the function pointer is available and return an
-/// error if it is not.
+/// error if it is not. If a thread ID is passed, debug builds will also
+/// check that the function is called on that thread and return an error
+/// if it is not.
 #[macro_export]
 macro_rules! try_c {
     ($self:ident, $name:ident, $body:block) => {{
@@ -31,4 +42,17 @@ macro_rules! try_c {
             .ok_or_else(|| anyhow!($crate::FfiError::MissingFunctionPointer(stringify!($name))))
             .and_then(|$name| unsafe { $body })
     }};
+
+    ($self:ident, $name:ident, $thread_id:expr, $body:block) => {{
+        use anyhow::anyhow;
+
+        if cfg!(debug_assertions) && !$crate::currently_on($thread_id) {
+            Err(anyhow!($crate::FfiError::WrongThread(
+                stringify!($name),
+                $thread_id
+            )))
+        } else {
+            $crate::try_c!($self, $name, $body)
+        }
+    }};
 }
@@ -0,0 +1,160 @@
+use crate::{
+    button_delegate, ref_counted_ptr, try_c, view_deref, ButtonDelegateCallbacks,
+    ButtonDelegateWrappable, CefString, LabelButton, MenuAnchorPosition, MenuModel, Point,
+    RefCountedPtr, ThreadId, View, ViewDelegateCallbacks, ViewDelegateWrappable, Wrappable,
+    Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_menu_button_create, cef_menu_button_delegate_t, cef_menu_button_pressed_lock_t,
+    cef_menu_button_t, cef_point_t
+};
+
+// MenuButton pressed lock is released when this object is destroyed.
+ref_counted_ptr!(MenuButtonPressedLock, cef_menu_button_pressed_lock_t);
+
+/// Implement this structure to handle MenuButton events. The functions of
+/// this structure will be called on the browser process UI thread unless
+/// otherwise indicated.
+pub trait MenuButtonDelegateCallbacks: ButtonDelegateCallbacks {
+    /// Called when |button| is pressed. Call cef_menu_button_t::show_menu() to
+    /// show a popup menu at |screen_point|. When showing a custom popup such as a
+    /// window keep a reference to |button_pressed_lock| until the popup is hidden
+    /// to maintain the pressed button state.
+    fn on_menu_button_pressed(
+        &mut self,
+        menu_button: MenuButton,
+        screen_point: &Point,
+        button_pressed_lock: MenuButtonPressedLock
+    );
+}
+
+// Implement this structure to handle MenuButton events. The functions of this
+// structure will be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(MenuButtonDelegate, cef_menu_button_delegate_t);
+
+impl MenuButtonDelegate {
+    pub fn new<C: MenuButtonDelegateCallbacks>(delegate: C) -> Self {
+        Self(MenuButtonDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct MenuButtonDelegateWrapper(Box<dyn MenuButtonDelegateCallbacks>);
+
+impl MenuButtonDelegateWrapper {
+    pub fn new<C: MenuButtonDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Called when |button| is pressed. Call cef_menu_button_t::show_menu() to
+    /// show a popup menu at |screen_point|. When showing a custom popup such as a
+    /// window keep a reference to |button_pressed_lock| until the popup is hidden
+    /// to maintain the pressed button state.
+    unsafe extern "C" fn c_on_menu_button_pressed(
+        this: *mut cef_menu_button_delegate_t,
+        menu_button: *mut cef_menu_button_t,
+        screen_point: *const cef_point_t,
+        button_pressed_lock: *mut cef_menu_button_pressed_lock_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let menu_button = MenuButton::from_ptr_unchecked(menu_button);
+        let screen_point: Point = (*screen_point).into();
+        let button_pressed_lock = MenuButtonPressedLock::from_ptr_unchecked(button_pressed_lock);
+
+        this.0
+            .on_menu_button_pressed(menu_button, &screen_point, button_pressed_lock);
+    }
+}
+
+impl ViewDelegateWrappable for MenuButtonDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl ButtonDelegateWrappable for MenuButtonDelegateWrapper {
+    fn button_delegate(&mut self) -> &mut dyn ButtonDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for MenuButtonDelegateWrapper {
+    type Cef = cef_menu_button_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_menu_button_delegate_t> {
+        RefCountedPtr::wrap(
+            cef_menu_button_delegate_t {
+                base:                   button_delegate::<Self>(),
+                on_menu_button_pressed: Some(Self::c_on_menu_button_pressed)
+            },
+            self
+        )
+    }
+}
+
+// MenuButton is a button with optional text, icon and/or menu marker that
+// shows a menu when clicked with the left mouse button. All size and position
+// values are in density independent pixels (DIP) unless otherwise indicated.
+// Methods must be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(MenuButton, cef_menu_button_t);
+
+view_deref!(MenuButton, LabelButton);
+
+impl MenuButton {
+    /// Create a new MenuButton. A |delegate| must be provided to call show_menu()
+    /// when the button is clicked. |text| will be shown on the MenuButton and
+    /// used as the default accessible name.
+    pub fn create(delegate: MenuButtonDelegate, text: &str) -> Option<MenuButton> {
+        unsafe {
+            let text = CefString::new(text);
+
+            MenuButton::from_ptr(cef_menu_button_create(delegate.into_raw(), text.as_ptr()))
+        }
+    }
+
+    /// Show a menu with contents |menu_model|. |screen_point| specifies the menu
+    /// position in screen coordinates. |anchor_position| specifies how the menu
+    /// will be anchored relative to |screen_point|. This function should be
+    /// called from cef_menu_button_delegate_t::on_menu_button_pressed().
+    pub fn show_menu(
+        &self,
+        menu_model: MenuModel,
+        screen_point: &Point,
+        anchor_position: MenuAnchorPosition
+    ) -> Result<()> {
+        try_c!(self, show_menu, ThreadId::Ui, {
+            let screen_point: cef_point_t = screen_point.into();
+
+            Ok(show_menu(
+                self.as_ptr(),
+                menu_model.into_raw(),
+                &screen_point,
+                anchor_position.into()
+            ))
+        })
+    }
+
+    /// Show the menu for this button. Results in a call to
+    /// cef_menu_button_delegate_t::on_menu_button_pressed().
+    pub fn trigger_menu(&self) -> Result<()> {
+        try_c!(self, trigger_menu, ThreadId::Ui, {
+            Ok(trigger_menu(self.as_ptr()))
+        })
+    }
+}
+
+impl From<MenuButton> for LabelButton {
+    fn from(value: MenuButton) -> Self {
+        unsafe { LabelButton::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
+
+impl From<MenuButton> for View {
+    fn from(value: MenuButton) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
@@ -0,0 +1,143 @@
+use cef_ui_sys::cef_do_message_loop_work;
+use parking_lot::Mutex;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant}
+};
+
+/// The maximum time between calls to cef_do_message_loop_work (30 fps).
+const MAX_TIMER_DELAY: Duration = Duration::from_millis(1000 / 30);
+
+/// The callback used to wake up the foreign event loop.
+type Waker = Arc<dyn Fn() + Send + Sync + 'static>;
+
+/// The shared state of the message pump.
+#[derive(Default)]
+struct MessagePumpState {
+    deadline:            Option<Instant>,
+    is_active:           bool,
+    reentrancy_detected: bool,
+    waker:               Option<Waker>
+}
+
+/// Drives the CEF message loop from a foreign event loop when
+/// cef_settings_t.external_message_pump is enabled. Forward
+/// cef_browser_process_handler_t::on_schedule_message_pump_work() calls to
+/// on_schedule_message_pump_work(), sleep in the foreign event loop until
+/// next_deadline() or until the waker is called, and then call pump() on the
+/// main application thread.
+///
+/// Work requests are coalesced so that only the most recent one is kept, the
+/// message loop is pumped at least every 1/30th of a second so that CEF timers
+/// keep running, and reentrant calls into cef_do_message_loop_work() (for
+/// example from nested native message loops) are detected and rescheduled
+/// instead of being executed.
+#[derive(Clone, Default)]
+pub struct MessagePump(Arc<Mutex<MessagePumpState>>);
+
+impl MessagePump {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the function that is called whenever the deadline changes. This
+    /// may be called on any thread and should wake up the foreign event loop
+    /// so that it can check next_deadline() again. For example, this could
+    /// ping a calloop source, wake a mio poll or send a winit user event.
+    pub fn set_waker(&self, waker: impl Fn() + Send + Sync + 'static) {
+        self.0.lock().waker = Some(Arc::new(waker));
+    }
+
+    /// Call from cef_browser_process_handler_t::on_schedule_message_pump_work().
+    /// This may be called on any thread. If |delay_ms| is <= 0 the message loop
+    /// should be pumped as soon as possible, otherwise it should be pumped after
+    /// |delay_ms| milliseconds, capped at 1/30th of a second. A new request
+    /// replaces any pending one.
+    pub fn on_schedule_message_pump_work(&self, delay_ms: i64) {
+        let delay = match delay_ms {
+            ..=0 => Duration::ZERO,
+            _ => Duration::from_millis(delay_ms as u64).min(MAX_TIMER_DELAY)
+        };
+
+        self.schedule(delay);
+    }
+
+    /// Returns the time at which pump() should next be called, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.0.lock().deadline
+    }
+
+    /// Performs a single iteration of CEF message loop processing if the
+    /// deadline has been reached. Returns true if work was performed. This must
+    /// be called on the main application thread.
+    pub fn pump(&self) -> bool {
+        {
+            let mut state = self.0.lock();
+
+            if state
+                .deadline
+                .is_none_or(|deadline| deadline > Instant::now())
+            {
+                return false;
+            }
+
+            state.deadline = None;
+        }
+
+        self.do_work();
+
+        true
+    }
+
+    /// Runs cef_do_message_loop_work() unless it is already running, and then
+    /// schedules the next iteration.
+    fn do_work(&self) {
+        {
+            let mut state = self.0.lock();
+
+            if state.is_active {
+                // A nested message loop called back into the pump.
+                state.reentrancy_detected = true;
+
+                return;
+            }
+
+            state.is_active = true;
+            state.reentrancy_detected = false;
+        }
+
+        // The lock must not be held here, as CEF may call
+        // on_schedule_message_pump_work() from inside.
+        unsafe { cef_do_message_loop_work() };
+
+        let delay = {
+            let mut state = self.0.lock();
+
+            state.is_active = false;
+
+            match (state.reentrancy_detected, state.deadline) {
+                (true, _) => Some(Duration::ZERO),
+                (false, None) => Some(MAX_TIMER_DELAY),
+                (false, Some(_)) => None
+            }
+        };
+
+        if let Some(delay) = delay {
+            self.schedule(delay);
+        }
+    }
+
+    /// Replaces the deadline and wakes up the foreign event loop.
+    fn schedule(&self, delay: Duration) {
+        let waker = {
+            let mut state = self.0.lock();
+
+            state.deadline = Some(Instant::now() + delay);
+            state.waker.clone()
+        };
+
+        if let Some(waker) = waker {
+            waker();
+        }
+    }
+}
@@ -0,0 +1,1027 @@
+use crate::{
+    get_argument_list, Browser, Frame, ProcessId, ProcessMessage, V8Context, V8Handler,
+    V8HandlerCallbacks, V8PropertyAttribute, V8Value
+};
+use anyhow::Result;
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc};
+
+/// The error code sent to the renderer when a query is canceled or
+/// unhandled by the browser process.
+pub const MESSAGE_ROUTER_CANCELED_ERROR_CODE: i32 = -1;
+
+/// Used to configure the query router. The same values must be passed to both
+/// MessageRouterBrowserSide and MessageRouterRendererSide. If using multiple
+/// router pairs make sure to choose values that do not conflict.
+#[derive(Clone, Debug)]
+pub struct MessageRouterConfig {
+    js_query_function:  String,
+    js_cancel_function: String
+}
+
+impl Default for MessageRouterConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageRouterConfig {
+    pub fn new() -> Self {
+        Self {
+            js_query_function:  String::from("cefQuery"),
+            js_cancel_function: String::from("cefQueryCancel")
+        }
+    }
+
+    /// Name of the JavaScript function that will be added to the 'window' object
+    /// for sending a query. The default value is "cefQuery".
+    pub fn js_query_function(mut self, value: &str) -> Self {
+        self.js_query_function = value.to_string();
+        self
+    }
+
+    /// Name of the JavaScript function that will be added to the 'window' object
+    /// for canceling a pending query. The default value is "cefQueryCancel".
+    pub fn js_cancel_function(mut self, value: &str) -> Self {
+        self.js_cancel_function = value.to_string();
+        self
+    }
+
+    /// The name of the process message used for queries and their responses.
+    fn query_message_name(&self) -> String {
+        format!("{}Msg", self.js_query_function)
+    }
+
+    /// The name of the process message used for canceling queries.
+    fn cancel_message_name(&self) -> String {
+        format!("{}Msg", self.js_cancel_function)
+    }
+}
+
+/// Implement this trait to handle queries sent from JavaScript via
+/// window.cefQuery. All functions will be called on the browser process UI
+/// thread. No router locks are held while these functions run, so they may
+/// call back into the router, e.g. to cancel queries or remove handlers. Use
+/// interior mutability for any state that the handler keeps.
+pub trait MessageRouterHandler: Send + Sync + 'static {
+    /// Executed when a new query is received. |query_id| uniquely identifies the
+    /// query for the life span of the router. Return true to handle the query
+    /// or false to propagate the query to other registered handlers, if any. If
+    /// no handlers return true from this function then the query will be
+    /// automatically canceled with an error code of -1 delivered to the
+    /// JavaScript onFailure callback. If this function returns true then a
+    /// callback function must be executed either in this function or
+    /// asynchronously to complete the query.
+    fn on_query(
+        &self,
+        browser: Browser,
+        frame: Frame,
+        query_id: i64,
+        request: &str,
+        persistent: bool,
+        callback: MessageRouterCallback
+    ) -> bool;
+
+    /// Executed when a query has been canceled either explicitly using the
+    /// JavaScript cancel function or implicitly due to browser destruction,
+    /// navigation or renderer process termination. It will only be called for
+    /// the single handler that returned true from on_query for the same
+    /// |query_id|. No references to the associated callback object should be
+    /// kept after this function is called, nor should any of its functions be
+    /// executed.
+    fn on_query_canceled(&self, browser: Browser, frame: Frame, query_id: i64);
+}
+
+/// A pending query in the browser process.
+struct BrowserQuery {
+    browser:    Browser,
+    browser_id: i32,
+    frame:      Frame,
+    frame_id:   i64,
+    context_id: i32,
+    request_id: i32,
+    persistent: bool,
+    handler_id: Option<usize>
+}
+
+/// A registered browser process handler.
+type SharedHandler = Arc<dyn MessageRouterHandler>;
+
+/// The shared state of the browser side router.
+struct BrowserSideState {
+    config:          MessageRouterConfig,
+    handlers:        Vec<(usize, SharedHandler)>,
+    next_handler_id: usize,
+    next_query_id:   i64,
+    queries:         HashMap<i64, BrowserQuery>
+}
+
+impl BrowserSideState {
+    /// Sends a response to the renderer that issued a query.
+    fn send_response(
+        &self,
+        query: &BrowserQuery,
+        response: std::result::Result<&str, (i32, &str)>
+    ) -> Result<()> {
+        let message = ProcessMessage::new(&self.config.query_message_name());
+        let args = get_argument_list(&message)?;
+
+        args.set_int(0, query.context_id)?;
+        args.set_int(1, query.request_id)?;
+
+        match response {
+            Ok(response) => {
+                args.set_bool(2, true)?;
+                args.set_string(3, response)?;
+            },
+            Err((error_code, error_message)) => {
+                args.set_bool(2, false)?;
+                args.set_int(3, error_code)?;
+                args.set_string(4, error_message)?;
+            }
+        }
+
+        query
+            .frame
+            .send_process_message(ProcessId::Renderer, message)
+    }
+
+    /// Returns the handler with the specified id, if it's still registered.
+    fn get_handler(&self, handler_id: Option<usize>) -> Option<SharedHandler> {
+        handler_id.and_then(|handler_id| {
+            self.handlers
+                .iter()
+                .find(|(id, _)| *id == handler_id)
+                .map(|(_, handler)| handler.clone())
+        })
+    }
+}
+
+/// Callback associated with a single pending asynchronous query. Execute the
+/// success or failure function to send the asynchronous response to the
+/// associated JavaScript handler. It is a runtime error to destroy a callback
+/// object associated with an uncanceled query without first executing one of
+/// the callback functions. The methods of this object may be called on any
+/// thread.
+#[derive(Clone)]
+pub struct MessageRouterCallback {
+    state:    Arc<Mutex<BrowserSideState>>,
+    query_id: i64
+}
+
+impl MessageRouterCallback {
+    /// Notify the associated JavaScript onSuccess callback that the query has
+    /// completed successfully with the specified |response|. Non-persistent
+    /// queries are completed by this call. Does nothing if the query has already
+    /// been canceled or completed.
+    pub fn success(&self, response: &str) -> Result<()> {
+        let mut state = self.state.lock();
+
+        let persistent = match state.queries.get(&self.query_id) {
+            Some(query) => query.persistent,
+            None => return Ok(())
+        };
+
+        let query = match persistent {
+            true => None,
+            false => state.queries.remove(&self.query_id)
+        };
+
+        match &query {
+            Some(query) => state.send_response(query, Ok(response)),
+            None => state.send_response(&state.queries[&self.query_id], Ok(response))
+        }
+    }
+
+    /// Notify the associated JavaScript onFailure callback that the query has
+    /// failed with the specified |error_code| and |error_message|. The query is
+    /// always completed by this call. Does nothing if the query has already been
+    /// canceled or completed.
+    pub fn failure(&self, error_code: i32, error_message: &str) -> Result<()> {
+        let mut state = self.state.lock();
+
+        match state.queries.remove(&self.query_id) {
+            Some(query) => state.send_response(&query, Err((error_code, error_message))),
+            None => Ok(())
+        }
+    }
+}
+
+/// Implements the browser side of query routing. Queries sent from JavaScript
+/// via window.cefQuery are delivered to the registered MessageRouterHandler
+/// instances. This object must be notified of process messages, browser
+/// closing, navigation and renderer termination so that pending queries can
+/// be canceled. The methods of this object should be called on the browser
+/// process UI thread.
+#[derive(Clone)]
+pub struct MessageRouterBrowserSide(Arc<Mutex<BrowserSideState>>);
+
+impl MessageRouterBrowserSide {
+    pub fn new(config: MessageRouterConfig) -> Self {
+        Self(Arc::new(Mutex::new(BrowserSideState {
+            config,
+            handlers: Vec::new(),
+            next_handler_id: 0,
+            next_query_id: 0,
+            queries: HashMap::new()
+        })))
+    }
+
+    /// Add a new query handler. If |first| is true it will be added as the first
+    /// handler, otherwise it will be added as the last handler. Returns an id
+    /// that can be passed to remove_handler.
+    pub fn add_handler<H: MessageRouterHandler>(&self, handler: H, first: bool) -> usize {
+        let mut state = self.0.lock();
+        let handler_id = state.next_handler_id;
+        let handler: SharedHandler = Arc::new(handler);
+
+        state.next_handler_id += 1;
+
+        match first {
+            true => state
+                .handlers
+                .insert(0, (handler_id, handler)),
+            false => state
+                .handlers
+                .push((handler_id, handler))
+        }
+
+        handler_id
+    }
+
+    /// Remove an existing query handler. Any pending queries associated with
+    /// the handler will be canceled. on_query_canceled will be called for each
+    /// and the JavaScript onFailure callback will be executed with an error code
+    /// of -1. Returns true if the handler was removed.
+    pub fn remove_handler(&self, handler_id: usize) -> Result<bool> {
+        let removed = {
+            let mut state = self.0.lock();
+            let len = state.handlers.len();
+
+            state
+                .handlers
+                .retain(|(id, _)| *id != handler_id);
+
+            state.handlers.len() != len
+        };
+
+        if removed {
+            self.cancel_where(true, |query| query.handler_id == Some(handler_id))?;
+        }
+
+        Ok(removed)
+    }
+
+    /// Cancel all pending queries associated with either |browser| or
+    /// |handler_id|. If both |browser| and |handler_id| are None all pending
+    /// queries will be canceled. on_query_canceled will be called for each
+    /// canceled query.
+    pub fn cancel_pending(
+        &self,
+        browser: Option<&Browser>,
+        handler_id: Option<usize>
+    ) -> Result<()> {
+        let browser_id = browser
+            .map(|browser| browser.get_identifier())
+            .transpose()?;
+
+        self.cancel_where(false, |query| {
+            browser_id.is_none_or(|id| query.browser_id == id)
+                && handler_id.is_none_or(|id| query.handler_id == Some(id))
+        })
+    }
+
+    /// Returns the number of pending queries, optionally restricted to
+    /// |browser| and/or |handler_id|.
+    pub fn get_pending_count(
+        &self,
+        browser: Option<&Browser>,
+        handler_id: Option<usize>
+    ) -> Result<usize> {
+        let browser_id = browser
+            .map(|browser| browser.get_identifier())
+            .transpose()?;
+
+        Ok(self
+            .0
+            .lock()
+            .queries
+            .values()
+            .filter(|query| {
+                browser_id.is_none_or(|id| query.browser_id == id)
+                    && handler_id.is_none_or(|id| query.handler_id == Some(id))
+            })
+            .count())
+    }
+
+    /// Call from cef_life_span_handler_t::on_before_close. Any pending queries
+    /// associated with |browser| will be canceled.
+    pub fn on_before_close(&self, browser: &Browser) -> Result<()> {
+        self.cancel_pending(Some(browser), None)
+    }
+
+    /// Call from cef_request_handler_t::on_render_process_terminated. Any
+    /// pending queries associated with |browser| will be canceled.
+    pub fn on_render_process_terminated(&self, browser: &Browser) -> Result<()> {
+        self.cancel_pending(Some(browser), None)
+    }
+
+    /// Call from cef_request_handler_t::on_before_browse only if the navigation
+    /// is allowed to proceed. If |frame| is the main frame then any pending
+    /// queries associated with |browser| will be canceled, otherwise only the
+    /// queries associated with |frame| will be canceled.
+    pub fn on_before_browse(&self, browser: &Browser, frame: &Frame) -> Result<()> {
+        match frame.is_main()? {
+            true => self.cancel_pending(Some(browser), None),
+            false => {
+                let browser_id = browser.get_identifier()?;
+                let frame_id = frame.get_identifier()?;
+
+                self.cancel_where(false, |query| {
+                    query.browser_id == browser_id && query.frame_id == frame_id
+                })
+            }
+        }
+    }
+
+    /// Call from cef_client_t::on_process_message_received. Returns true if the
+    /// message is handled by this router or false otherwise.
+    pub fn on_process_message_received(
+        &self,
+        browser: Browser,
+        frame: Frame,
+        message: &ProcessMessage
+    ) -> Result<bool> {
+        let name = message.get_name()?;
+        let (query_message_name, cancel_message_name) = {
+            let state = self.0.lock();
+
+            (
+                state.config.query_message_name(),
+                state.config.cancel_message_name()
+            )
+        };
+
+        if name == query_message_name {
+            let args = get_argument_list(message)?;
+            let context_id = args.get_int(0)?;
+            let request_id = args.get_int(1)?;
+            let request = args
+                .get_string(2)?
+                .unwrap_or_default();
+            let persistent = args.get_bool(3)?;
+
+            self.on_query(browser, frame, context_id, request_id, &request, persistent)?;
+
+            Ok(true)
+        } else if name == cancel_message_name {
+            let args = get_argument_list(message)?;
+            let context_id = args.get_int(0)?;
+            let request_id = args.get_int(1)?;
+            let browser_id = browser.get_identifier()?;
+
+            self.cancel_where(false, |query| {
+                query.browser_id == browser_id
+                    && query.context_id == context_id
+                    && (request_id == -1 || query.request_id == request_id)
+            })?;
+
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Offers a new query to each registered handler in order.
+    fn on_query(
+        &self,
+        browser: Browser,
+        frame: Frame,
+        context_id: i32,
+        request_id: i32,
+        request: &str,
+        persistent: bool
+    ) -> Result<()> {
+        let (query_id, handlers) = {
+            let mut state = self.0.lock();
+            let query_id = state.next_query_id;
+
+            state.next_query_id += 1;
+            state.queries.insert(
+                query_id,
+                BrowserQuery {
+                    browser: browser.clone(),
+                    browser_id: browser.get_identifier()?,
+                    frame: frame.clone(),
+                    frame_id: frame.get_identifier()?,
+                    context_id,
+                    request_id,
+                    persistent,
+                    handler_id: None
+                }
+            );
+
+            (query_id, state.handlers.clone())
+        };
+
+        for (handler_id, handler) in handlers {
+            match self
+                .0
+                .lock()
+                .queries
+                .get_mut(&query_id)
+            {
+                Some(query) => query.handler_id = Some(handler_id),
+                None => return Ok(())
+            }
+
+            let callback = MessageRouterCallback {
+                state: self.0.clone(),
+                query_id
+            };
+
+            let handled = handler.on_query(
+                browser.clone(),
+                frame.clone(),
+                query_id,
+                request,
+                persistent,
+                callback
+            );
+
+            if handled {
+                return Ok(());
+            }
+        }
+
+        // No handler accepted the query.
+        let mut state = self.0.lock();
+
+        match state.queries.remove(&query_id) {
+            Some(query) => state.send_response(
+                &query,
+                Err((
+                    MESSAGE_ROUTER_CANCELED_ERROR_CODE,
+                    "The query was not handled."
+                ))
+            ),
+            None => Ok(())
+        }
+    }
+
+    /// Cancels all pending queries matching |predicate|.
+    fn cancel_where<P>(&self, notify_renderer: bool, predicate: P) -> Result<()>
+    where
+        P: Fn(&BrowserQuery) -> bool
+    {
+        let canceled: Vec<(i64, BrowserQuery, Option<SharedHandler>)> = {
+            let mut state = self.0.lock();
+            let query_ids: Vec<i64> = state
+                .queries
+                .iter()
+                .filter(|(_, query)| predicate(query))
+                .map(|(query_id, _)| *query_id)
+                .collect();
+
+            query_ids
+                .into_iter()
+                .filter_map(|query_id| {
+                    state
+                        .queries
+                        .remove(&query_id)
+                        .map(|query| {
+                            let handler = state.get_handler(query.handler_id);
+
+                            (query_id, query, handler)
+                        })
+                })
+                .collect()
+        };
+
+        for (query_id, query, handler) in canceled {
+            if notify_renderer {
+                self.0.lock().send_response(
+                    &query,
+                    Err((
+                        MESSAGE_ROUTER_CANCELED_ERROR_CODE,
+                        "The query has been canceled."
+                    ))
+                )?;
+            }
+
+            if let Some(handler) = handler {
+                handler.on_query_canceled(query.browser.clone(), query.frame.clone(), query_id);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A pending query in the render process.
+#[derive(Clone)]
+struct RendererRequest {
+    persistent:       bool,
+    success_callback: V8Value,
+    failure_callback: Option<V8Value>
+}
+
+/// A V8 context tracked by the renderer side router.
+struct RendererContext {
+    context:  V8Context,
+    frame:    Frame,
+    requests: HashMap<i32, RendererRequest>
+}
+
+/// The shared state of the renderer side router.
+struct RendererSideState {
+    config:          MessageRouterConfig,
+    next_context_id: i32,
+    next_request_id: i32,
+    contexts:        HashMap<i32, RendererContext>
+}
+
+/// Implements the renderer side of query routing. This object injects the
+/// window.cefQuery and window.cefQueryCancel functions into each V8 context
+/// and forwards queries to the browser process. The methods of this object
+/// must be called on the render process main thread.
+#[derive(Clone)]
+pub struct MessageRouterRendererSide(Arc<Mutex<RendererSideState>>);
+
+impl MessageRouterRendererSide {
+    pub fn new(config: MessageRouterConfig) -> Self {
+        Self(Arc::new(Mutex::new(RendererSideState {
+            config,
+            next_context_id: 0,
+            next_request_id: 0,
+            contexts: HashMap::new()
+        })))
+    }
+
+    /// Returns the number of pending queries, optionally restricted to
+    /// |context|.
+    pub fn get_pending_count(&self, context: Option<&V8Context>) -> Result<usize> {
+        let state = self.0.lock();
+        let mut count = 0;
+
+        for info in state.contexts.values() {
+            if let Some(context) = context {
+                if !info
+                    .context
+                    .is_same(context.clone())?
+                {
+                    continue;
+                }
+            }
+
+            count += info.requests.len();
+        }
+
+        Ok(count)
+    }
+
+    /// Call from cef_render_process_handler_t::on_context_created. Registers
+    /// the JavaScript functions with the new context.
+    pub fn on_context_created(
+        &self,
+        _browser: Browser,
+        frame: Frame,
+        context: V8Context
+    ) -> Result<()> {
+        let (context_id, config) = {
+            let mut state = self.0.lock();
+            let context_id = state.next_context_id;
+
+            state.next_context_id += 1;
+            state.contexts.insert(
+                context_id,
+                RendererContext {
+                    context: context.clone(),
+                    frame,
+                    requests: HashMap::new()
+                }
+            );
+
+            (context_id, state.config.clone())
+        };
+
+        let window = context.get_global()?;
+        let query = V8Value::new_function(
+            &config.js_query_function,
+            V8Handler::new(QueryFunction {
+                state: self.0.clone(),
+                context_id
+            })
+        );
+        let cancel = V8Value::new_function(
+            &config.js_cancel_function,
+            V8Handler::new(CancelFunction {
+                state: self.0.clone(),
+                context_id
+            })
+        );
+
+        window.set_value_bykey(
+            &config.js_query_function,
+            query,
+            V8PropertyAttribute::ReadOnly
+        )?;
+        window.set_value_bykey(
+            &config.js_cancel_function,
+            cancel,
+            V8PropertyAttribute::ReadOnly
+        )?;
+
+        Ok(())
+    }
+
+    /// Call from cef_render_process_handler_t::on_context_released. Any pending
+    /// queries associated with the released context will be canceled and the
+    /// browser process will be notified.
+    pub fn on_context_released(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        context: V8Context
+    ) -> Result<()> {
+        let released = {
+            let mut state = self.0.lock();
+            let mut context_id = None;
+
+            for (id, info) in state.contexts.iter() {
+                if info
+                    .context
+                    .is_same(context.clone())?
+                {
+                    context_id = Some(*id);
+                    break;
+                }
+            }
+
+            context_id.and_then(|context_id| {
+                state
+                    .contexts
+                    .remove(&context_id)
+                    .map(|info| (context_id, info, state.config.cancel_message_name()))
+            })
+        };
+
+        if let Some((context_id, info, cancel_message_name)) = released {
+            if !info.requests.is_empty() {
+                send_cancel(&info.frame, &cancel_message_name, context_id, -1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Call from cef_render_process_handler_t::on_process_message_received.
+    /// Returns true if the message is handled by this router or false otherwise.
+    pub fn on_process_message_received(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        source_process: ProcessId,
+        message: &ProcessMessage
+    ) -> Result<bool> {
+        if source_process != ProcessId::Browser {
+            return Ok(false);
+        }
+
+        let name = message.get_name()?;
+
+        if name
+            != self
+                .0
+                .lock()
+                .config
+                .query_message_name()
+        {
+            return Ok(false);
+        }
+
+        let args = get_argument_list(message)?;
+        let context_id = args.get_int(0)?;
+        let request_id = args.get_int(1)?;
+        let success = args.get_bool(2)?;
+
+        // Find the request, removing it if it's been completed.
+        let (context, request) = {
+            let mut state = self.0.lock();
+            let info = match state.contexts.get_mut(&context_id) {
+                Some(info) => info,
+                None => return Ok(true)
+            };
+
+            let request = match info.requests.get(&request_id) {
+                Some(request) => request.clone(),
+                None => return Ok(true)
+            };
+
+            if !request.persistent || !success {
+                info.requests.remove(&request_id);
+            }
+
+            (info.context.clone(), request)
+        };
+
+        // Execute the callback outside of the lock as it may issue new queries.
+        if !context.enter()? {
+            return Ok(true);
+        }
+
+        let result = match success {
+            true => {
+                let response = args
+                    .get_string(3)?
+                    .unwrap_or_default();
+
+                request
+                    .success_callback
+                    .execute_function(None, vec![V8Value::new_string(&response)])
+                    .map(|_| ())
+            },
+            false => match &request.failure_callback {
+                Some(failure_callback) => {
+                    let error_code = args.get_int(3)?;
+                    let error_message = args
+                        .get_string(4)?
+                        .unwrap_or_default();
+
+                    failure_callback
+                        .execute_function(
+                            None,
+                            vec![
+                                V8Value::new_int(error_code),
+                                V8Value::new_string(&error_message),
+                            ]
+                        )
+                        .map(|_| ())
+                },
+                None => Ok(())
+            }
+        };
+
+        context.exit()?;
+        result?;
+
+        Ok(true)
+    }
+}
+
+/// Implements window.cefQuery.
+struct QueryFunction {
+    state:      Arc<Mutex<RendererSideState>>,
+    context_id: i32
+}
+
+impl QueryFunction {
+    /// Parses the query object and sends it to the browser process.
+    fn query(&mut self, arguments: &[V8Value]) -> std::result::Result<V8Value, String> {
+        let invalid = || String::from("Invalid arguments; expecting a single object");
+        let to_string = |e: anyhow::Error| e.to_string();
+
+        let object = match arguments {
+            [object]
+                if object
+                    .is_object()
+                    .map_err(to_string)? =>
+            {
+                object
+            },
+            _ => return Err(invalid())
+        };
+
+        let request = object
+            .get_value_bykey("request")
+            .map_err(to_string)?
+            .ok_or_else(invalid)?;
+
+        if !request
+            .is_string()
+            .map_err(to_string)?
+        {
+            return Err(String::from(
+                "Invalid arguments; object member 'request' is required and must have type string"
+            ));
+        }
+
+        let success_callback = object
+            .get_value_bykey("onSuccess")
+            .map_err(to_string)?
+            .ok_or_else(invalid)?;
+
+        if !success_callback
+            .is_function()
+            .map_err(to_string)?
+        {
+            return Err(String::from(
+                "Invalid arguments; object member 'onSuccess' is required and must have type function"
+            ));
+        }
+
+        let failure_callback = match object
+            .get_value_bykey("onFailure")
+            .map_err(to_string)?
+        {
+            Some(value)
+                if value
+                    .is_function()
+                    .map_err(to_string)? =>
+            {
+                Some(value)
+            },
+            Some(value)
+                if !value
+                    .is_undefined()
+                    .map_err(to_string)? =>
+            {
+                return Err(String::from(
+                    "Invalid arguments; object member 'onFailure' must have type function"
+                ));
+            },
+            _ => None
+        };
+
+        let persistent = match object
+            .get_value_bykey("persistent")
+            .map_err(to_string)?
+        {
+            Some(value) if value.is_bool().map_err(to_string)? => value
+                .get_bool_value()
+                .map_err(to_string)?,
+            Some(value)
+                if !value
+                    .is_undefined()
+                    .map_err(to_string)? =>
+            {
+                return Err(String::from(
+                    "Invalid arguments; object member 'persistent' must have type boolean"
+                ));
+            },
+            _ => false
+        };
+
+        let request = request
+            .get_string_value()
+            .map_err(to_string)?;
+
+        let (request_id, frame, query_message_name) = {
+            let mut state = self.state.lock();
+            let request_id = state.next_request_id;
+            let query_message_name = state.config.query_message_name();
+
+            state.next_request_id += 1;
+
+            let info = state
+                .contexts
+                .get_mut(&self.context_id)
+                .ok_or_else(|| String::from("The context has been released"))?;
+
+            info.requests.insert(
+                request_id,
+                RendererRequest {
+                    persistent,
+                    success_callback,
+                    failure_callback
+                }
+            );
+
+            (request_id, info.frame.clone(), query_message_name)
+        };
+
+        send_query(
+            &frame,
+            &query_message_name,
+            self.context_id,
+            request_id,
+            &request,
+            persistent
+        )
+        .map_err(to_string)?;
+
+        Ok(V8Value::new_int(request_id))
+    }
+}
+
+impl V8HandlerCallbacks for QueryFunction {
+    fn execute(
+        &mut self,
+        _name: &str,
+        _object: V8Value,
+        arguments: Vec<V8Value>,
+        retval: &mut Option<V8Value>,
+        exception: &mut Option<String>
+    ) -> bool {
+        match self.query(&arguments) {
+            Ok(value) => *retval = Some(value),
+            Err(e) => *exception = Some(e)
+        }
+
+        true
+    }
+}
+
+/// Implements window.cefQueryCancel.
+struct CancelFunction {
+    state:      Arc<Mutex<RendererSideState>>,
+    context_id: i32
+}
+
+impl CancelFunction {
+    /// Cancels a pending query and notifies the browser process.
+    fn cancel(&mut self, arguments: &[V8Value]) -> std::result::Result<V8Value, String> {
+        let to_string = |e: anyhow::Error| e.to_string();
+
+        let request_id = match arguments {
+            [request_id]
+                if request_id
+                    .is_int()
+                    .map_err(to_string)? =>
+            {
+                request_id
+                    .get_int_value()
+                    .map_err(to_string)?
+            },
+            _ => {
+                return Err(String::from(
+                    "Invalid arguments; expecting a single integer"
+                ));
+            }
+        };
+
+        let canceled = {
+            let mut state = self.state.lock();
+            let cancel_message_name = state.config.cancel_message_name();
+
+            state
+                .contexts
+                .get_mut(&self.context_id)
+                .and_then(|info| {
+                    info.requests
+                        .remove(&request_id)
+                        .map(|_| (info.frame.clone(), cancel_message_name))
+                })
+        };
+
+        match canceled {
+            Some((frame, cancel_message_name)) => {
+                send_cancel(&frame, &cancel_message_name, self.context_id, request_id)
+                    .map_err(to_string)?;
+
+                Ok(V8Value::new_bool(true))
+            },
+            None => Ok(V8Value::new_bool(false))
+        }
+    }
+}
+
+impl V8HandlerCallbacks for CancelFunction {
+    fn execute(
+        &mut self,
+        _name: &str,
+        _object: V8Value,
+        arguments: Vec<V8Value>,
+        retval: &mut Option<V8Value>,
+        exception: &mut Option<String>
+    ) -> bool {
+        match self.cancel(&arguments) {
+            Ok(value) => *retval = Some(value),
+            Err(e) => *exception = Some(e)
+        }
+
+        true
+    }
+}
+
+/// Sends a query from the renderer to the browser process.
+fn send_query(
+    frame: &Frame,
+    name: &str,
+    context_id: i32,
+    request_id: i32,
+    request: &str,
+    persistent: bool
+) -> Result<()> {
+    let message = ProcessMessage::new(name);
+    let args = get_argument_list(&message)?;
+
+    args.set_int(0, context_id)?;
+    args.set_int(1, request_id)?;
+    args.set_string(2, request)?;
+    args.set_bool(3, persistent)?;
+
+    frame.send_process_message(ProcessId::Browser, message)
+}
+
+/// Sends a cancellation from the renderer to the browser process. A
+/// |request_id| of -1 cancels all queries for the context.
+fn send_cancel(frame: &Frame, name: &str, context_id: i32, request_id: i32) -> Result<()> {
+    let message = ProcessMessage::new(name);
+    let args = get_argument_list(&message)?;
+
+    args.set_int(0, context_id)?;
+    args.set_int(1, request_id)?;
+
+    frame.send_process_message(ProcessId::Browser, message)
+}
@@ -0,0 +1,286 @@
+use crate::{ref_counted_ptr, try_c, Insets, Point, Rect, Size, ThreadId, View, Window};
+use anyhow::{anyhow, Result};
+use cef_ui_sys::{
+    cef_docking_mode_t, cef_overlay_controller_t, cef_point_t, cef_rect_t, cef_size_t
+};
+use std::ffi::c_int;
+
+/// Docking modes supported by cef_window_t::add_overlay.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DockingMode {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Custom
+}
+
+impl From<cef_docking_mode_t> for DockingMode {
+    fn from(value: cef_docking_mode_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_docking_mode_t> for DockingMode {
+    fn from(value: &cef_docking_mode_t) -> Self {
+        match value {
+            cef_docking_mode_t::CEF_DOCKING_MODE_TOP_LEFT => DockingMode::TopLeft,
+            cef_docking_mode_t::CEF_DOCKING_MODE_TOP_RIGHT => DockingMode::TopRight,
+            cef_docking_mode_t::CEF_DOCKING_MODE_BOTTOM_LEFT => DockingMode::BottomLeft,
+            cef_docking_mode_t::CEF_DOCKING_MODE_BOTTOM_RIGHT => DockingMode::BottomRight,
+            cef_docking_mode_t::CEF_DOCKING_MODE_CUSTOM => DockingMode::Custom
+        }
+    }
+}
+
+impl From<DockingMode> for cef_docking_mode_t {
+    fn from(value: DockingMode) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&DockingMode> for cef_docking_mode_t {
+    fn from(value: &DockingMode) -> Self {
+        match value {
+            DockingMode::TopLeft => cef_docking_mode_t::CEF_DOCKING_MODE_TOP_LEFT,
+            DockingMode::TopRight => cef_docking_mode_t::CEF_DOCKING_MODE_TOP_RIGHT,
+            DockingMode::BottomLeft => cef_docking_mode_t::CEF_DOCKING_MODE_BOTTOM_LEFT,
+            DockingMode::BottomRight => cef_docking_mode_t::CEF_DOCKING_MODE_BOTTOM_RIGHT,
+            DockingMode::Custom => cef_docking_mode_t::CEF_DOCKING_MODE_CUSTOM
+        }
+    }
+}
+
+// Controller for an overlay that contains a contents View added via
+// cef_window_t::add_overlay_view. Methods exposed by this controller should be
+// called in preference to functions of the same name exposed by the contents
+// View unless otherwise indicated. Methods must be called on the browser
+// process UI thread unless otherwise indicated.
+//
+// The overlay belongs to the Window that created it. It becomes invalid when
+// destroy() is called or when that Window is destroyed. Every function other
+// than is_valid() and is_same() checks this first and returns an error once
+// the overlay is invalid.
+ref_counted_ptr!(OverlayController, cef_overlay_controller_t);
+
+impl OverlayController {
+    /// Returns true (1) if this control is valid.
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, ThreadId::Ui, {
+            Ok(is_valid(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns an error if this overlay or its Window has been destroyed.
+    fn ensure_valid(&self) -> Result<()> {
+        match self.is_valid()? {
+            true => Ok(()),
+            false => Err(anyhow!("The overlay is no longer valid."))
+        }
+    }
+
+    /// Returns true (1) if this object is the same as |that| object.
+    pub fn is_same(&self, that: OverlayController) -> Result<bool> {
+        try_c!(self, is_same, ThreadId::Ui, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    /// Returns the contents View for this overlay.
+    pub fn get_contents_view(&self) -> Result<Option<View>> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_contents_view, ThreadId::Ui, {
+            Ok(View::from_ptr(get_contents_view(self.as_ptr())))
+        })
+    }
+
+    /// Returns the top-level Window hosting this overlay. Use this function
+    /// instead of calling get_window() on the contents View.
+    pub fn get_window(&self) -> Result<Option<Window>> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_window, ThreadId::Ui, {
+            Ok(Window::from_ptr(get_window(self.as_ptr())))
+        })
+    }
+
+    /// Returns the docking mode for this overlay.
+    pub fn get_docking_mode(&self) -> Result<DockingMode> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_docking_mode, ThreadId::Ui, {
+            Ok(get_docking_mode(self.as_ptr()).into())
+        })
+    }
+
+    /// Destroy this overlay.
+    pub fn destroy(&self) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, destroy, ThreadId::Ui, { Ok(destroy(self.as_ptr())) })
+    }
+
+    /// Sets the bounds (size and position) of this overlay. This will set the
+    /// bounds of the contents View to match and trigger a re-layout if
+    /// necessary. |bounds| is in parent coordinates and any insets configured on
+    /// this overlay will be ignored. Use this function only for overlays created
+    /// with a docking mode value of CEF_DOCKING_MODE_CUSTOM. With other docking
+    /// modes modify the insets of this overlay and/or layout of the contents View
+    /// and call size_to_preferred_size() instead to calculate the new size and
+    /// re-position the overlay if necessary.
+    pub fn set_bounds(&self, bounds: &Rect) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, set_bounds, ThreadId::Ui, {
+            let bounds: cef_rect_t = bounds.into();
+
+            Ok(set_bounds(self.as_ptr(), &bounds))
+        })
+    }
+
+    /// Returns the bounds (size and position) of this overlay in parent
+    /// coordinates.
+    pub fn get_bounds(&self) -> Result<Rect> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_bounds, ThreadId::Ui, {
+            Ok(get_bounds(self.as_ptr()).into())
+        })
+    }
+
+    /// Returns the bounds (size and position) of this overlay in DIP screen
+    /// coordinates.
+    pub fn get_bounds_in_screen(&self) -> Result<Rect> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_bounds_in_screen, ThreadId::Ui, {
+            Ok(get_bounds_in_screen(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the size of this overlay without changing the position. This will set
+    /// the size of the contents View to match and trigger a re-layout if
+    /// necessary. |size| is in parent coordinates and any insets configured on
+    /// this overlay will be ignored. Use this function only for overlays created
+    /// with a docking mode value of CEF_DOCKING_MODE_CUSTOM. With other docking
+    /// modes modify the insets of this overlay and/or layout of the contents View
+    /// and call size_to_preferred_size() instead to calculate the new size and
+    /// re-position the overlay if necessary.
+    pub fn set_size(&self, size: &Size) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, set_size, ThreadId::Ui, {
+            let size: cef_size_t = size.into();
+
+            Ok(set_size(self.as_ptr(), &size))
+        })
+    }
+
+    /// Returns the size of this overlay in parent coordinates.
+    pub fn get_size(&self) -> Result<Size> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_size, ThreadId::Ui, {
+            Ok(get_size(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the position of this overlay without changing the size. |position| is
+    /// in parent coordinates and any insets configured on this overlay will be
+    /// ignored. Use this function only for overlays created with a docking mode
+    /// value of CEF_DOCKING_MODE_CUSTOM. With other docking modes modify the
+    /// insets of this overlay and/or layout of the contents View and call
+    /// size_to_preferred_size() instead to calculate the new size and re-position
+    /// the overlay if necessary.
+    pub fn set_position(&self, position: &Point) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, set_position, ThreadId::Ui, {
+            let position: cef_point_t = position.into();
+
+            Ok(set_position(self.as_ptr(), &position))
+        })
+    }
+
+    /// Returns the position of this overlay in parent coordinates.
+    pub fn get_position(&self) -> Result<Point> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_position, ThreadId::Ui, {
+            Ok(get_position(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the insets for this overlay. |insets| is in parent coordinates. Use
+    /// this function only for overlays created with a docking mode value other
+    /// than CEF_DOCKING_MODE_CUSTOM.
+    pub fn set_insets(&self, insets: &Insets) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, set_insets, ThreadId::Ui, {
+            let insets = insets.into();
+
+            Ok(set_insets(self.as_ptr(), &insets))
+        })
+    }
+
+    /// Returns the insets for this overlay in parent coordinates.
+    pub fn get_insets(&self) -> Result<Insets> {
+        self.ensure_valid()?;
+
+        try_c!(self, get_insets, ThreadId::Ui, {
+            Ok(get_insets(self.as_ptr()).into())
+        })
+    }
+
+    /// Size this overlay to its preferred size and trigger a re-layout if
+    /// necessary. The position of overlays created with a docking mode value of
+    /// CEF_DOCKING_MODE_CUSTOM will not be modified by calling this function.
+    /// With other docking modes this function may re-position the overlay if
+    /// necessary to accommodate the new size and any insets configured on the
+    /// contents View.
+    pub fn size_to_preferred_size(&self) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, size_to_preferred_size, ThreadId::Ui, {
+            Ok(size_to_preferred_size(self.as_ptr()))
+        })
+    }
+
+    /// Sets whether this overlay is visible. Overlays are hidden by default. If
+    /// this overlay is hidden then it and any child Views will not be drawn and,
+    /// if any of those Views currently have focus, then focus will also be
+    /// cleared. Painting is scheduled as needed.
+    pub fn set_visible(&self, visible: bool) -> Result<()> {
+        self.ensure_valid()?;
+
+        try_c!(self, set_visible, ThreadId::Ui, {
+            Ok(set_visible(self.as_ptr(), visible as c_int))
+        })
+    }
+
+    /// Returns whether this overlay is visible. A View may be visible but still
+    /// not drawn in a Window if any parent Views are hidden. Call is_drawn() to
+    /// determine whether this overlay and all parent Views are visible and will
+    /// be drawn.
+    pub fn is_visible(&self) -> Result<bool> {
+        self.ensure_valid()?;
+
+        try_c!(self, is_visible, ThreadId::Ui, {
+            Ok(is_visible(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns whether this overlay is visible and drawn in a Window. A View is
+    /// drawn if it and all parent Views are visible. To determine if the
+    /// containing Window is visible to the user on-screen call is_visible() on
+    /// the Window.
+    pub fn is_drawn(&self) -> Result<bool> {
+        self.ensure_valid()?;
+
+        try_c!(self, is_drawn, ThreadId::Ui, {
+            Ok(is_drawn(self.as_ptr()) != 0)
+        })
+    }
+}
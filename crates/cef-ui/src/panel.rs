@@ -0,0 +1,176 @@
+use crate::{
+    ref_counted_ptr, try_c, view_delegate, view_deref, BoxLayout, BoxLayoutSettings, FillLayout,
+    Layout, RefCountedPtr, ThreadId, View, ViewDelegateCallbacks, ViewDelegateWrappable, Window,
+    Wrappable
+};
+use anyhow::Result;
+use cef_ui_sys::{cef_panel_create, cef_panel_delegate_t, cef_panel_t};
+use std::{ffi::c_int, ptr::null_mut};
+
+// Implement this structure to handle Panel events. The functions of this
+// structure will be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(PanelDelegate, cef_panel_delegate_t);
+
+impl PanelDelegate {
+    pub fn new<C: ViewDelegateCallbacks>(delegate: C) -> Self {
+        Self(PanelDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct PanelDelegateWrapper(Box<dyn ViewDelegateCallbacks>);
+
+impl PanelDelegateWrapper {
+    pub fn new<C: ViewDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+}
+
+impl ViewDelegateWrappable for PanelDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for PanelDelegateWrapper {
+    type Cef = cef_panel_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_panel_delegate_t> {
+        RefCountedPtr::wrap(
+            cef_panel_delegate_t {
+                base: view_delegate::<Self>()
+            },
+            self
+        )
+    }
+}
+
+// A Panel is a container in the views hierarchy that can contain other Views as
+// children. Methods must be called on the browser process UI thread unless
+// otherwise indicated.
+ref_counted_ptr!(Panel, cef_panel_t);
+
+view_deref!(Panel, View);
+
+impl Panel {
+    /// Create a new Panel.
+    pub fn create(delegate: Option<PanelDelegate>) -> Option<Panel> {
+        unsafe {
+            let delegate = delegate
+                .map(|delegate| delegate.into_raw())
+                .unwrap_or_else(null_mut);
+
+            Panel::from_ptr(cef_panel_create(delegate))
+        }
+    }
+
+    /// Returns this Panel as a Window or NULL if this is not a Window.
+    pub fn as_window(&self) -> Result<Option<Window>> {
+        try_c!(self, as_window, ThreadId::Ui, {
+            Ok(Window::from_ptr(as_window(self.as_ptr())))
+        })
+    }
+
+    /// Set this Panel's Layout to FillLayout and return the FillLayout object.
+    pub fn set_to_fill_layout(&self) -> Result<FillLayout> {
+        try_c!(self, set_to_fill_layout, ThreadId::Ui, {
+            Ok(FillLayout::from_ptr_unchecked(set_to_fill_layout(
+                self.as_ptr()
+            )))
+        })
+    }
+
+    /// Set this Panel's Layout to BoxLayout and return the BoxLayout object.
+    pub fn set_to_box_layout(&self, settings: &BoxLayoutSettings) -> Result<BoxLayout> {
+        try_c!(self, set_to_box_layout, ThreadId::Ui, {
+            Ok(BoxLayout::from_ptr_unchecked(set_to_box_layout(
+                self.as_ptr(),
+                settings.as_raw()
+            )))
+        })
+    }
+
+    /// Get the Layout.
+    pub fn get_layout(&self) -> Result<Option<Layout>> {
+        try_c!(self, get_layout, ThreadId::Ui, {
+            Ok(Layout::from_ptr(get_layout(self.as_ptr())))
+        })
+    }
+
+    /// Lay out the child Views (set their bounds based on sizing heuristics
+    /// specific to the current Layout).
+    pub fn layout(&self) -> Result<()> {
+        try_c!(self, layout, ThreadId::Ui, { Ok(layout(self.as_ptr())) })
+    }
+
+    /// Add a child View.
+    pub fn add_child_view(&self, view: View) -> Result<()> {
+        try_c!(self, add_child_view, ThreadId::Ui, {
+            Ok(add_child_view(self.as_ptr(), view.into_raw()))
+        })
+    }
+
+    /// Add a child View at the specified |index|. If |index| matches the result
+    /// of GetChildCount() then the View will be added at the end.
+    pub fn add_child_view_at(&self, view: View, index: i32) -> Result<()> {
+        try_c!(self, add_child_view_at, ThreadId::Ui, {
+            Ok(add_child_view_at(
+                self.as_ptr(),
+                view.into_raw(),
+                index as c_int
+            ))
+        })
+    }
+
+    /// Move the child View to the specified |index|. A negative value for |index|
+    /// will move the View to the end.
+    pub fn reorder_child_view(&self, view: View, index: i32) -> Result<()> {
+        try_c!(self, reorder_child_view, ThreadId::Ui, {
+            Ok(reorder_child_view(
+                self.as_ptr(),
+                view.into_raw(),
+                index as c_int
+            ))
+        })
+    }
+
+    /// Remove a child View. The View can then be added to another Panel.
+    pub fn remove_child_view(&self, view: View) -> Result<()> {
+        try_c!(self, remove_child_view, ThreadId::Ui, {
+            Ok(remove_child_view(self.as_ptr(), view.into_raw()))
+        })
+    }
+
+    /// Remove all child Views. The removed Views will be deleted if the client
+    /// holds no references to them.
+    pub fn remove_all_child_views(&self) -> Result<()> {
+        try_c!(self, remove_all_child_views, ThreadId::Ui, {
+            Ok(remove_all_child_views(self.as_ptr()))
+        })
+    }
+
+    /// Returns the number of child Views.
+    pub fn get_child_view_count(&self) -> Result<usize> {
+        try_c!(self, get_child_view_count, ThreadId::Ui, {
+            Ok(get_child_view_count(self.as_ptr()))
+        })
+    }
+
+    /// Returns the child View at the specified |index|.
+    pub fn get_child_view_at(&self, index: i32) -> Result<Option<View>> {
+        try_c!(self, get_child_view_at, ThreadId::Ui, {
+            Ok(View::from_ptr(get_child_view_at(
+                self.as_ptr(),
+                index as c_int
+            )))
+        })
+    }
+}
+
+impl From<Panel> for View {
+    fn from(value: Panel) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{post_delayed_task, Browser, Frame, ThreadId};
+use anyhow::Result;
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+/// A call made to another process that is waiting for its result.
+struct PendingCall<R> {
+    browser_id: i32,
+    frame_id:   i64,
+    callback:   Box<dyn FnOnce(R) + Send + 'static>
+}
+
+/// The shared state of a registry.
+struct PendingCallsState<R> {
+    next_id: i32,
+    pending: HashMap<i32, PendingCall<R>>
+}
+
+/// Tracks calls made to another process through process messages. Each call
+/// gets an ID that is sent along with the request so that the reply can be
+/// matched to its callback. Every callback is called exactly once, with the
+/// result, an error on timeout or an error when its frame or browser goes
+/// away. Callbacks are always called outside of the lock as they may make new
+/// calls.
+pub(crate) struct PendingCalls<R>(Arc<Mutex<PendingCallsState<R>>>);
+
+impl<R> Clone for PendingCalls<R> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R: Clone + Send + 'static> PendingCalls<R> {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(PendingCallsState {
+            next_id: 0,
+            pending: HashMap::new()
+        })))
+    }
+
+    /// Registers a call made through |frame| and returns its ID.
+    pub fn insert(&self, frame: &Frame, callback: impl FnOnce(R) + Send + 'static) -> Result<i32> {
+        let browser_id = frame
+            .get_browser()?
+            .get_identifier()?;
+        let frame_id = frame.get_identifier()?;
+
+        let mut state = self.0.lock();
+        let id = state.next_id;
+
+        state.next_id = state.next_id.wrapping_add(1);
+        state.pending.insert(
+            id,
+            PendingCall {
+                browser_id,
+                frame_id,
+                callback: Box::new(callback)
+            }
+        );
+
+        Ok(id)
+    }
+
+    /// Completes the call with |id| with |result| once |timeout| elapses on
+    /// |thread_id|, unless it completes before that.
+    pub fn complete_after(
+        &self,
+        id: i32,
+        thread_id: ThreadId,
+        timeout: Duration,
+        result: R
+    ) -> Result<()> {
+        let calls = self.clone();
+
+        post_delayed_task(thread_id, timeout, move || {
+            calls.complete(id, result);
+        })
+    }
+
+    /// Completes the call with |id|. Does nothing if it already completed.
+    pub fn complete(&self, id: i32, result: R) {
+        let call = self.0.lock().pending.remove(&id);
+
+        if let Some(call) = call {
+            (call.callback)(result);
+        }
+    }
+
+    /// Completes every call made through |frame| with |result|.
+    pub fn complete_for_frame(&self, frame: &Frame, result: R) -> Result<()> {
+        let frame_id = frame.get_identifier()?;
+
+        self.complete_where(result, |call| call.frame_id == frame_id);
+
+        Ok(())
+    }
+
+    /// Completes every call made through a frame of |browser| with |result|.
+    pub fn complete_for_browser(&self, browser: &Browser, result: R) -> Result<()> {
+        let browser_id = browser.get_identifier()?;
+
+        self.complete_where(result, |call| call.browser_id == browser_id);
+
+        Ok(())
+    }
+
+    /// Completes the calls affected by navigating |frame| with |result|.
+    /// Navigating the main frame affects every call made through |browser|,
+    /// otherwise only the calls made through |frame| are affected.
+    pub fn complete_for_navigation(
+        &self,
+        browser: &Browser,
+        frame: &Frame,
+        result: R
+    ) -> Result<()> {
+        match frame.is_main()? {
+            true => self.complete_for_browser(browser, result),
+            false => self.complete_for_frame(frame, result)
+        }
+    }
+
+    /// Returns the number of calls waiting for a result.
+    pub fn len(&self) -> usize {
+        self.0.lock().pending.len()
+    }
+
+    /// Completes every call matching |predicate| with |result|.
+    fn complete_where<P>(&self, result: R, predicate: P)
+    where
+        P: Fn(&PendingCall<R>) -> bool
+    {
+        let completed: Vec<PendingCall<R>> = {
+            let mut state = self.0.lock();
+            let ids: Vec<i32> = state
+                .pending
+                .iter()
+                .filter(|(_, call)| predicate(call))
+                .map(|(id, _)| *id)
+                .collect();
+
+            ids.into_iter()
+                .filter_map(|id| state.pending.remove(&id))
+                .collect()
+        };
+
+        for call in completed {
+            (call.callback)(result.clone());
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{ref_counted_ptr, try_c, CefString, ListValue, SharedMemoryRegion};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use cef_ui_sys::{cef_process_id_t, cef_process_message_create, cef_process_message_t};
 
 /// Existing process IDs.
@@ -102,3 +102,11 @@ impl ProcessMessage {
         })
     }
 }
+
+/// Returns the argument list of |message|. Messages created with
+/// ProcessMessage::new always have one.
+pub(crate) fn get_argument_list(message: &ProcessMessage) -> Result<ListValue> {
+    message
+        .get_argument_list()?
+        .ok_or_else(|| anyhow!("Process message has no argument list."))
+}
@@ -34,6 +34,7 @@ macro_rules! ref_counted {
 }
 
 /// A smart pointer that wraps RefCounted objects.
+#[repr(transparent)]
 pub struct RefCountedPtr<T: RefCounted> {
     pub value: NonNull<T>
 }
@@ -0,0 +1,7 @@
+use crate::ref_counted_ptr;
+use cef_ui_sys::cef_registration_t;
+
+// Generic callback structure used for managing the lifespan of a registration.
+// The registration is removed when the last reference to this object is
+// released.
+ref_counted_ptr!(Registration, cef_registration_t);
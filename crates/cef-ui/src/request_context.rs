@@ -1,11 +1,13 @@
 use crate::{
     ref_counted_ptr, try_c, CefString, CefStringList, CompletionCallback, ErrorCode, RefCountedPtr,
-    RequestContextHandler, Wrappable, Wrapped
+    RequestContextHandler, ThreadId, Wrappable, Wrapped
 };
 use anyhow::Result;
 use cef_ui_sys::{
     cef_errorcode_t, cef_request_context_t, cef_resolve_callback_t, cef_string_list_t
 };
+#[cfg(feature = "futures")]
+use futures::channel::oneshot;
 use parking_lot::Mutex;
 use std::mem::zeroed;
 
@@ -207,6 +209,61 @@ impl RequestContext {
         })
     }
 
+    /// Same as clear_certificate_exceptions, but completes when the callback
+    /// is executed.
+    #[cfg(feature = "futures")]
+    pub async fn clear_certificate_exceptions_async(&self) -> Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.clear_certificate_exceptions(CompletionCallback::new(move || {
+            let _ = sender.send(());
+        }))?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Same as clear_http_auth_credentials, but completes when the callback is
+    /// executed.
+    #[cfg(feature = "futures")]
+    pub async fn clear_http_auth_credentials_async(&self) -> Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.clear_http_auth_credentials(CompletionCallback::new(move || {
+            let _ = sender.send(());
+        }))?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Same as close_all_connections, but completes when the callback is
+    /// executed.
+    #[cfg(feature = "futures")]
+    pub async fn close_all_connections_async(&self) -> Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.close_all_connections(CompletionCallback::new(move || {
+            let _ = sender.send(());
+        }))?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Same as resolve_host, but resolves to the result code and the list of
+    /// resolved IP addresses.
+    #[cfg(feature = "futures")]
+    pub async fn resolve_host_async(&self, origin: &str) -> Result<(ErrorCode, Vec<String>)> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.resolve_host(
+            origin,
+            ResolveCallback::new(move |result, resolved_ips| {
+                let _ = sender.send((result, resolved_ips));
+            })
+        )?;
+
+        Ok(receiver.await?)
+    }
+
     // TODO: Fix this!
 
     //     ///
@@ -267,7 +324,7 @@ impl RequestContext {
     /// access to the extension (see HasExtension). This function must be called
     /// on the browser process UI thread.
     pub fn did_load_extension(&self, extension_id: &str) -> Result<bool> {
-        try_c!(self, did_load_extension, {
+        try_c!(self, did_load_extension, ThreadId::Ui, {
             let extension_id = CefString::new(extension_id);
 
             Ok(did_load_extension(self.as_ptr(), extension_id.as_ptr()) != 0)
@@ -279,7 +336,7 @@ impl RequestContext {
     /// extension (see DidLoadExtension). This function must be called on the
     /// browser process UI thread.
     pub fn has_extension(&self, extension_id: &str) -> Result<bool> {
-        try_c!(self, has_extension, {
+        try_c!(self, has_extension, ThreadId::Ui, {
             let extension_id = CefString::new(extension_id);
 
             Ok(has_extension(self.as_ptr(), extension_id.as_ptr()) != 0)
@@ -291,7 +348,7 @@ impl RequestContext {
     /// extension ID values. Returns true (1) on success. This function must be
     /// called on the browser process UI thread.
     pub fn get_extensions(&self) -> Result<Option<Vec<String>>> {
-        try_c!(self, get_extensions, {
+        try_c!(self, get_extensions, ThreadId::Ui, {
             let mut extension_ids = CefStringList::new();
 
             let extension_ids = match get_extensions(self.as_ptr(), extension_ids.as_mut_ptr()) {
@@ -0,0 +1,406 @@
+#[cfg(feature = "serde")]
+use crate::{from_value, to_value};
+use crate::{
+    get_argument_list, pending_call::PendingCalls, Browser, Frame, ListValue, ProcessId,
+    ProcessMessage, ThreadId, Value
+};
+use anyhow::Result;
+#[cfg(feature = "futures")]
+use futures::channel::oneshot;
+use parking_lot::Mutex;
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    sync::Arc,
+    time::Duration
+};
+
+/// The name of the process message used to send a request.
+const REQUEST_MESSAGE_NAME: &str = "cefRpcRequestMsg";
+
+/// The name of the process message used to send a response.
+const RESPONSE_MESSAGE_NAME: &str = "cefRpcResponseMsg";
+
+/// The response status sent when the handler succeeded.
+const STATUS_SUCCESS: i32 = 0;
+
+/// The response status sent when no handler is registered for the method.
+const STATUS_HANDLER_MISSING: i32 = 1;
+
+/// The response status sent when the handler returned an error.
+const STATUS_HANDLER_FAILED: i32 = 2;
+
+/// Errors that can occur when calling a remote method.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RpcError {
+    /// No handler is registered for the method in the target process.
+    HandlerMissing(String),
+
+    /// The handler in the target process returned an error.
+    HandlerFailed(String),
+
+    /// The call did not complete before its deadline.
+    Timeout,
+
+    /// The call was canceled because the frame was detached or navigated, the
+    /// browser closed or the render process terminated.
+    Canceled,
+
+    /// The request couldn't be sent or the response couldn't be converted.
+    Failed(String)
+}
+
+impl Display for RpcError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpcError::HandlerMissing(method) => write!(f, "No handler for method {}.", method),
+            RpcError::HandlerFailed(message) => write!(f, "The handler failed: {}", message),
+            RpcError::Timeout => write!(f, "The call timed out."),
+            RpcError::Canceled => write!(f, "The call was canceled."),
+            RpcError::Failed(message) => write!(f, "The call failed: {}", message)
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+/// A method handler. Receives the frame the request came from and the request
+/// parameters, and returns the response or an error message.
+type RpcHandler =
+    Arc<dyn Fn(&Frame, Value) -> std::result::Result<Value, String> + Send + Sync + 'static>;
+
+/// The result of a call.
+type RpcResult = std::result::Result<Value, RpcError>;
+
+/// Calls methods in another process and serves calls made from it. Requests
+/// and responses are carried in process messages sent through
+/// Frame::send_process_message. Each request carries an ID in its argument
+/// list so that its response can be matched to the caller.
+///
+/// Create one endpoint in the browser process and one in the render process.
+/// Both may register methods and call methods registered by the other. This
+/// object must be notified of process messages, navigation, browser closing
+/// and renderer termination so that pending calls complete. The methods of
+/// this object should be called on the browser process UI thread or the
+/// render process main thread.
+#[derive(Clone)]
+pub struct Rpc {
+    process:  ProcessId,
+    pending:  PendingCalls<RpcResult>,
+    handlers: Arc<Mutex<HashMap<String, RpcHandler>>>
+}
+
+impl Rpc {
+    /// Creates a new endpoint that lives in |process|.
+    pub fn new(process: ProcessId) -> Self {
+        Self {
+            process,
+            pending: PendingCalls::new(),
+            handlers: Arc::new(Mutex::new(HashMap::new()))
+        }
+    }
+
+    /// Registers |handler| for |method|, replacing any existing handler.
+    pub fn register(
+        &self,
+        method: &str,
+        handler: impl Fn(&Frame, Value) -> std::result::Result<Value, String> + Send + Sync + 'static
+    ) {
+        self.handlers
+            .lock()
+            .insert(method.to_string(), Arc::new(handler));
+    }
+
+    /// Like register, but converts the parameters and the response with serde.
+    #[cfg(feature = "serde")]
+    pub fn register_typed<P, R>(
+        &self,
+        method: &str,
+        handler: impl Fn(&Frame, P) -> std::result::Result<R, String> + Send + Sync + 'static
+    ) where
+        P: DeserializeOwned,
+        R: Serialize
+    {
+        self.register(method, move |frame, params| {
+            let params = from_value(params).map_err(|e| e.to_string())?;
+            let response = handler(frame, params)?;
+
+            to_value(&response).map_err(|e| e.to_string())
+        });
+    }
+
+    /// Removes the handler for |method|. Returns true if a handler was removed.
+    pub fn unregister(&self, method: &str) -> bool {
+        self.handlers
+            .lock()
+            .remove(method)
+            .is_some()
+    }
+
+    /// Calls |method| in the other process with |params| and calls |callback|
+    /// with the response. If |timeout| elapses before a response arrives the
+    /// callback is called with RpcError::Timeout.
+    pub fn call(
+        &self,
+        frame: &Frame,
+        method: &str,
+        params: Value,
+        timeout: Option<Duration>,
+        callback: impl FnOnce(std::result::Result<Value, RpcError>) + Send + 'static
+    ) -> Result<()> {
+        let id = self
+            .pending
+            .insert(frame, callback)?;
+
+        let message = ProcessMessage::new(REQUEST_MESSAGE_NAME);
+        let sent = get_argument_list(&message).and_then(|args| {
+            args.set_int(0, id)?;
+            args.set_string(1, method)?;
+            args.set_value(2, params)?;
+
+            frame.send_process_message(self.get_target_process(), message)
+        });
+
+        if let Err(e) = sent {
+            self.pending
+                .complete(id, Err(RpcError::Failed(e.to_string())));
+
+            return Ok(());
+        }
+
+        if let Some(timeout) = timeout {
+            self.pending.complete_after(
+                id,
+                self.get_thread_id(),
+                timeout,
+                Err(RpcError::Timeout)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Like call, but converts the parameters and the response with serde.
+    #[cfg(feature = "serde")]
+    pub fn call_typed<P, R>(
+        &self,
+        frame: &Frame,
+        method: &str,
+        params: &P,
+        timeout: Option<Duration>,
+        callback: impl FnOnce(std::result::Result<R, RpcError>) + Send + 'static
+    ) -> Result<()>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned
+    {
+        let params = to_value(params)?;
+
+        self.call(frame, method, params, timeout, move |result| {
+            callback(
+                result.and_then(|value| {
+                    from_value(value).map_err(|e| RpcError::Failed(e.to_string()))
+                })
+            )
+        })
+    }
+
+    /// Same as call, but resolves to the response.
+    #[cfg(feature = "futures")]
+    pub async fn call_async(
+        &self,
+        frame: &Frame,
+        method: &str,
+        params: Value,
+        timeout: Option<Duration>
+    ) -> Result<std::result::Result<Value, RpcError>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.call(frame, method, params, timeout, move |result| {
+            let _ = sender.send(result);
+        })?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Same as call_typed, but resolves to the response.
+    #[cfg(all(feature = "futures", feature = "serde"))]
+    pub async fn call_typed_async<P, R>(
+        &self,
+        frame: &Frame,
+        method: &str,
+        params: &P,
+        timeout: Option<Duration>
+    ) -> Result<std::result::Result<R, RpcError>>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned + Send + 'static
+    {
+        let (sender, receiver) = oneshot::channel();
+
+        self.call_typed(frame, method, params, timeout, move |result| {
+            let _ = sender.send(result);
+        })?;
+
+        Ok(receiver.await?)
+    }
+
+    /// Returns the number of calls waiting for a response.
+    pub fn get_pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Cancels all pending calls to |frame|. Call this when the frame is
+    /// detached.
+    pub fn cancel_for_frame(&self, frame: &Frame) -> Result<()> {
+        self.pending
+            .complete_for_frame(frame, Err(RpcError::Canceled))
+    }
+
+    /// Cancels all pending calls to frames of |browser|.
+    pub fn cancel_for_browser(&self, browser: &Browser) -> Result<()> {
+        self.pending
+            .complete_for_browser(browser, Err(RpcError::Canceled))
+    }
+
+    /// Call from cef_life_span_handler_t::on_before_close.
+    pub fn on_before_close(&self, browser: &Browser) -> Result<()> {
+        self.cancel_for_browser(browser)
+    }
+
+    /// Call from cef_request_handler_t::on_render_process_terminated.
+    pub fn on_render_process_terminated(&self, browser: &Browser) -> Result<()> {
+        self.cancel_for_browser(browser)
+    }
+
+    /// Call from cef_request_handler_t::on_before_browse only if the navigation
+    /// is allowed to proceed. Navigating the main frame cancels everything
+    /// pending for the browser, otherwise only |frame| is affected.
+    pub fn on_before_browse(&self, browser: &Browser, frame: &Frame) -> Result<()> {
+        self.pending
+            .complete_for_navigation(browser, frame, Err(RpcError::Canceled))
+    }
+
+    /// Call from cef_render_process_handler_t::on_context_released. Pending
+    /// calls made through |frame| are canceled.
+    pub fn on_context_released(&self, frame: &Frame) -> Result<()> {
+        self.cancel_for_frame(frame)
+    }
+
+    /// Call from cef_client_t::on_process_message_received in the browser
+    /// process or cef_render_process_handler_t::on_process_message_received in
+    /// the render process. Returns true if the message is handled by this
+    /// endpoint or false otherwise.
+    pub fn on_process_message_received(
+        &self,
+        frame: Frame,
+        source_process: ProcessId,
+        message: &ProcessMessage
+    ) -> Result<bool> {
+        if source_process != self.get_target_process() {
+            return Ok(false);
+        }
+
+        match message.get_name()?.as_str() {
+            REQUEST_MESSAGE_NAME => self.on_request(frame, message)?,
+            RESPONSE_MESSAGE_NAME => self.on_response(message)?,
+            _ => return Ok(false)
+        }
+
+        Ok(true)
+    }
+
+    /// Runs the handler for a request and sends back the response.
+    fn on_request(&self, frame: Frame, message: &ProcessMessage) -> Result<()> {
+        let args = get_argument_list(message)?;
+        let id = args.get_int(0)?;
+        let method = args
+            .get_string(1)?
+            .unwrap_or_default();
+        let params = args
+            .get_value(2)?
+            .unwrap_or_else(Value::new);
+
+        // Run the handler outside of the lock as it may call again.
+        let handler = self
+            .handlers
+            .lock()
+            .get(&method)
+            .cloned();
+
+        let response = ProcessMessage::new(RESPONSE_MESSAGE_NAME);
+        let response_args = get_argument_list(&response)?;
+
+        response_args.set_int(0, id)?;
+
+        match handler.map(|handler| handler(&frame, params)) {
+            Some(Ok(value)) => {
+                response_args.set_int(1, STATUS_SUCCESS)?;
+                response_args.set_value(2, value)?;
+            },
+            Some(Err(message)) => {
+                response_args.set_int(1, STATUS_HANDLER_FAILED)?;
+                response_args.set_string(2, &message)?;
+            },
+            None => {
+                response_args.set_int(1, STATUS_HANDLER_MISSING)?;
+                response_args.set_string(2, &method)?;
+            }
+        }
+
+        frame.send_process_message(self.get_target_process(), response)
+    }
+
+    /// Completes the call that a response belongs to. A malformed response
+    /// still completes the call.
+    fn on_response(&self, message: &ProcessMessage) -> Result<()> {
+        let args = get_argument_list(message)?;
+        let id = args.get_int(0)?;
+        let result = parse_response(&args).unwrap_or_else(|e| Err(RpcError::Failed(e.to_string())));
+
+        self.pending.complete(id, result);
+
+        Ok(())
+    }
+
+    /// Returns the process that calls are sent to.
+    fn get_target_process(&self) -> ProcessId {
+        match self.process {
+            ProcessId::Browser => ProcessId::Renderer,
+            ProcessId::Renderer => ProcessId::Browser
+        }
+    }
+
+    /// Returns the thread that this endpoint runs on.
+    fn get_thread_id(&self) -> ThreadId {
+        match self.process {
+            ProcessId::Browser => ThreadId::Ui,
+            ProcessId::Renderer => ThreadId::Renderer
+        }
+    }
+}
+
+/// Converts the arguments of a response message into the result of a call.
+fn parse_response(args: &ListValue) -> Result<RpcResult> {
+    let result = match args.get_int(1)? {
+        STATUS_SUCCESS => Ok(args
+            .get_value(2)?
+            .unwrap_or_else(Value::new)),
+        STATUS_HANDLER_MISSING => Err(RpcError::HandlerMissing(
+            args.get_string(2)?
+                .unwrap_or_default()
+        )),
+        STATUS_HANDLER_FAILED => Err(RpcError::HandlerFailed(
+            args.get_string(2)?
+                .unwrap_or_default()
+        )),
+        status => Err(RpcError::Failed(format!(
+            "Unknown response status {}.",
+            status
+        )))
+    };
+
+    Ok(result)
+}
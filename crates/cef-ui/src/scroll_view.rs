@@ -0,0 +1,80 @@
+use crate::{ref_counted_ptr, try_c, view_deref, Rect, ThreadId, View, ViewDelegate};
+use anyhow::Result;
+use cef_ui_sys::{cef_scroll_view_create, cef_scroll_view_t};
+use std::ptr::null_mut;
+
+// A ScrollView will show horizontal and/or vertical scrollbars when necessary
+// based on the size of the attached content view. Methods must be called on
+// the browser process UI thread unless otherwise indicated.
+ref_counted_ptr!(ScrollView, cef_scroll_view_t);
+
+view_deref!(ScrollView, View);
+
+impl ScrollView {
+    /// Create a new ScrollView.
+    pub fn create(delegate: Option<ViewDelegate>) -> Option<ScrollView> {
+        unsafe {
+            let delegate = delegate
+                .map(|delegate| delegate.into_raw())
+                .unwrap_or_else(null_mut);
+
+            ScrollView::from_ptr(cef_scroll_view_create(delegate))
+        }
+    }
+
+    /// Set the content View. The content View must have a specified size (e.g.
+    /// via cef_view_t::SetBounds or cef_view_delegate_t::GetPreferredSize).
+    pub fn set_content_view(&self, view: View) -> Result<()> {
+        try_c!(self, set_content_view, ThreadId::Ui, {
+            Ok(set_content_view(self.as_ptr(), view.into_raw()))
+        })
+    }
+
+    /// Returns the content View.
+    pub fn get_content_view(&self) -> Result<Option<View>> {
+        try_c!(self, get_content_view, ThreadId::Ui, {
+            Ok(View::from_ptr(get_content_view(self.as_ptr())))
+        })
+    }
+
+    /// Returns the visible region of the content View.
+    pub fn get_visible_content_rect(&self) -> Result<Rect> {
+        try_c!(self, get_visible_content_rect, ThreadId::Ui, {
+            Ok(get_visible_content_rect(self.as_ptr()).into())
+        })
+    }
+
+    /// Returns true (1) if the horizontal scrollbar is currently showing.
+    pub fn has_horizontal_scrollbar(&self) -> Result<bool> {
+        try_c!(self, has_horizontal_scrollbar, ThreadId::Ui, {
+            Ok(has_horizontal_scrollbar(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the height of the horizontal scrollbar.
+    pub fn get_horizontal_scrollbar_height(&self) -> Result<i32> {
+        try_c!(self, get_horizontal_scrollbar_height, ThreadId::Ui, {
+            Ok(get_horizontal_scrollbar_height(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns true (1) if the vertical scrollbar is currently showing.
+    pub fn has_vertical_scrollbar(&self) -> Result<bool> {
+        try_c!(self, has_vertical_scrollbar, ThreadId::Ui, {
+            Ok(has_vertical_scrollbar(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the width of the vertical scrollbar.
+    pub fn get_vertical_scrollbar_width(&self) -> Result<i32> {
+        try_c!(self, get_vertical_scrollbar_width, ThreadId::Ui, {
+            Ok(get_vertical_scrollbar_width(self.as_ptr()) as i32)
+        })
+    }
+}
+
+impl From<ScrollView> for View {
+    fn from(value: ScrollView) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
@@ -0,0 +1,500 @@
+use crate::{
+    ref_counted_ptr, try_c, Callback, CefString, CefStringMultiMap, RefCountedPtr, Request,
+    TaskRunner, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_callback_t, cef_request_t, cef_server_create, cef_server_handler_t, cef_server_t,
+    cef_string_t
+};
+use std::{
+    collections::HashMap,
+    ffi::{c_int, c_void},
+    mem::zeroed,
+    slice::from_raw_parts
+};
+
+// Structure representing a server that supports HTTP and WebSocket requests.
+// Server capacity is limited and is intended to handle only a small number of
+// simultaneous connections (e.g. for communicating between applications on
+// localhost). The functions of this structure are safe to call from any thread
+// in the brower process unless otherwise indicated.
+ref_counted_ptr!(Server, cef_server_t);
+
+impl Server {
+    /// Create a new server that binds to |address| and |port|. |address| must be
+    /// a valid IPv4 or IPv6 address (e.g. 127.0.0.1 or ::1) and |port| must be a
+    /// port number outside of the reserved range (e.g. between 1025 and 65535 on
+    /// most platforms). |backlog| is the maximum number of pending connections. A
+    /// new thread will be created for each CreateServer call (the "dedicated
+    /// server thread"). It is therefore recommended to use a different
+    /// cef_server_handler_t instance for each CreateServer call to avoid thread
+    /// safety issues in the cef_server_handler_t implementation. The
+    /// cef_server_handler_t::OnServerCreated function will be called on the
+    /// dedicated server thread to report success or failure. See
+    /// cef_server_handler_t::OnServerCreated documentation for a description of
+    /// server lifespan.
+    pub fn create(address: &str, port: u16, backlog: i32, handler: ServerHandler) {
+        let address = CefString::new(address);
+
+        unsafe {
+            cef_server_create(address.as_ptr(), port, backlog as c_int, handler.into_raw());
+        }
+    }
+
+    /// Returns the task runner for the dedicated server thread.
+    pub fn get_task_runner(&self) -> Result<Option<TaskRunner>> {
+        try_c!(self, get_task_runner, {
+            Ok(TaskRunner::from_ptr(get_task_runner(self.as_ptr())))
+        })
+    }
+
+    /// Stop the server and shut down the dedicated server thread. See
+    /// cef_server_handler_t::OnServerCreated documentation for a description of
+    /// server lifespan.
+    pub fn shutdown(&self) -> Result<()> {
+        try_c!(self, shutdown, { Ok(shutdown(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if the server is currently running and accepting incoming
+    /// connections. See cef_server_handler_t::OnServerCreated documentation for a
+    /// description of server lifespan. This function must be called on the
+    /// dedicated server thread.
+    pub fn is_running(&self) -> Result<bool> {
+        try_c!(self, is_running, { Ok(is_running(self.as_ptr()) != 0) })
+    }
+
+    /// Returns the server address including the port number.
+    pub fn get_address(&self) -> Result<String> {
+        try_c!(self, get_address, {
+            let s = get_address(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Returns true (1) if the server currently has a connection. This function
+    /// must be called on the dedicated server thread.
+    pub fn has_connection(&self) -> Result<bool> {
+        try_c!(self, has_connection, {
+            Ok(has_connection(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if |connection_id| represents a valid connection. This
+    /// function must be called on the dedicated server thread.
+    pub fn is_valid_connection(&self, connection_id: i32) -> Result<bool> {
+        try_c!(self, is_valid_connection, {
+            Ok(is_valid_connection(self.as_ptr(), connection_id as c_int) != 0)
+        })
+    }
+
+    /// Send an HTTP 200 "OK" response to the connection identified by
+    /// |connection_id|. |content_type| is the response content type (e.g.
+    /// "text/html"), |data| is the response content, and |data_size| is the size
+    /// of |data| in bytes. The contents of |data| will be copied. The connection
+    /// will be closed automatically after the response is sent.
+    pub fn send_http200response(
+        &self,
+        connection_id: i32,
+        content_type: &str,
+        data: &[u8]
+    ) -> Result<()> {
+        try_c!(self, send_http200response, {
+            let content_type = CefString::new(content_type);
+
+            Ok(send_http200response(
+                self.as_ptr(),
+                connection_id as c_int,
+                content_type.as_ptr(),
+                data.as_ptr() as *const c_void,
+                data.len()
+            ))
+        })
+    }
+
+    /// Send an HTTP 404 "Not Found" response to the connection identified by
+    /// |connection_id|. The connection will be closed automatically after the
+    /// response is sent.
+    pub fn send_http404response(&self, connection_id: i32) -> Result<()> {
+        try_c!(self, send_http404response, {
+            Ok(send_http404response(self.as_ptr(), connection_id as c_int))
+        })
+    }
+
+    /// Send an HTTP 500 "Internal Server Error" response to the connection
+    /// identified by |connection_id|. |error_message| is the associated error
+    /// message. The connection will be closed automatically after the response is
+    /// sent.
+    pub fn send_http500response(&self, connection_id: i32, error_message: &str) -> Result<()> {
+        try_c!(self, send_http500response, {
+            let error_message = CefString::new(error_message);
+
+            Ok(send_http500response(
+                self.as_ptr(),
+                connection_id as c_int,
+                error_message.as_ptr()
+            ))
+        })
+    }
+
+    /// Send a custom HTTP response to the connection identified by
+    /// |connection_id|. |response_code| is the HTTP response code sent in the
+    /// status line (e.g. 200), |content_type| is the response content type sent
+    /// as the "Content-Type" header (e.g. "text/html"), |content_length| is the
+    /// expected content length, and |extra_headers| is the map of extra response
+    /// headers. If |content_length| is >= 0 then the "Content-Length" header will
+    /// be sent. If |content_length| is 0 then no content is expected and the
+    /// connection will be closed automatically after the response is sent. If
+    /// |content_length| is < 0 then no "Content-Length" header will be sent and
+    /// the client will continue reading until the connection is closed. Use the
+    /// SendRawData function to send the content, if applicable, and call
+    /// CloseConnection after all content has been sent.
+    pub fn send_http_response(
+        &self,
+        connection_id: i32,
+        response_code: i32,
+        content_type: &str,
+        content_length: i64,
+        extra_headers: &HashMap<String, Vec<String>>
+    ) -> Result<()> {
+        try_c!(self, send_http_response, {
+            let content_type = CefString::new(content_type);
+            let mut extra_headers = CefStringMultiMap::from(extra_headers);
+
+            Ok(send_http_response(
+                self.as_ptr(),
+                connection_id as c_int,
+                response_code as c_int,
+                content_type.as_ptr(),
+                content_length,
+                extra_headers.as_mut_ptr()
+            ))
+        })
+    }
+
+    /// Send raw data directly to the connection identified by |connection_id|.
+    /// |data| is the raw data and |data_size| is the size of |data| in bytes. The
+    /// contents of |data| will be copied. No validation of |data| is performed
+    /// internally so the client should be careful to send the amount indicated
+    /// by the "Content-Length" header, if specified. See SendHttpResponse
+    /// documentation for intended usage.
+    pub fn send_raw_data(&self, connection_id: i32, data: &[u8]) -> Result<()> {
+        try_c!(self, send_raw_data, {
+            Ok(send_raw_data(
+                self.as_ptr(),
+                connection_id as c_int,
+                data.as_ptr() as *const c_void,
+                data.len()
+            ))
+        })
+    }
+
+    /// Close the connection identified by |connection_id|. See SendHttpResponse
+    /// documentation for intended usage.
+    pub fn close_connection(&self, connection_id: i32) -> Result<()> {
+        try_c!(self, close_connection, {
+            Ok(close_connection(self.as_ptr(), connection_id as c_int))
+        })
+    }
+
+    /// Send a WebSocket message to the connection identified by |connection_id|.
+    /// |data| is the response content and |data_size| is the size of |data| in
+    /// bytes. The contents of |data| will be copied. See
+    /// cef_server_handler_t::OnWebSocketRequest documentation for intended usage.
+    pub fn send_web_socket_message(&self, connection_id: i32, data: &[u8]) -> Result<()> {
+        try_c!(self, send_web_socket_message, {
+            Ok(send_web_socket_message(
+                self.as_ptr(),
+                connection_id as c_int,
+                data.as_ptr() as *const c_void,
+                data.len()
+            ))
+        })
+    }
+}
+
+/// Implement this structure to handle HTTP server requests. A new thread will be
+/// created for each cef_server_t::CreateServer call (the "dedicated server
+/// thread"), and the functions of this structure will be called on that thread.
+/// It is therefore recommended to use a different cef_server_handler_t instance
+/// for each cef_server_t::CreateServer call to avoid thread safety issues in
+/// the cef_server_handler_t implementation.
+pub trait ServerHandlerCallbacks: Send + Sync + 'static {
+    /// Called when |server| is created. If the server was started successfully
+    /// then cef_server_t::IsRunning will return true (1). The server will
+    /// continue running until cef_server_t::Shutdown is called, after which time
+    /// OnServerDestroyed will be called. If the server failed to start then
+    /// OnServerDestroyed will be called immediately after this function returns.
+    fn on_server_created(&mut self, server: Server);
+
+    /// Called when |server| is destroyed. The server thread will be stopped after
+    /// this function returns. The client should release any references to
+    /// |server| when this function is called. See OnServerCreated documentation
+    /// for a description of server lifespan.
+    fn on_server_destroyed(&mut self, server: Server);
+
+    /// Called when a client connects to |server|. |connection_id| uniquely
+    /// identifies the connection. Each call to this function will have a
+    /// matching call to OnClientDisconnected.
+    fn on_client_connected(&mut self, server: Server, connection_id: i32);
+
+    /// Called when a client disconnects from |server|. |connection_id| uniquely
+    /// identifies the connection. The client should release any data associated
+    /// with |connection_id| when this function is called and |connection_id|
+    /// should no longer be passed to cef_server_t functions. Disconnects can
+    /// originate from either the client or the server. For example, the server
+    /// will disconnect automatically after a cef_server_t::SendHttpXXXResponse
+    /// function is called.
+    fn on_client_disconnected(&mut self, server: Server, connection_id: i32);
+
+    /// Called when |server| receives an HTTP request. |connection_id| uniquely
+    /// identifies the connection, |client_address| is the requesting IPv4 or
+    /// IPv6 client address including port number, and |request| contains the
+    /// request contents (URL, function, headers and optional POST data). Call
+    /// cef_server_t functions either synchronously or asynchronusly to send a
+    /// response.
+    fn on_http_request(
+        &mut self,
+        server: Server,
+        connection_id: i32,
+        client_address: String,
+        request: Request
+    );
+
+    /// Called when |server| receives a WebSocket request. |connection_id|
+    /// uniquely identifies the connection, |client_address| is the requesting
+    /// IPv4 or IPv6 client address including port number, and |request| contains
+    /// the request contents (URL, function, headers and optional POST data).
+    /// Execute |callback| either synchronously or asynchronously to accept or
+    /// decline the WebSocket connection. If the request is accepted then
+    /// OnWebSocketConnected will be called after the WebSocket has connected and
+    /// incoming messages will be delivered to the OnWebSocketMessage callback. If
+    /// the request is declined then the client will be disconnected and
+    /// OnClientDisconnected will be called. Call the
+    /// cef_server_t::SendWebSocketMessage function after receiving the
+    /// OnWebSocketConnected callback to respond with WebSocket messages.
+    fn on_web_socket_request(
+        &mut self,
+        server: Server,
+        connection_id: i32,
+        client_address: String,
+        request: Request,
+        callback: Callback
+    );
+
+    /// Called after the client has accepted the WebSocket connection for |server|
+    /// and |connection_id| via the OnWebSocketRequest callback. See
+    /// OnWebSocketRequest documentation for intended usage.
+    fn on_web_socket_connected(&mut self, server: Server, connection_id: i32);
+
+    /// Called when |server| receives an WebSocket message. |connection_id|
+    /// uniquely identifies the connection, |data| is the message content and
+    /// |data_size| is the size of |data| in bytes. Do not keep a reference to
+    /// |data| outside of this function. See OnWebSocketRequest documentation for
+    /// intended usage.
+    fn on_web_socket_message(&mut self, server: Server, connection_id: i32, data: &[u8]);
+}
+
+// Implement this structure to handle HTTP server requests. A new thread will be
+// created for each cef_server_t::CreateServer call (the "dedicated server
+// thread"), and the functions of this structure will be called on that thread.
+// It is therefore recommended to use a different cef_server_handler_t instance
+// for each cef_server_t::CreateServer call to avoid thread safety issues in
+// the cef_server_handler_t implementation.
+ref_counted_ptr!(ServerHandler, cef_server_handler_t);
+
+impl ServerHandler {
+    pub fn new<C: ServerHandlerCallbacks>(delegate: C) -> Self {
+        Self(ServerHandlerWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct ServerHandlerWrapper(Box<dyn ServerHandlerCallbacks>);
+
+impl ServerHandlerWrapper {
+    pub fn new<C: ServerHandlerCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Called when |server| is created. If the server was started successfully
+    /// then cef_server_t::IsRunning will return true (1). The server will
+    /// continue running until cef_server_t::Shutdown is called, after which time
+    /// OnServerDestroyed will be called. If the server failed to start then
+    /// OnServerDestroyed will be called immediately after this function returns.
+    unsafe extern "C" fn c_on_server_created(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+
+        this.0.on_server_created(server);
+    }
+
+    /// Called when |server| is destroyed. The server thread will be stopped after
+    /// this function returns. The client should release any references to
+    /// |server| when this function is called. See OnServerCreated documentation
+    /// for a description of server lifespan.
+    unsafe extern "C" fn c_on_server_destroyed(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+
+        this.0.on_server_destroyed(server);
+    }
+
+    /// Called when a client connects to |server|. |connection_id| uniquely
+    /// identifies the connection. Each call to this function will have a
+    /// matching call to OnClientDisconnected.
+    unsafe extern "C" fn c_on_client_connected(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t,
+        connection_id: c_int
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+
+        this.0
+            .on_client_connected(server, connection_id as i32);
+    }
+
+    /// Called when a client disconnects from |server|. |connection_id| uniquely
+    /// identifies the connection. The client should release any data associated
+    /// with |connection_id| when this function is called and |connection_id|
+    /// should no longer be passed to cef_server_t functions. Disconnects can
+    /// originate from either the client or the server. For example, the server
+    /// will disconnect automatically after a cef_server_t::SendHttpXXXResponse
+    /// function is called.
+    unsafe extern "C" fn c_on_client_disconnected(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t,
+        connection_id: c_int
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+
+        this.0
+            .on_client_disconnected(server, connection_id as i32);
+    }
+
+    /// Called when |server| receives an HTTP request. |connection_id| uniquely
+    /// identifies the connection, |client_address| is the requesting IPv4 or
+    /// IPv6 client address including port number, and |request| contains the
+    /// request contents (URL, function, headers and optional POST data). Call
+    /// cef_server_t functions either synchronously or asynchronusly to send a
+    /// response.
+    unsafe extern "C" fn c_on_http_request(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t,
+        connection_id: c_int,
+        client_address: *const cef_string_t,
+        request: *mut cef_request_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+        let client_address: String = CefString::from_ptr_unchecked(client_address).into();
+        let request = Request::from_ptr_unchecked(request);
+
+        this.0
+            .on_http_request(server, connection_id as i32, client_address, request);
+    }
+
+    /// Called when |server| receives a WebSocket request. |connection_id|
+    /// uniquely identifies the connection, |client_address| is the requesting
+    /// IPv4 or IPv6 client address including port number, and |request| contains
+    /// the request contents (URL, function, headers and optional POST data).
+    /// Execute |callback| either synchronously or asynchronously to accept or
+    /// decline the WebSocket connection. If the request is accepted then
+    /// OnWebSocketConnected will be called after the WebSocket has connected and
+    /// incoming messages will be delivered to the OnWebSocketMessage callback. If
+    /// the request is declined then the client will be disconnected and
+    /// OnClientDisconnected will be called. Call the
+    /// cef_server_t::SendWebSocketMessage function after receiving the
+    /// OnWebSocketConnected callback to respond with WebSocket messages.
+    unsafe extern "C" fn c_on_web_socket_request(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t,
+        connection_id: c_int,
+        client_address: *const cef_string_t,
+        request: *mut cef_request_t,
+        callback: *mut cef_callback_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+        let client_address: String = CefString::from_ptr_unchecked(client_address).into();
+        let request = Request::from_ptr_unchecked(request);
+        let callback = Callback::from_ptr_unchecked(callback);
+
+        this.0.on_web_socket_request(
+            server,
+            connection_id as i32,
+            client_address,
+            request,
+            callback
+        );
+    }
+
+    /// Called after the client has accepted the WebSocket connection for |server|
+    /// and |connection_id| via the OnWebSocketRequest callback. See
+    /// OnWebSocketRequest documentation for intended usage.
+    unsafe extern "C" fn c_on_web_socket_connected(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t,
+        connection_id: c_int
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+
+        this.0
+            .on_web_socket_connected(server, connection_id as i32);
+    }
+
+    /// Called when |server| receives an WebSocket message. |connection_id|
+    /// uniquely identifies the connection, |data| is the message content and
+    /// |data_size| is the size of |data| in bytes. Do not keep a reference to
+    /// |data| outside of this function. See OnWebSocketRequest documentation for
+    /// intended usage.
+    unsafe extern "C" fn c_on_web_socket_message(
+        this: *mut cef_server_handler_t,
+        server: *mut cef_server_t,
+        connection_id: c_int,
+        data: *const c_void,
+        data_size: usize
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let server = Server::from_ptr_unchecked(server);
+        let data: &[u8] = match data.is_null() {
+            true => &[],
+            false => from_raw_parts(data as *const u8, data_size)
+        };
+
+        this.0
+            .on_web_socket_message(server, connection_id as i32, data);
+    }
+}
+
+impl Wrappable for ServerHandlerWrapper {
+    type Cef = cef_server_handler_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_server_handler_t> {
+        RefCountedPtr::wrap(
+            cef_server_handler_t {
+                base:                    unsafe { zeroed() },
+                on_server_created:       Some(Self::c_on_server_created),
+                on_server_destroyed:     Some(Self::c_on_server_destroyed),
+                on_client_connected:     Some(Self::c_on_client_connected),
+                on_client_disconnected:  Some(Self::c_on_client_disconnected),
+                on_http_request:         Some(Self::c_on_http_request),
+                on_web_socket_request:   Some(Self::c_on_web_socket_request),
+                on_web_socket_connected: Some(Self::c_on_web_socket_connected),
+                on_web_socket_message:   Some(Self::c_on_web_socket_message)
+            },
+            self
+        )
+    }
+}
@@ -88,6 +88,11 @@ impl Settings {
         self
     }
 
+    /// Returns true if the browser process message loop runs in a separate thread.
+    pub fn is_multi_threaded_message_loop(&self) -> bool {
+        self.0.multi_threaded_message_loop != 0
+    }
+
     /// Set to true (1) to control browser process main (UI) thread message pump
     /// scheduling via the CefBrowserProcessHandler::OnScheduleMessagePumpWork()
     /// callback. This option is recommended for use in combination with the
@@ -102,6 +107,11 @@ impl Settings {
         self
     }
 
+    /// Returns true if the message pump is scheduled externally.
+    pub fn is_external_message_pump(&self) -> bool {
+        self.0.external_message_pump != 0
+    }
+
     /// Set to true (1) to enable windowless (off-screen) rendering support. Do
     /// not enable this value if the application does not use windowless rendering
     /// as it may reduce rendering performance on some systems.
@@ -1,7 +1,7 @@
 use crate::{ref_counted_ptr, try_c};
 use anyhow::Result;
 use cef_ui_sys::cef_shared_memory_region_t;
-use std::ffi::c_void;
+use std::{ffi::c_void, slice::from_raw_parts};
 
 // Structure that wraps platform-dependent share memory region mapping.
 ref_counted_ptr!(SharedMemoryRegion, cef_shared_memory_region_t);
@@ -22,4 +22,16 @@ impl SharedMemoryRegion {
     pub fn memory(&self) -> Result<*mut c_void> {
         try_c!(self, memory, { Ok(memory(self.as_ptr())) })
     }
+
+    /// Returns the contents of the mapping. Returns an empty slice for invalid
+    /// instances.
+    pub fn as_slice(&self) -> Result<&[u8]> {
+        let memory = self.memory()?;
+        let size = self.size()?;
+
+        match memory.is_null() || size == 0 {
+            true => Ok(&[]),
+            false => Ok(unsafe { from_raw_parts(memory as *const u8, size) })
+        }
+    }
 }
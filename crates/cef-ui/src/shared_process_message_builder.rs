@@ -0,0 +1,78 @@
+use crate::{ref_counted, try_c, CefString, ProcessMessage, RefCountedPtr};
+use anyhow::Result;
+use cef_ui_sys::{cef_shared_process_message_builder_create, cef_shared_process_message_builder_t};
+use std::{cell::Cell, ffi::c_void, marker::PhantomData, slice::from_raw_parts_mut};
+
+ref_counted!(cef_shared_process_message_builder_t);
+
+/// Structure that builds a cef_process_message_t containing a shared memory
+/// region. This structure is not thread-safe but may be used exclusively on a
+/// different thread from the one which constructed it.
+///
+/// Unlike other CEF wrappers this is a unique owner. It can't be cloned or
+/// shared between threads, so at most one mutable view of the shared memory
+/// exists at a time, and building the message consumes the builder.
+pub struct SharedProcessMessageBuilder(
+    RefCountedPtr<cef_shared_process_message_builder_t>,
+    PhantomData<Cell<()>>
+);
+
+impl SharedProcessMessageBuilder {
+    /// Creates a new SharedProcessMessageBuilder with the specified |name| and
+    /// shared memory region of specified |byte_size|.
+    pub fn create(name: &str, byte_size: usize) -> Option<SharedProcessMessageBuilder> {
+        let name = CefString::new(name);
+
+        unsafe {
+            RefCountedPtr::from_ptr(cef_shared_process_message_builder_create(
+                name.as_ptr(),
+                byte_size
+            ))
+            .map(|builder| Self(builder, PhantomData))
+        }
+    }
+
+    /// Returns the raw pointer.
+    unsafe fn as_ptr(&self) -> *mut cef_shared_process_message_builder_t {
+        self.0.as_ptr()
+    }
+
+    /// Returns true (1) if the builder is valid.
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, { Ok(is_valid(self.as_ptr()) != 0) })
+    }
+
+    /// Returns the size of the shared memory region in bytes. Returns 0 for
+    /// invalid instances.
+    pub fn size(&self) -> Result<usize> {
+        try_c!(self, size, { Ok(size(self.as_ptr())) })
+    }
+
+    /// Returns the pointer to the writable memory. Returns nullptr for invalid
+    /// instances. The returned pointer is only valid for the life span of this
+    /// object.
+    fn memory(&self) -> Result<*mut c_void> {
+        try_c!(self, memory, { Ok(memory(self.as_ptr())) })
+    }
+
+    /// Returns the writable contents of the shared memory region. Returns an
+    /// empty slice for invalid instances.
+    pub fn as_mut_slice(&mut self) -> Result<&mut [u8]> {
+        let memory = self.memory()?;
+        let size = self.size()?;
+
+        match memory.is_null() || size == 0 {
+            true => Ok(&mut []),
+            false => Ok(unsafe { from_raw_parts_mut(memory as *mut u8, size) })
+        }
+    }
+
+    /// Creates a new cef_process_message_t from the data provided to the
+    /// builder. Returns None for invalid instances. Consumes the builder, as
+    /// CEF invalidates it.
+    pub fn build(self) -> Result<Option<ProcessMessage>> {
+        try_c!(self, build, {
+            Ok(ProcessMessage::from_ptr(build(self.as_ptr())))
+        })
+    }
+}
@@ -0,0 +1,435 @@
+use crate::{ref_counted_ptr, try_c, CefString, RefCountedPtr, Wrappable, Wrapped};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_read_handler_t, cef_stream_reader_create_for_data, cef_stream_reader_create_for_file,
+    cef_stream_reader_create_for_handler, cef_stream_reader_t, cef_stream_writer_create_for_file,
+    cef_stream_writer_create_for_handler, cef_stream_writer_t, cef_write_handler_t, SEEK_CUR,
+    SEEK_END, SEEK_SET
+};
+use parking_lot::Mutex;
+use std::{
+    ffi::{c_int, c_void},
+    io::{self, ErrorKind, Read, Seek, SeekFrom, Write},
+    mem::zeroed,
+    path::Path,
+    slice::{from_raw_parts, from_raw_parts_mut}
+};
+
+/// Converts a Rust seek position to a CEF offset and whence.
+fn to_cef_seek(pos: SeekFrom) -> (i64, c_int) {
+    match pos {
+        SeekFrom::Start(offset) => (offset as i64, SEEK_SET as c_int),
+        SeekFrom::Current(offset) => (offset, SEEK_CUR as c_int),
+        SeekFrom::End(offset) => (offset, SEEK_END as c_int)
+    }
+}
+
+/// Converts a CEF offset and whence to a Rust seek position.
+fn from_cef_seek(offset: i64, whence: c_int) -> Option<SeekFrom> {
+    match whence as u32 {
+        SEEK_SET => u64::try_from(offset)
+            .ok()
+            .map(SeekFrom::Start),
+        SEEK_CUR => Some(SeekFrom::Current(offset)),
+        SEEK_END => Some(SeekFrom::End(offset)),
+        _ => None
+    }
+}
+
+// Structure used to read data from a stream. The functions of this structure
+// may be called on any thread.
+//
+// Reading and seeking is done through the std::io::Read and std::io::Seek
+// traits.
+ref_counted_ptr!(StreamReader, cef_stream_reader_t);
+
+impl StreamReader {
+    /// Create a new StreamReader object from a file.
+    pub fn from_file(path: &Path) -> Option<StreamReader> {
+        let path = CefString::new(&path.to_string_lossy());
+
+        unsafe { StreamReader::from_ptr(cef_stream_reader_create_for_file(path.as_ptr())) }
+    }
+
+    /// Create a new StreamReader object from data. The data is copied.
+    pub fn from_data(data: &[u8]) -> Option<StreamReader> {
+        unsafe {
+            StreamReader::from_ptr(cef_stream_reader_create_for_data(
+                data.as_ptr() as *mut c_void,
+                data.len()
+            ))
+        }
+    }
+
+    /// Create a new StreamReader object from a custom handler.
+    pub fn from_handler(handler: ReadHandler) -> Option<StreamReader> {
+        unsafe { StreamReader::from_ptr(cef_stream_reader_create_for_handler(handler.into_raw())) }
+    }
+
+    /// Create a new StreamReader object from a Rust reader.
+    pub fn from_read<R: Read + Seek + Send + 'static>(reader: R) -> Option<StreamReader> {
+        Self::from_handler(ReadHandler::new(reader))
+    }
+
+    /// Return the current offset position.
+    pub fn tell(&self) -> Result<i64> {
+        try_c!(self, tell, { Ok(tell(self.as_ptr())) })
+    }
+
+    /// Return non-zero if at end of file.
+    pub fn eof(&self) -> Result<bool> {
+        try_c!(self, eof, { Ok(eof(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this reader performs work like accessing the file
+    /// system which may block. Used as a hint for determining the thread to
+    /// access the reader from.
+    pub fn may_block(&self) -> Result<bool> {
+        try_c!(self, may_block, { Ok(may_block(self.as_ptr()) != 0) })
+    }
+}
+
+impl Read for StreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        try_c!(self, read, {
+            Ok(read(
+                self.as_ptr(),
+                buf.as_mut_ptr() as *mut c_void,
+                1,
+                buf.len()
+            ))
+        })
+        .map_err(io::Error::other)
+    }
+}
+
+impl Seek for StreamReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (offset, whence) = to_cef_seek(pos);
+
+        match try_c!(self, seek, { Ok(seek(self.as_ptr(), offset, whence)) }) {
+            Ok(0) => self
+                .tell()
+                .map(|position| position as u64)
+                .map_err(io::Error::other),
+            Ok(_) => Err(io::Error::new(ErrorKind::InvalidInput, "seek failed")),
+            Err(e) => Err(io::Error::other(e))
+        }
+    }
+}
+
+/// Implemented by readers that can be used to back a StreamReader.
+trait ReadHandlerCallbacks: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> ReadHandlerCallbacks for T {}
+
+// Structure the client can implement to provide a custom stream reader. The
+// functions of this structure may be called on any thread.
+ref_counted_ptr!(ReadHandler, cef_read_handler_t);
+
+impl ReadHandler {
+    pub fn new<R: Read + Seek + Send + 'static>(reader: R) -> Self {
+        Self(ReadHandlerWrapper::new(reader).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct ReadHandlerWrapper(Mutex<Box<dyn ReadHandlerCallbacks>>);
+
+impl ReadHandlerWrapper {
+    pub fn new<R: Read + Seek + Send + 'static>(reader: R) -> Self {
+        Self(Mutex::new(Box::new(reader)))
+    }
+
+    /// Read raw binary data.
+    unsafe extern "C" fn c_read(
+        this: *mut cef_read_handler_t,
+        ptr: *mut c_void,
+        size: usize,
+        n: usize
+    ) -> usize {
+        let this: &Self = Wrapped::wrappable(this);
+
+        if ptr.is_null() {
+            return 0;
+        }
+
+        let len = match size.checked_mul(n) {
+            Some(len) if len > 0 => len,
+            _ => return 0
+        };
+
+        let buf = from_raw_parts_mut(ptr as *mut u8, len);
+        let mut reader = this.0.lock();
+        let mut total = 0;
+
+        while total < buf.len() {
+            match reader.read(&mut buf[total..]) {
+                Ok(0) => break,
+                Ok(count) => total += count,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => break
+            }
+        }
+
+        total / size
+    }
+
+    /// Seek to the specified offset position. |whence| may be any one of
+    /// SEEK_CUR, SEEK_END or SEEK_SET. Return zero on success and non-zero on
+    /// failure.
+    unsafe extern "C" fn c_seek(
+        this: *mut cef_read_handler_t,
+        offset: i64,
+        whence: c_int
+    ) -> c_int {
+        let this: &Self = Wrapped::wrappable(this);
+
+        match from_cef_seek(offset, whence).map(|pos| this.0.lock().seek(pos)) {
+            Some(Ok(_)) => 0,
+            _ => -1
+        }
+    }
+
+    /// Return the current offset position.
+    unsafe extern "C" fn c_tell(this: *mut cef_read_handler_t) -> i64 {
+        let this: &Self = Wrapped::wrappable(this);
+
+        this.0
+            .lock()
+            .stream_position()
+            .map(|position| position as i64)
+            .unwrap_or(-1)
+    }
+
+    /// Return non-zero if at end of file.
+    unsafe extern "C" fn c_eof(this: *mut cef_read_handler_t) -> c_int {
+        let this: &Self = Wrapped::wrappable(this);
+        let mut reader = this.0.lock();
+
+        let eof = (|| -> io::Result<bool> {
+            let position = reader.stream_position()?;
+            let end = reader.seek(SeekFrom::End(0))?;
+
+            reader.seek(SeekFrom::Start(position))?;
+
+            Ok(position >= end)
+        })();
+
+        eof.unwrap_or(true) as c_int
+    }
+
+    /// Return true (1) if this handler performs work like accessing the file
+    /// system which may block. Used as a hint for determining the thread to
+    /// access the handler from.
+    unsafe extern "C" fn c_may_block(_this: *mut cef_read_handler_t) -> c_int {
+        1
+    }
+}
+
+impl Wrappable for ReadHandlerWrapper {
+    type Cef = cef_read_handler_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_read_handler_t> {
+        RefCountedPtr::wrap(
+            cef_read_handler_t {
+                base:      unsafe { zeroed() },
+                read:      Some(Self::c_read),
+                seek:      Some(Self::c_seek),
+                tell:      Some(Self::c_tell),
+                eof:       Some(Self::c_eof),
+                may_block: Some(Self::c_may_block)
+            },
+            self
+        )
+    }
+}
+
+// Structure used to write data to a stream. The functions of this structure
+// may be called on any thread.
+//
+// Writing, flushing and seeking is done through the std::io::Write and
+// std::io::Seek traits.
+ref_counted_ptr!(StreamWriter, cef_stream_writer_t);
+
+impl StreamWriter {
+    /// Create a new StreamWriter object for a file.
+    pub fn from_file(path: &Path) -> Option<StreamWriter> {
+        let path = CefString::new(&path.to_string_lossy());
+
+        unsafe { StreamWriter::from_ptr(cef_stream_writer_create_for_file(path.as_ptr())) }
+    }
+
+    /// Create a new StreamWriter object for a custom handler.
+    pub fn from_handler(handler: WriteHandler) -> Option<StreamWriter> {
+        unsafe { StreamWriter::from_ptr(cef_stream_writer_create_for_handler(handler.into_raw())) }
+    }
+
+    /// Create a new StreamWriter object from a Rust writer.
+    pub fn from_write<W: Write + Seek + Send + 'static>(writer: W) -> Option<StreamWriter> {
+        Self::from_handler(WriteHandler::new(writer))
+    }
+
+    /// Return the current offset position.
+    pub fn tell(&self) -> Result<i64> {
+        try_c!(self, tell, { Ok(tell(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if this writer performs work like accessing the file
+    /// system which may block. Used as a hint for determining the thread to
+    /// access the writer from.
+    pub fn may_block(&self) -> Result<bool> {
+        try_c!(self, may_block, { Ok(may_block(self.as_ptr()) != 0) })
+    }
+}
+
+impl Write for StreamWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        try_c!(self, write, {
+            Ok(write(
+                self.as_ptr(),
+                buf.as_ptr() as *const c_void,
+                1,
+                buf.len()
+            ))
+        })
+        .map_err(io::Error::other)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match try_c!(self, flush, { Ok(flush(self.as_ptr())) }) {
+            Ok(0) => Ok(()),
+            Ok(_) => Err(io::Error::other("flush failed")),
+            Err(e) => Err(io::Error::other(e))
+        }
+    }
+}
+
+impl Seek for StreamWriter {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (offset, whence) = to_cef_seek(pos);
+
+        match try_c!(self, seek, { Ok(seek(self.as_ptr(), offset, whence)) }) {
+            Ok(0) => self
+                .tell()
+                .map(|position| position as u64)
+                .map_err(io::Error::other),
+            Ok(_) => Err(io::Error::new(ErrorKind::InvalidInput, "seek failed")),
+            Err(e) => Err(io::Error::other(e))
+        }
+    }
+}
+
+/// Implemented by writers that can be used to back a StreamWriter.
+trait WriteHandlerCallbacks: Write + Seek + Send {}
+
+impl<T: Write + Seek + Send> WriteHandlerCallbacks for T {}
+
+// Structure the client can implement to provide a custom stream writer. The
+// functions of this structure may be called on any thread.
+ref_counted_ptr!(WriteHandler, cef_write_handler_t);
+
+impl WriteHandler {
+    pub fn new<W: Write + Seek + Send + 'static>(writer: W) -> Self {
+        Self(WriteHandlerWrapper::new(writer).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct WriteHandlerWrapper(Mutex<Box<dyn WriteHandlerCallbacks>>);
+
+impl WriteHandlerWrapper {
+    pub fn new<W: Write + Seek + Send + 'static>(writer: W) -> Self {
+        Self(Mutex::new(Box::new(writer)))
+    }
+
+    /// Write raw binary data.
+    unsafe extern "C" fn c_write(
+        this: *mut cef_write_handler_t,
+        ptr: *const c_void,
+        size: usize,
+        n: usize
+    ) -> usize {
+        let this: &Self = Wrapped::wrappable(this);
+
+        if ptr.is_null() {
+            return 0;
+        }
+
+        let len = match size.checked_mul(n) {
+            Some(len) if len > 0 => len,
+            _ => return 0
+        };
+
+        let buf = from_raw_parts(ptr as *const u8, len);
+
+        match this.0.lock().write_all(buf) {
+            Ok(_) => n,
+            Err(_) => 0
+        }
+    }
+
+    /// Seek to the specified offset position. |whence| may be any one of
+    /// SEEK_CUR, SEEK_END or SEEK_SET. Return zero on success and non-zero on
+    /// failure.
+    unsafe extern "C" fn c_seek(
+        this: *mut cef_write_handler_t,
+        offset: i64,
+        whence: c_int
+    ) -> c_int {
+        let this: &Self = Wrapped::wrappable(this);
+
+        match from_cef_seek(offset, whence).map(|pos| this.0.lock().seek(pos)) {
+            Some(Ok(_)) => 0,
+            _ => -1
+        }
+    }
+
+    /// Return the current offset position.
+    unsafe extern "C" fn c_tell(this: *mut cef_write_handler_t) -> i64 {
+        let this: &Self = Wrapped::wrappable(this);
+
+        this.0
+            .lock()
+            .stream_position()
+            .map(|position| position as i64)
+            .unwrap_or(-1)
+    }
+
+    /// Flush the stream.
+    unsafe extern "C" fn c_flush(this: *mut cef_write_handler_t) -> c_int {
+        let this: &Self = Wrapped::wrappable(this);
+
+        match this.0.lock().flush() {
+            Ok(_) => 0,
+            Err(_) => -1
+        }
+    }
+
+    /// Return true (1) if this handler performs work like accessing the file
+    /// system which may block. Used as a hint for determining the thread to
+    /// access the handler from.
+    unsafe extern "C" fn c_may_block(_this: *mut cef_write_handler_t) -> c_int {
+        1
+    }
+}
+
+impl Wrappable for WriteHandlerWrapper {
+    type Cef = cef_write_handler_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_write_handler_t> {
+        RefCountedPtr::wrap(
+            cef_write_handler_t {
+                base:      unsafe { zeroed() },
+                write:     Some(Self::c_write),
+                seek:      Some(Self::c_seek),
+                tell:      Some(Self::c_tell),
+                flush:     Some(Self::c_flush),
+                may_block: Some(Self::c_may_block)
+            },
+            self
+        )
+    }
+}
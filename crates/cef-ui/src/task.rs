@@ -0,0 +1,255 @@
+use crate::{ref_counted_ptr, try_c, RefCountedPtr, Wrappable, Wrapped};
+use anyhow::{anyhow, Result};
+use cef_ui_sys::{
+    cef_currently_on, cef_post_delayed_task, cef_post_task, cef_task_runner_get_for_current_thread,
+    cef_task_runner_get_for_thread, cef_task_runner_t, cef_task_t, cef_thread_id_t
+};
+use parking_lot::Mutex;
+use std::{mem::zeroed, time::Duration};
+
+/// Existing thread IDs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ThreadId {
+    // BROWSER PROCESS THREADS -- Only available in the browser process.
+
+    // The main thread in the browser. This will be the same as the main
+    // application thread if cef_initialize() is called with a
+    // CefSettings.multi_threaded_message_loop value of false. Do not perform
+    // blocking tasks on this thread. All tasks posted after
+    // cef_browser_process_handler_t::on_context_initialized() and before
+    // cef_shutdown() are guaranteed to run. This thread will outlive all other
+    // CEF threads.
+    Ui,
+
+    // Used for blocking tasks like file system access where the user won't
+    // notice if the task takes an arbitrarily long time to complete. All tasks
+    // posted after cef_browser_process_handler_t::on_context_initialized() and
+    // before cef_shutdown() are guaranteed to run.
+    FileBackground,
+
+    // Used for blocking tasks like file system access that affect UI or
+    // responsiveness of future user interactions. Do not use if an immediate
+    // response to a user interaction is expected. All tasks posted after
+    // cef_browser_process_handler_t::on_context_initialized() and before
+    // cef_shutdown() are guaranteed to run.
+    FileUserVisible,
+
+    // Used for blocking tasks like file system access that affect UI
+    // immediately after a user interaction. All tasks posted after
+    // cef_browser_process_handler_t::on_context_initialized() and before
+    // cef_shutdown() are guaranteed to run.
+    FileUserBlocking,
+
+    // Used to launch and terminate browser processes.
+    ProcessLauncher,
+
+    // Used to process IPC and network messages. Do not perform blocking tasks
+    // on this thread. All tasks posted after
+    // cef_browser_process_handler_t::on_context_initialized() and before
+    // cef_shutdown() are guaranteed to run.
+    Io,
+
+    // RENDER PROCESS THREADS -- Only available in the render process.
+
+    // The main thread in the renderer. Used for all WebKit and V8 interaction.
+    // Tasks may be posted to this thread after
+    // cef_render_process_handler_t::on_web_kit_initialized but are not
+    // guaranteed to run before sub-process termination (sub-processes may be
+    // killed at any time without warning).
+    Renderer
+}
+
+impl From<cef_thread_id_t> for ThreadId {
+    fn from(value: cef_thread_id_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_thread_id_t> for ThreadId {
+    fn from(value: &cef_thread_id_t) -> Self {
+        match value {
+            cef_thread_id_t::TID_UI => ThreadId::Ui,
+            cef_thread_id_t::TID_FILE_BACKGROUND => ThreadId::FileBackground,
+            cef_thread_id_t::TID_FILE_USER_VISIBLE => ThreadId::FileUserVisible,
+            cef_thread_id_t::TID_FILE_USER_BLOCKING => ThreadId::FileUserBlocking,
+            cef_thread_id_t::TID_PROCESS_LAUNCHER => ThreadId::ProcessLauncher,
+            cef_thread_id_t::TID_IO => ThreadId::Io,
+            cef_thread_id_t::TID_RENDERER => ThreadId::Renderer
+        }
+    }
+}
+
+impl From<ThreadId> for cef_thread_id_t {
+    fn from(value: ThreadId) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&ThreadId> for cef_thread_id_t {
+    fn from(value: &ThreadId) -> Self {
+        match value {
+            ThreadId::Ui => cef_thread_id_t::TID_UI,
+            ThreadId::FileBackground => cef_thread_id_t::TID_FILE_BACKGROUND,
+            ThreadId::FileUserVisible => cef_thread_id_t::TID_FILE_USER_VISIBLE,
+            ThreadId::FileUserBlocking => cef_thread_id_t::TID_FILE_USER_BLOCKING,
+            ThreadId::ProcessLauncher => cef_thread_id_t::TID_PROCESS_LAUNCHER,
+            ThreadId::Io => cef_thread_id_t::TID_IO,
+            ThreadId::Renderer => cef_thread_id_t::TID_RENDERER
+        }
+    }
+}
+
+/// Returns true (1) if called on the specified thread. Equivalent to using
+/// cef_task_runner_t::GetForThread(threadId)->belongs_to_current_thread().
+pub fn currently_on(thread_id: ThreadId) -> bool {
+    unsafe { cef_currently_on(thread_id.into()) != 0 }
+}
+
+/// Post a task for execution on the specified thread. Equivalent to using
+/// cef_task_runner_t::GetForThread(threadId)->PostTask(task).
+pub fn post_task(thread_id: ThreadId, f: impl FnOnce() + Send + 'static) -> Result<()> {
+    let task = Task::new(f);
+
+    match unsafe { cef_post_task(thread_id.into(), task.into_raw()) } {
+        0 => Err(anyhow!("Failed to post task to thread {:?}.", thread_id)),
+        _ => Ok(())
+    }
+}
+
+/// Post a task for delayed execution on the specified thread. Equivalent to
+/// using cef_task_runner_t::GetForThread(threadId)->PostDelayedTask(task,
+/// delay_ms).
+pub fn post_delayed_task(
+    thread_id: ThreadId,
+    delay: Duration,
+    f: impl FnOnce() + Send + 'static
+) -> Result<()> {
+    let task = Task::new(f);
+
+    match unsafe { cef_post_delayed_task(thread_id.into(), task.into_raw(), delay_to_ms(delay)) } {
+        0 => Err(anyhow!(
+            "Failed to post delayed task to thread {:?}.",
+            thread_id
+        )),
+        _ => Ok(())
+    }
+}
+
+/// Converts a delay to whole milliseconds, saturating on overflow.
+fn delay_to_ms(delay: Duration) -> i64 {
+    i64::try_from(delay.as_millis()).unwrap_or(i64::MAX)
+}
+
+// Implement this structure for asynchronous task execution. If the task is
+// posted successfully and if the associated message loop is still running then
+// the execute() function will be called on the target thread. If the task fails
+// to post then the task object may be destroyed on the source thread instead of
+// the target thread. For this reason be cautious when performing work in the
+// task object destructor.
+ref_counted_ptr!(Task, cef_task_t);
+
+impl Task {
+    pub fn new(f: impl FnOnce() + Send + 'static) -> Self {
+        Self(TaskWrapper::new(f).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct TaskWrapper(Mutex<Option<Box<dyn FnOnce() + Send + 'static>>>);
+
+impl TaskWrapper {
+    pub fn new(f: impl FnOnce() + Send + 'static) -> Self {
+        Self(Mutex::new(Some(Box::new(f))))
+    }
+
+    /// Method that will be executed on the target thread.
+    unsafe extern "C" fn c_execute(this: *mut cef_task_t) {
+        let this: &Self = Wrapped::wrappable(this);
+
+        if let Some(f) = this.0.lock().take() {
+            f();
+        }
+    }
+}
+
+impl Wrappable for TaskWrapper {
+    type Cef = cef_task_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<Self::Cef> {
+        RefCountedPtr::wrap(
+            cef_task_t {
+                base:    unsafe { zeroed() },
+                execute: Some(Self::c_execute)
+            },
+            self
+        )
+    }
+}
+
+// Structure that asynchronously executes tasks on the associated thread. It is
+// safe to call the functions of this structure on any thread.
+//
+// CEF maintains multiple internal threads that are used for handling different
+// types of tasks in different processes. The cef_thread_id_t definitions in
+// cef_types.h list the common CEF threads. Task runners are also available for
+// other CEF threads as appropriate (for example, V8 WebWorker threads).
+ref_counted_ptr!(TaskRunner, cef_task_runner_t);
+
+impl TaskRunner {
+    /// Returns the task runner for the current thread. Only CEF threads will have
+    /// task runners. An NULL reference will be returned if this function is
+    /// called on an invalid thread.
+    pub fn get_for_current_thread() -> Option<TaskRunner> {
+        unsafe { TaskRunner::from_ptr(cef_task_runner_get_for_current_thread()) }
+    }
+
+    /// Returns the task runner for the specified CEF thread.
+    pub fn get_for_thread(thread_id: ThreadId) -> Option<TaskRunner> {
+        unsafe { TaskRunner::from_ptr(cef_task_runner_get_for_thread(thread_id.into())) }
+    }
+
+    /// Returns true (1) if this object is pointing to the same task runner as
+    /// |that| object.
+    pub fn is_same(&self, that: TaskRunner) -> Result<bool> {
+        try_c!(self, is_same, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    /// Returns true (1) if this task runner belongs to the current thread.
+    pub fn belongs_to_current_thread(&self) -> Result<bool> {
+        try_c!(self, belongs_to_current_thread, {
+            Ok(belongs_to_current_thread(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if this task runner is for the specified CEF thread.
+    pub fn belongs_to_thread(&self, thread_id: ThreadId) -> Result<bool> {
+        try_c!(self, belongs_to_thread, {
+            Ok(belongs_to_thread(self.as_ptr(), thread_id.into()) != 0)
+        })
+    }
+
+    /// Post a task for execution on the thread associated with this task runner.
+    /// Execution will occur asynchronously.
+    pub fn post_task(&self, f: impl FnOnce() + Send + 'static) -> Result<bool> {
+        try_c!(self, post_task, {
+            Ok(post_task(self.as_ptr(), Task::new(f).into_raw()) != 0)
+        })
+    }
+
+    /// Post a task for delayed execution on the thread associated with this task
+    /// runner. Execution will occur asynchronously. Delayed tasks are not
+    /// supported on V8 WebWorker threads and will be executed without the
+    /// specified delay.
+    pub fn post_delayed_task(
+        &self,
+        delay: Duration,
+        f: impl FnOnce() + Send + 'static
+    ) -> Result<bool> {
+        try_c!(self, post_delayed_task, {
+            Ok(post_delayed_task(self.as_ptr(), Task::new(f).into_raw(), delay_to_ms(delay)) != 0)
+        })
+    }
+}
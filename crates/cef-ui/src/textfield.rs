@@ -0,0 +1,401 @@
+use crate::{
+    ref_counted_ptr, try_c, view_delegate, view_deref, CefString, Color, KeyEvent, Range,
+    RefCountedPtr, TextFieldCommand, TextStyle, ThreadId, View, ViewDelegateCallbacks,
+    ViewDelegateWrappable, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_key_event_t, cef_range_t, cef_textfield_create, cef_textfield_delegate_t, cef_textfield_t
+};
+use std::{
+    ffi::c_int,
+    ptr::{null, null_mut}
+};
+
+/// Implement this structure to handle Textfield events. The functions of this
+/// structure will be called on the browser process UI thread unless otherwise
+/// indicated.
+pub trait TextfieldDelegateCallbacks: ViewDelegateCallbacks {
+    /// Called when |textfield| receives a keyboard event. |event| contains
+    /// information about the keyboard event. Return true (1) if the keyboard
+    /// event was handled or false (0) otherwise for default handling.
+    fn on_key_event(&mut self, textfield: Textfield, event: KeyEvent) -> bool;
+
+    /// Called after performing a user action that may change |textfield|.
+    fn on_after_user_action(&mut self, textfield: Textfield);
+}
+
+// Implement this structure to handle Textfield events. The functions of this
+// structure will be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(TextfieldDelegate, cef_textfield_delegate_t);
+
+impl TextfieldDelegate {
+    pub fn new<C: TextfieldDelegateCallbacks>(delegate: C) -> Self {
+        Self(TextfieldDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct TextfieldDelegateWrapper(Box<dyn TextfieldDelegateCallbacks>);
+
+impl TextfieldDelegateWrapper {
+    pub fn new<C: TextfieldDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Called when |textfield| receives a keyboard event. |event| contains
+    /// information about the keyboard event. Return true (1) if the keyboard
+    /// event was handled or false (0) otherwise for default handling.
+    unsafe extern "C" fn c_on_key_event(
+        this: *mut cef_textfield_delegate_t,
+        textfield: *mut cef_textfield_t,
+        event: *const cef_key_event_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let textfield = Textfield::from_ptr_unchecked(textfield);
+        let event = KeyEvent::from_ptr_unchecked(event);
+
+        this.0
+            .on_key_event(textfield, event) as c_int
+    }
+
+    /// Called after performing a user action that may change |textfield|.
+    unsafe extern "C" fn c_on_after_user_action(
+        this: *mut cef_textfield_delegate_t,
+        textfield: *mut cef_textfield_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let textfield = Textfield::from_ptr_unchecked(textfield);
+
+        this.0
+            .on_after_user_action(textfield);
+    }
+}
+
+impl ViewDelegateWrappable for TextfieldDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for TextfieldDelegateWrapper {
+    type Cef = cef_textfield_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_textfield_delegate_t> {
+        RefCountedPtr::wrap(
+            cef_textfield_delegate_t {
+                base:                 view_delegate::<Self>(),
+                on_key_event:         Some(Self::c_on_key_event),
+                on_after_user_action: Some(Self::c_on_after_user_action)
+            },
+            self
+        )
+    }
+}
+
+// A Textfield supports editing of text. This control is custom rendered with
+// no platform-specific code. Methods must be called on the browser process UI
+// thread unless otherwise indicated.
+ref_counted_ptr!(Textfield, cef_textfield_t);
+
+view_deref!(Textfield, View);
+
+impl Textfield {
+    /// Create a new Textfield.
+    pub fn create(delegate: Option<TextfieldDelegate>) -> Option<Textfield> {
+        unsafe {
+            let delegate = delegate
+                .map(|delegate| delegate.into_raw())
+                .unwrap_or_else(null_mut);
+
+            Textfield::from_ptr(cef_textfield_create(delegate))
+        }
+    }
+
+    /// Sets whether the text will be displayed as asterisks.
+    pub fn set_password_input(&self, password_input: bool) -> Result<()> {
+        try_c!(self, set_password_input, ThreadId::Ui, {
+            Ok(set_password_input(self.as_ptr(), password_input as c_int))
+        })
+    }
+
+    /// Returns true (1) if the text will be displayed as asterisks.
+    pub fn is_password_input(&self) -> Result<bool> {
+        try_c!(self, is_password_input, ThreadId::Ui, {
+            Ok(is_password_input(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Sets whether the text will read-only.
+    pub fn set_read_only(&self, read_only: bool) -> Result<()> {
+        try_c!(self, set_read_only, ThreadId::Ui, {
+            Ok(set_read_only(self.as_ptr(), read_only as c_int))
+        })
+    }
+
+    /// Returns true (1) if the text is read-only.
+    pub fn is_read_only(&self) -> Result<bool> {
+        try_c!(self, is_read_only, ThreadId::Ui, {
+            Ok(is_read_only(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the currently displayed text.
+    pub fn get_text(&self) -> Result<String> {
+        try_c!(self, get_text, ThreadId::Ui, {
+            let s = get_text(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Sets the contents to |text|. The cursor will be moved to end of the text
+    /// if the current position is outside of the text range.
+    pub fn set_text(&self, text: &str) -> Result<()> {
+        try_c!(self, set_text, ThreadId::Ui, {
+            let text = CefString::new(text);
+
+            Ok(set_text(self.as_ptr(), text.as_ptr()))
+        })
+    }
+
+    /// Appends |text| to the previously-existing text.
+    pub fn append_text(&self, text: &str) -> Result<()> {
+        try_c!(self, append_text, ThreadId::Ui, {
+            let text = CefString::new(text);
+
+            Ok(append_text(self.as_ptr(), text.as_ptr()))
+        })
+    }
+
+    /// Inserts |text| at the current cursor position replacing any selected
+    /// text.
+    pub fn insert_or_replace_text(&self, text: &str) -> Result<()> {
+        try_c!(self, insert_or_replace_text, ThreadId::Ui, {
+            let text = CefString::new(text);
+
+            Ok(insert_or_replace_text(self.as_ptr(), text.as_ptr()))
+        })
+    }
+
+    /// Returns true (1) if there is any selected text.
+    pub fn has_selection(&self) -> Result<bool> {
+        try_c!(self, has_selection, ThreadId::Ui, {
+            Ok(has_selection(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the currently selected text.
+    pub fn get_selected_text(&self) -> Result<String> {
+        try_c!(self, get_selected_text, ThreadId::Ui, {
+            let s = get_selected_text(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Selects all text. If |reversed| is true (1) the range will end at the
+    /// logical beginning of the text; this generally shows the leading portion
+    /// of text that overflows its display area.
+    pub fn select_all(&self, reversed: bool) -> Result<()> {
+        try_c!(self, select_all, ThreadId::Ui, {
+            Ok(select_all(self.as_ptr(), reversed as c_int))
+        })
+    }
+
+    /// Clears the text selection and sets the caret to the end.
+    pub fn clear_selection(&self) -> Result<()> {
+        try_c!(self, clear_selection, ThreadId::Ui, {
+            Ok(clear_selection(self.as_ptr()))
+        })
+    }
+
+    /// Returns the selected logical text range.
+    pub fn get_selected_range(&self) -> Result<Range> {
+        try_c!(self, get_selected_range, ThreadId::Ui, {
+            Ok(get_selected_range(self.as_ptr()).into())
+        })
+    }
+
+    /// Selects the specified logical text range.
+    pub fn select_range(&self, range: &Range) -> Result<()> {
+        try_c!(self, select_range, ThreadId::Ui, {
+            let range: cef_range_t = range.into();
+
+            Ok(select_range(self.as_ptr(), &range))
+        })
+    }
+
+    /// Returns the current cursor position.
+    pub fn get_cursor_position(&self) -> Result<usize> {
+        try_c!(self, get_cursor_position, ThreadId::Ui, {
+            Ok(get_cursor_position(self.as_ptr()))
+        })
+    }
+
+    /// Sets the text color.
+    pub fn set_text_color(&self, color: &Color) -> Result<()> {
+        try_c!(self, set_text_color, ThreadId::Ui, {
+            Ok(set_text_color(self.as_ptr(), color.into()))
+        })
+    }
+
+    /// Returns the text color.
+    pub fn get_text_color(&self) -> Result<Color> {
+        try_c!(self, get_text_color, ThreadId::Ui, {
+            Ok(get_text_color(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the selection text color.
+    pub fn set_selection_text_color(&self, color: &Color) -> Result<()> {
+        try_c!(self, set_selection_text_color, ThreadId::Ui, {
+            Ok(set_selection_text_color(self.as_ptr(), color.into()))
+        })
+    }
+
+    /// Returns the selection text color.
+    pub fn get_selection_text_color(&self) -> Result<Color> {
+        try_c!(self, get_selection_text_color, ThreadId::Ui, {
+            Ok(get_selection_text_color(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the selection background color.
+    pub fn set_selection_background_color(&self, color: &Color) -> Result<()> {
+        try_c!(self, set_selection_background_color, ThreadId::Ui, {
+            Ok(set_selection_background_color(self.as_ptr(), color.into()))
+        })
+    }
+
+    /// Returns the selection background color.
+    pub fn get_selection_background_color(&self) -> Result<Color> {
+        try_c!(self, get_selection_background_color, ThreadId::Ui, {
+            Ok(get_selection_background_color(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the font list. The format is "<FONT_FAMILY_LIST>,[STYLES] <SIZE>",
+    /// where:
+    /// - FONT_FAMILY_LIST is a comma-separated list of font family names,
+    /// - STYLES is an optional space-separated list of style names (case-
+    ///   sensitive "Bold" and "Italic" are supported), and
+    /// - SIZE is an integer font size in pixels with the suffix "px".
+    ///
+    /// Here are examples of valid font description strings:
+    /// - "Arial, Helvetica, Bold Italic 14px"
+    /// - "Arial, 14px"
+    pub fn set_font_list(&self, font_list: &str) -> Result<()> {
+        try_c!(self, set_font_list, ThreadId::Ui, {
+            let font_list = CefString::new(font_list);
+
+            Ok(set_font_list(self.as_ptr(), font_list.as_ptr()))
+        })
+    }
+
+    /// Applies |color| to the specified |range| without changing the default
+    /// color. If |range| is None the color will be applied to the full text
+    /// contents.
+    pub fn apply_text_color(&self, color: &Color, range: Option<&Range>) -> Result<()> {
+        try_c!(self, apply_text_color, ThreadId::Ui, {
+            let range: Option<cef_range_t> = range.map(|range| range.into());
+            let range = range
+                .as_ref()
+                .map_or(null(), |range| range as *const cef_range_t);
+
+            Ok(apply_text_color(self.as_ptr(), color.into(), range))
+        })
+    }
+
+    /// Applies |style| to the specified |range| without changing the default
+    /// style. If |add| is true (1) the style will be added, otherwise the style
+    /// will be removed. If |range| is None the style will be applied to the full
+    /// text contents.
+    pub fn apply_text_style(
+        &self,
+        style: TextStyle,
+        add: bool,
+        range: Option<&Range>
+    ) -> Result<()> {
+        try_c!(self, apply_text_style, ThreadId::Ui, {
+            let range: Option<cef_range_t> = range.map(|range| range.into());
+            let range = range
+                .as_ref()
+                .map_or(null(), |range| range as *const cef_range_t);
+
+            Ok(apply_text_style(
+                self.as_ptr(),
+                style.into(),
+                add as c_int,
+                range
+            ))
+        })
+    }
+
+    /// Returns true (1) if the action associated with the specified command id
+    /// is enabled. See additional comments on execute_command().
+    pub fn is_command_enabled(&self, command_id: TextFieldCommand) -> Result<bool> {
+        try_c!(self, is_command_enabled, ThreadId::Ui, {
+            Ok(is_command_enabled(self.as_ptr(), command_id.into()) != 0)
+        })
+    }
+
+    /// Performs the action associated with the specified command id.
+    pub fn execute_command(&self, command_id: TextFieldCommand) -> Result<()> {
+        try_c!(self, execute_command, ThreadId::Ui, {
+            Ok(execute_command(self.as_ptr(), command_id.into()))
+        })
+    }
+
+    /// Clears Edit history.
+    pub fn clear_edit_history(&self) -> Result<()> {
+        try_c!(self, clear_edit_history, ThreadId::Ui, {
+            Ok(clear_edit_history(self.as_ptr()))
+        })
+    }
+
+    /// Sets the placeholder text that will be displayed when the Textfield is
+    /// NULL.
+    pub fn set_placeholder_text(&self, text: &str) -> Result<()> {
+        try_c!(self, set_placeholder_text, ThreadId::Ui, {
+            let text = CefString::new(text);
+
+            Ok(set_placeholder_text(self.as_ptr(), text.as_ptr()))
+        })
+    }
+
+    /// Returns the placeholder text that will be displayed when the Textfield is
+    /// NULL.
+    pub fn get_placeholder_text(&self) -> Result<String> {
+        try_c!(self, get_placeholder_text, ThreadId::Ui, {
+            let s = get_placeholder_text(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Sets the placeholder text color.
+    pub fn set_placeholder_text_color(&self, color: &Color) -> Result<()> {
+        try_c!(self, set_placeholder_text_color, ThreadId::Ui, {
+            Ok(set_placeholder_text_color(self.as_ptr(), color.into()))
+        })
+    }
+
+    /// Set the accessible name that will be exposed to assistive technology
+    /// (AT).
+    pub fn set_accessible_name(&self, name: &str) -> Result<()> {
+        try_c!(self, set_accessible_name, ThreadId::Ui, {
+            let name = CefString::new(name);
+
+            Ok(set_accessible_name(self.as_ptr(), name.as_ptr()))
+        })
+    }
+}
+
+impl From<Textfield> for View {
+    fn from(value: Textfield) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
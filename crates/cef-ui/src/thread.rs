@@ -0,0 +1,222 @@
+use crate::{ref_counted_ptr, try_c, CefString, TaskRunner};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_com_init_mode_t, cef_message_loop_type_t, cef_thread_create, cef_thread_priority_t,
+    cef_thread_t
+};
+use std::ffi::c_int;
+
+/// Thread priority values listed in increasing order of importance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ThreadPriority {
+    /// Suitable for threads that shouldn't disrupt high priority work.
+    Background,
+
+    /// Default priority level.
+    Normal,
+
+    /// Suitable for threads which generate data for the display (at ~60Hz).
+    Display,
+
+    /// Suitable for low-latency, glitch-resistant audio.
+    RealtimeAudio
+}
+
+impl From<cef_thread_priority_t> for ThreadPriority {
+    fn from(value: cef_thread_priority_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_thread_priority_t> for ThreadPriority {
+    fn from(value: &cef_thread_priority_t) -> Self {
+        match value {
+            cef_thread_priority_t::TP_BACKGROUND => ThreadPriority::Background,
+            cef_thread_priority_t::TP_NORMAL => ThreadPriority::Normal,
+            cef_thread_priority_t::TP_DISPLAY => ThreadPriority::Display,
+            cef_thread_priority_t::TP_REALTIME_AUDIO => ThreadPriority::RealtimeAudio
+        }
+    }
+}
+
+impl From<ThreadPriority> for cef_thread_priority_t {
+    fn from(value: ThreadPriority) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&ThreadPriority> for cef_thread_priority_t {
+    fn from(value: &ThreadPriority) -> Self {
+        match value {
+            ThreadPriority::Background => cef_thread_priority_t::TP_BACKGROUND,
+            ThreadPriority::Normal => cef_thread_priority_t::TP_NORMAL,
+            ThreadPriority::Display => cef_thread_priority_t::TP_DISPLAY,
+            ThreadPriority::RealtimeAudio => cef_thread_priority_t::TP_REALTIME_AUDIO
+        }
+    }
+}
+
+/// Message loop types. Indicates the set of asynchronous events that a message
+/// loop can process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MessageLoopType {
+    /// Supports tasks and timers.
+    Default,
+
+    /// Supports tasks, timers and native UI events (e.g. Windows messages).
+    Ui,
+
+    /// Supports tasks, timers and asynchronous IO events.
+    Io
+}
+
+impl From<cef_message_loop_type_t> for MessageLoopType {
+    fn from(value: cef_message_loop_type_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_message_loop_type_t> for MessageLoopType {
+    fn from(value: &cef_message_loop_type_t) -> Self {
+        match value {
+            cef_message_loop_type_t::ML_TYPE_DEFAULT => MessageLoopType::Default,
+            cef_message_loop_type_t::ML_TYPE_UI => MessageLoopType::Ui,
+            cef_message_loop_type_t::ML_TYPE_IO => MessageLoopType::Io
+        }
+    }
+}
+
+impl From<MessageLoopType> for cef_message_loop_type_t {
+    fn from(value: MessageLoopType) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&MessageLoopType> for cef_message_loop_type_t {
+    fn from(value: &MessageLoopType) -> Self {
+        match value {
+            MessageLoopType::Default => cef_message_loop_type_t::ML_TYPE_DEFAULT,
+            MessageLoopType::Ui => cef_message_loop_type_t::ML_TYPE_UI,
+            MessageLoopType::Io => cef_message_loop_type_t::ML_TYPE_IO
+        }
+    }
+}
+
+/// Windows COM initialization mode. Specifies how COM will be initialized for a
+/// new thread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComInitMode {
+    /// No COM initialization.
+    None,
+
+    /// Initialize COM using single-threaded apartments.
+    Sta,
+
+    /// Initialize COM using multi-threaded apartments.
+    Mta
+}
+
+impl From<cef_com_init_mode_t> for ComInitMode {
+    fn from(value: cef_com_init_mode_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_com_init_mode_t> for ComInitMode {
+    fn from(value: &cef_com_init_mode_t) -> Self {
+        match value {
+            cef_com_init_mode_t::COM_INIT_MODE_NONE => ComInitMode::None,
+            cef_com_init_mode_t::COM_INIT_MODE_STA => ComInitMode::Sta,
+            cef_com_init_mode_t::COM_INIT_MODE_MTA => ComInitMode::Mta
+        }
+    }
+}
+
+impl From<ComInitMode> for cef_com_init_mode_t {
+    fn from(value: ComInitMode) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&ComInitMode> for cef_com_init_mode_t {
+    fn from(value: &ComInitMode) -> Self {
+        match value {
+            ComInitMode::None => cef_com_init_mode_t::COM_INIT_MODE_NONE,
+            ComInitMode::Sta => cef_com_init_mode_t::COM_INIT_MODE_STA,
+            ComInitMode::Mta => cef_com_init_mode_t::COM_INIT_MODE_MTA
+        }
+    }
+}
+
+// A simple thread abstraction that establishes a message loop on a new thread.
+// The consumer uses cef_task_runner_t to execute code on the thread's message
+// loop. The thread is terminated when the cef_thread_t object is destroyed or
+// stop() is called. All pending tasks queued on the thread's message loop will
+// run to completion before the thread is terminated. cef_thread_create() can be
+// called on any valid CEF thread in either the browser or render process. This
+// structure should only be used for tasks that require a dedicated thread. In
+// most cases you can post tasks to an existing CEF thread instead of creating a
+// new one; see cef_task.h for details.
+ref_counted_ptr!(Thread, cef_thread_t);
+
+impl Thread {
+    /// Create and start a new thread. This function does not block waiting for
+    /// the thread to run initialization. |display_name| is the name that will be
+    /// used to identify the thread. |priority| is the thread execution priority.
+    /// |message_loop_type| indicates the set of asynchronous events that the
+    /// thread can process. If |stoppable| is true (1) the thread will stopped and
+    /// joined on destruction or when stop() is called; otherwise, the thread
+    /// cannot be stopped and will be leaked on shutdown. On Windows the
+    /// |com_init_mode| value specifies how COM will be initialized for the
+    /// thread. If |com_init_mode| is set to COM_INIT_MODE_STA then
+    /// |message_loop_type| must be set to ML_TYPE_UI.
+    pub fn new(
+        display_name: &str,
+        priority: ThreadPriority,
+        message_loop_type: MessageLoopType,
+        stoppable: bool,
+        com_init_mode: ComInitMode
+    ) -> Option<Thread> {
+        unsafe {
+            let display_name = CefString::new(display_name);
+
+            Thread::from_ptr(cef_thread_create(
+                display_name.as_ptr(),
+                priority.into(),
+                message_loop_type.into(),
+                stoppable as c_int,
+                com_init_mode.into()
+            ))
+        }
+    }
+
+    /// Returns the cef_task_runner_t that will execute code on this thread's
+    /// message loop. This function is safe to call from any thread.
+    pub fn get_task_runner(&self) -> Result<Option<TaskRunner>> {
+        try_c!(self, get_task_runner, {
+            Ok(TaskRunner::from_ptr(get_task_runner(self.as_ptr())))
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Returns the platform thread ID. It will return the same value after stop()
+    // /// is called. This function is safe to call from any thread.
+    // ///
+    // cef_platform_thread_id_t(CEF_CALLBACK* get_platform_thread_id)(
+    // struct _cef_thread_t* self);
+
+    /// Stop and join the thread. This function must be called from the same
+    /// thread that called cef_thread_create(). Do not call this function if
+    /// cef_thread_create() was called with a |stoppable| value of false (0).
+    pub fn stop(&self) -> Result<()> {
+        try_c!(self, stop, { Ok(stop(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if the thread is currently running. This function must be
+    /// called from the same thread that called cef_thread_create().
+    pub fn is_running(&self) -> Result<bool> {
+        try_c!(self, is_running, { Ok(is_running(self.as_ptr()) != 0) })
+    }
+}
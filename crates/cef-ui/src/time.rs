@@ -1,5 +1,5 @@
-use cef_ui_sys::{cef_basetime_t, cef_time_from_basetime, cef_time_t};
-use chrono::{DateTime, NaiveDateTime, Utc};
+use cef_ui_sys::{cef_basetime_t, cef_time_from_basetime, cef_time_t, cef_time_to_basetime};
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc};
 use std::mem::zeroed;
 
 /// A wrapper for DateTime<Utc> for interacting with CEF.
@@ -17,6 +17,61 @@ impl From<&CefTime> for DateTime<Utc> {
     }
 }
 
+impl From<DateTime<Utc>> for CefTime {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CefTime> for cef_time_t {
+    fn from(value: CefTime) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&CefTime> for cef_time_t {
+    fn from(value: &CefTime) -> Self {
+        Self {
+            year:         value.0.year(),
+            month:        value.0.month() as i32,
+            day_of_week:  value
+                .0
+                .weekday()
+                .num_days_from_sunday() as i32,
+            day_of_month: value.0.day() as i32,
+            hour:         value.0.hour() as i32,
+            minute:       value.0.minute() as i32,
+            second:       value.0.second() as i32,
+            millisecond:  value
+                .0
+                .timestamp_subsec_millis()
+                .min(999) as i32
+        }
+    }
+}
+
+impl TryFrom<CefTime> for cef_basetime_t {
+    type Error = &'static str;
+
+    fn try_from(value: CefTime) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&CefTime> for cef_basetime_t {
+    type Error = &'static str;
+
+    fn try_from(value: &CefTime) -> Result<Self, Self::Error> {
+        let time: cef_time_t = value.into();
+        let mut base_time: cef_basetime_t = unsafe { zeroed() };
+
+        match unsafe { cef_time_to_basetime(&time, &mut base_time) } {
+            0 => Err("Invalid time!"),
+            _ => Ok(base_time)
+        }
+    }
+}
+
 impl TryFrom<cef_basetime_t> for CefTime {
     type Error = &'static str;
 
@@ -0,0 +1,417 @@
+use crate::{
+    currently_on, ref_counted_ptr, CefString, CompletionCallback, FfiError, RefCountedPtr,
+    ThreadId, Wrappable, Wrapped
+};
+use anyhow::{anyhow, Result};
+use cef_ui_sys::{
+    cef_begin_tracing, cef_end_tracing, cef_end_tracing_callback_t, cef_now_from_system_trace_time,
+    cef_string_t, cef_trace_counter, cef_trace_counter_id, cef_trace_event_async_begin,
+    cef_trace_event_async_end, cef_trace_event_async_step_into, cef_trace_event_async_step_past,
+    cef_trace_event_begin, cef_trace_event_end, cef_trace_event_instant
+};
+use parking_lot::Mutex;
+use std::{
+    ffi::{c_char, CStr},
+    mem::zeroed,
+    path::{Path, PathBuf},
+    ptr::{null, null_mut}
+};
+
+/// An optional trace event argument. The name must have application lifetime.
+pub type TraceArg = Option<(&'static CStr, u64)>;
+
+// Implement this structure to receive notification when tracing has completed.
+// The functions of this structure will be called on the browser process UI
+// thread.
+ref_counted_ptr!(EndTracingCallback, cef_end_tracing_callback_t);
+
+impl EndTracingCallback {
+    pub fn new(f: impl FnOnce(PathBuf) + Send + 'static) -> Self {
+        Self(EndTracingCallbackWrapper::new(f).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct EndTracingCallbackWrapper(Mutex<Option<Box<dyn FnOnce(PathBuf) + Send + 'static>>>);
+
+impl EndTracingCallbackWrapper {
+    pub fn new(f: impl FnOnce(PathBuf) + Send + 'static) -> Self {
+        Self(Mutex::new(Some(Box::new(f))))
+    }
+
+    /// Called after all processes have sent their trace data. |tracing_file| is
+    /// the path at which tracing data was written. The client is responsible for
+    /// deleting |tracing_file|.
+    unsafe extern "C" fn c_on_end_tracing_complete(
+        this: *mut cef_end_tracing_callback_t,
+        tracing_file: *const cef_string_t
+    ) {
+        let this: &Self = Wrapped::wrappable(this);
+        let tracing_file: String = CefString::from_ptr_unchecked(tracing_file).into();
+
+        if let Some(f) = this.0.lock().take() {
+            f(PathBuf::from(tracing_file));
+        }
+    }
+}
+
+impl Wrappable for EndTracingCallbackWrapper {
+    type Cef = cef_end_tracing_callback_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<Self::Cef> {
+        RefCountedPtr::wrap(
+            cef_end_tracing_callback_t {
+                base:                    unsafe { zeroed() },
+                on_end_tracing_complete: Some(Self::c_on_end_tracing_complete)
+            },
+            self
+        )
+    }
+}
+
+/// Start tracing events on all processes. Tracing is initialized asynchronously
+/// and |callback| will be executed on the UI thread after initialization is
+/// complete.
+///
+/// If begin_tracing was called previously, or if a end_tracing call is
+/// pending, begin_tracing will fail and return false (0).
+///
+/// |categories| is a comma-delimited list of category wildcards. A category can
+/// have an optional '-' prefix to make it an excluded category. Having both
+/// included and excluded categories in the same list is not supported.
+///
+/// Examples:
+/// - "test_MyTest*"
+/// - "test_MyTest*,test_OtherStuff"
+/// - "-excluded_category1,-excluded_category2"
+///
+/// This function must be called on the browser process UI thread.
+pub fn begin_tracing(categories: &str, callback: Option<CompletionCallback>) -> Result<bool> {
+    if cfg!(debug_assertions) && !currently_on(ThreadId::Ui) {
+        return Err(anyhow!(FfiError::WrongThread(
+            "begin_tracing",
+            ThreadId::Ui
+        )));
+    }
+
+    let categories = CefString::new(categories);
+
+    unsafe {
+        let callback = callback
+            .map(|callback| callback.into_raw())
+            .unwrap_or_else(null_mut);
+
+        Ok(cef_begin_tracing(categories.as_ptr(), callback) != 0)
+    }
+}
+
+/// Stop tracing events on all processes.
+///
+/// This function will fail and return false (0) if a previous call to
+/// end_tracing is already pending or if begin_tracing was not called.
+///
+/// |tracing_file| is the path at which tracing data will be written and
+/// |callback| is the callback that will be executed once all processes have
+/// sent their trace data. If |tracing_file| is None a new temporary file path
+/// will be used. If |callback| is None no trace data will be written.
+///
+/// This function must be called on the browser process UI thread.
+pub fn end_tracing(
+    tracing_file: Option<&Path>,
+    callback: Option<EndTracingCallback>
+) -> Result<bool> {
+    if cfg!(debug_assertions) && !currently_on(ThreadId::Ui) {
+        return Err(anyhow!(FfiError::WrongThread("end_tracing", ThreadId::Ui)));
+    }
+
+    let tracing_file = tracing_file.map(|path| CefString::new(&path.to_string_lossy()));
+    let tracing_file = tracing_file
+        .as_ref()
+        .map(|path| path.as_ptr())
+        .unwrap_or_else(null);
+
+    unsafe {
+        let callback = callback
+            .map(|callback| callback.into_raw())
+            .unwrap_or_else(null_mut);
+
+        Ok(cef_end_tracing(tracing_file, callback) != 0)
+    }
+}
+
+/// Returns the current system trace time or, if none is defined, the current
+/// high-res time. Can be used by clients to synchronize with the time
+/// information in trace events.
+pub fn now_from_system_trace_time() -> i64 {
+    unsafe { cef_now_from_system_trace_time() }
+}
+
+/// Splits an optional argument into the name pointer and value passed to CEF.
+fn split_arg(arg: TraceArg) -> (*const c_char, u64) {
+    match arg {
+        Some((name, value)) => (name.as_ptr(), value),
+        None => (null(), 0)
+    }
+}
+
+/// Records a single event called |name| immediately, with up to two arguments.
+/// The category, name and argument names must have application lifetime.
+pub fn trace_event_instant(
+    category: &'static CStr,
+    name: &'static CStr,
+    arg1: TraceArg,
+    arg2: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+    let (arg2_name, arg2_val) = split_arg(arg2);
+
+    unsafe {
+        cef_trace_event_instant(
+            category.as_ptr(),
+            name.as_ptr(),
+            arg1_name,
+            arg1_val,
+            arg2_name,
+            arg2_val
+        );
+    }
+}
+
+/// Records the beginning of a block of work called |name|. Each call must be
+/// matched by a call to trace_event_end with the same category and name on the
+/// same thread. The category, name and argument names must have application
+/// lifetime.
+pub fn trace_event_begin(
+    category: &'static CStr,
+    name: &'static CStr,
+    arg1: TraceArg,
+    arg2: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+    let (arg2_name, arg2_val) = split_arg(arg2);
+
+    unsafe {
+        cef_trace_event_begin(
+            category.as_ptr(),
+            name.as_ptr(),
+            arg1_name,
+            arg1_val,
+            arg2_name,
+            arg2_val
+        );
+    }
+}
+
+/// Records the end of a block of work started with trace_event_begin.
+pub fn trace_event_end(
+    category: &'static CStr,
+    name: &'static CStr,
+    arg1: TraceArg,
+    arg2: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+    let (arg2_name, arg2_val) = split_arg(arg2);
+
+    unsafe {
+        cef_trace_event_end(
+            category.as_ptr(),
+            name.as_ptr(),
+            arg1_name,
+            arg1_val,
+            arg2_name,
+            arg2_val
+        );
+    }
+}
+
+/// Records the value of a counter called |name| immediately. The value is
+/// displayed under the counter's name.
+pub fn trace_counter(category: &'static CStr, name: &'static CStr, value: u64) {
+    unsafe {
+        cef_trace_counter(category.as_ptr(), name.as_ptr(), null(), value, null(), 0);
+    }
+}
+
+/// Records the values of a multi-part counter called |name| immediately. The
+/// values are displayed as a stacked-bar chart.
+pub fn trace_counter2(
+    category: &'static CStr,
+    name: &'static CStr,
+    value1: (&'static CStr, u64),
+    value2: (&'static CStr, u64)
+) {
+    unsafe {
+        cef_trace_counter(
+            category.as_ptr(),
+            name.as_ptr(),
+            value1.0.as_ptr(),
+            value1.1,
+            value2.0.as_ptr(),
+            value2.1
+        );
+    }
+}
+
+/// Like trace_counter, but |id| is combined with |name| to distinguish
+/// counters that share a name, e.g. one per object instance.
+pub fn trace_counter_id(category: &'static CStr, name: &'static CStr, id: u64, value: u64) {
+    unsafe {
+        cef_trace_counter_id(
+            category.as_ptr(),
+            name.as_ptr(),
+            id,
+            null(),
+            value,
+            null(),
+            0
+        );
+    }
+}
+
+/// Like trace_counter2, but |id| is combined with |name| to distinguish
+/// counters that share a name, e.g. one per object instance.
+pub fn trace_counter_id2(
+    category: &'static CStr,
+    name: &'static CStr,
+    id: u64,
+    value1: (&'static CStr, u64),
+    value2: (&'static CStr, u64)
+) {
+    unsafe {
+        cef_trace_counter_id(
+            category.as_ptr(),
+            name.as_ptr(),
+            id,
+            value1.0.as_ptr(),
+            value1.1,
+            value2.0.as_ptr(),
+            value2.1
+        );
+    }
+}
+
+/// Records the beginning of an asynchronous operation called |name|. The
+/// operation may end on a different thread. |id| is used to match the begin,
+/// step and end events of the operation.
+pub fn trace_event_async_begin(
+    category: &'static CStr,
+    name: &'static CStr,
+    id: u64,
+    arg1: TraceArg,
+    arg2: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+    let (arg2_name, arg2_val) = split_arg(arg2);
+
+    unsafe {
+        cef_trace_event_async_begin(
+            category.as_ptr(),
+            name.as_ptr(),
+            id,
+            arg1_name,
+            arg1_val,
+            arg2_name,
+            arg2_val
+        );
+    }
+}
+
+/// Records the start of |step| of an asynchronous operation. The step lasts
+/// until the next step or the end of the operation.
+pub fn trace_event_async_step_into(
+    category: &'static CStr,
+    name: &'static CStr,
+    id: u64,
+    step: u64,
+    arg1: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+
+    unsafe {
+        cef_trace_event_async_step_into(
+            category.as_ptr(),
+            name.as_ptr(),
+            id,
+            step,
+            arg1_name,
+            arg1_val
+        );
+    }
+}
+
+/// Records the end of |step| of an asynchronous operation. The step lasts
+/// from the previous step or the beginning of the operation.
+pub fn trace_event_async_step_past(
+    category: &'static CStr,
+    name: &'static CStr,
+    id: u64,
+    step: u64,
+    arg1: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+
+    unsafe {
+        cef_trace_event_async_step_past(
+            category.as_ptr(),
+            name.as_ptr(),
+            id,
+            step,
+            arg1_name,
+            arg1_val
+        );
+    }
+}
+
+/// Records the end of an asynchronous operation started with
+/// trace_event_async_begin.
+pub fn trace_event_async_end(
+    category: &'static CStr,
+    name: &'static CStr,
+    id: u64,
+    arg1: TraceArg,
+    arg2: TraceArg
+) {
+    let (arg1_name, arg1_val) = split_arg(arg1);
+    let (arg2_name, arg2_val) = split_arg(arg2);
+
+    unsafe {
+        cef_trace_event_async_end(
+            category.as_ptr(),
+            name.as_ptr(),
+            id,
+            arg1_name,
+            arg1_val,
+            arg2_name,
+            arg2_val
+        );
+    }
+}
+
+/// Records a block of work that lasts until this object is dropped. This is
+/// the equivalent of the TRACE_EVENT macros.
+pub struct TraceEvent {
+    category: &'static CStr,
+    name:     &'static CStr
+}
+
+impl TraceEvent {
+    /// Calls trace_event_begin and returns an object that calls trace_event_end
+    /// when dropped.
+    pub fn new(
+        category: &'static CStr,
+        name: &'static CStr,
+        arg1: TraceArg,
+        arg2: TraceArg
+    ) -> Self {
+        trace_event_begin(category, name, arg1, arg2);
+
+        Self { category, name }
+    }
+}
+
+impl Drop for TraceEvent {
+    fn drop(&mut self) {
+        trace_event_end(self.category, self.name, None, None);
+    }
+}
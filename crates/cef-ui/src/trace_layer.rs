@@ -0,0 +1,71 @@
+use crate::{trace_event_begin, trace_event_end, trace_event_instant};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    ffi::{CStr, CString}
+};
+use tracing::{span::Id, Event, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Names passed to Chromium must have application lifetime, so every name
+/// that is forwarded is converted once and kept for the life of the process.
+/// Names come from callsite metadata, so the number of entries is bounded.
+static NAMES: Mutex<Option<HashMap<&'static str, &'static CStr>>> = Mutex::new(None);
+
+/// Returns the nul-terminated copy of |name|.
+fn intern(name: &'static str) -> &'static CStr {
+    let mut names = NAMES.lock();
+
+    names
+        .get_or_insert_with(HashMap::new)
+        .entry(name)
+        .or_insert_with(|| {
+            let name = CString::new(name.replace('\0', "")).unwrap_or_default();
+
+            Box::leak(name.into_boxed_c_str())
+        })
+}
+
+/// A tracing layer that forwards spans and events to Chromium trace events, so
+/// they show up in the same trace as Chromium's own events. Entering and
+/// exiting a span records the beginning and end of a block of work and events
+/// are recorded as instant events. Everything is recorded under a single
+/// category that must be enabled when calling begin_tracing.
+#[derive(Clone, Debug)]
+pub struct ChromiumTraceLayer {
+    category: &'static CStr
+}
+
+impl Default for ChromiumTraceLayer {
+    fn default() -> Self {
+        Self::new(c"rust")
+    }
+}
+
+impl ChromiumTraceLayer {
+    /// Creates a layer that records everything under |category|.
+    pub fn new(category: &'static CStr) -> Self {
+        Self { category }
+    }
+}
+
+impl<S> Layer<S> for ChromiumTraceLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>
+{
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(metadata) = ctx.metadata(id) {
+            trace_event_begin(self.category, intern(metadata.name()), None, None);
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(metadata) = ctx.metadata(id) {
+            trace_event_end(self.category, intern(metadata.name()), None, None);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        trace_event_instant(self.category, intern(event.metadata().name()), None, None);
+    }
+}
@@ -1,9 +1,11 @@
 use bitflags::bitflags;
 use cef_ui_sys::{
-    cef_errorcode_t, cef_horizontal_alignment_t, cef_insets_t, cef_log_items_t, cef_log_severity_t,
-    cef_paint_element_type_t, cef_point_t, cef_range_t, cef_rect_t, cef_referrer_policy_t,
-    cef_resource_type_t, cef_screen_info_t, cef_size_t, cef_state_t, cef_termination_status_t,
-    cef_text_input_mode_t, cef_touch_handle_state_flags_t,
+    cef_button_state_t, cef_chrome_toolbar_type_t, cef_errorcode_t, cef_gesture_command_t,
+    cef_horizontal_alignment_t, cef_insets_t, cef_log_items_t, cef_log_severity_t,
+    cef_menu_anchor_position_t, cef_paint_element_type_t, cef_point_t, cef_range_t, cef_rect_t,
+    cef_referrer_policy_t, cef_resource_type_t, cef_screen_info_t, cef_show_state_t, cef_size_t,
+    cef_state_t, cef_termination_status_t, cef_text_field_commands_t, cef_text_input_mode_t,
+    cef_text_style_t, cef_touch_handle_state_flags_t,
     cef_touch_handle_state_flags_t_CEF_THS_FLAG_ALPHA,
     cef_touch_handle_state_flags_t_CEF_THS_FLAG_ENABLED,
     cef_touch_handle_state_flags_t_CEF_THS_FLAG_NONE,
@@ -3113,3 +3115,330 @@ impl From<&TouchHandleState> for cef_touch_handle_state_t {
         }
     }
 }
+
+/// Show states supported by CefWindowDelegate::GetInitialShowState.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShowState {
+    /// Show the window as normal.
+    Normal,
+
+    /// Show the window as minimized.
+    Minimized,
+
+    /// Show the window as maximized.
+    Maximized,
+
+    /// Show the window as fullscreen.
+    Fullscreen,
+
+    /// Show the window as hidden (no dock thumbnail). Only supported on MacOS.
+    Hidden
+}
+
+impl From<cef_show_state_t> for ShowState {
+    fn from(value: cef_show_state_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_show_state_t> for ShowState {
+    fn from(value: &cef_show_state_t) -> Self {
+        match value {
+            cef_show_state_t::CEF_SHOW_STATE_NORMAL => ShowState::Normal,
+            cef_show_state_t::CEF_SHOW_STATE_MINIMIZED => ShowState::Minimized,
+            cef_show_state_t::CEF_SHOW_STATE_MAXIMIZED => ShowState::Maximized,
+            cef_show_state_t::CEF_SHOW_STATE_FULLSCREEN => ShowState::Fullscreen,
+            cef_show_state_t::CEF_SHOW_STATE_HIDDEN => ShowState::Hidden
+        }
+    }
+}
+
+impl From<ShowState> for cef_show_state_t {
+    fn from(value: ShowState) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&ShowState> for cef_show_state_t {
+    fn from(value: &ShowState) -> Self {
+        match value {
+            ShowState::Normal => cef_show_state_t::CEF_SHOW_STATE_NORMAL,
+            ShowState::Minimized => cef_show_state_t::CEF_SHOW_STATE_MINIMIZED,
+            ShowState::Maximized => cef_show_state_t::CEF_SHOW_STATE_MAXIMIZED,
+            ShowState::Fullscreen => cef_show_state_t::CEF_SHOW_STATE_FULLSCREEN,
+            ShowState::Hidden => cef_show_state_t::CEF_SHOW_STATE_HIDDEN
+        }
+    }
+}
+
+/// Specifies where along the menu anchor the menu is positioned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MenuAnchorPosition {
+    /// Anchor the menu at the top left.
+    TopLeft,
+
+    /// Anchor the menu at the top right.
+    TopRight,
+
+    /// Anchor the menu at the bottom center.
+    BottomCenter
+}
+
+impl From<cef_menu_anchor_position_t> for MenuAnchorPosition {
+    fn from(value: cef_menu_anchor_position_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_menu_anchor_position_t> for MenuAnchorPosition {
+    fn from(value: &cef_menu_anchor_position_t) -> Self {
+        match value {
+            cef_menu_anchor_position_t::CEF_MENU_ANCHOR_TOPLEFT => MenuAnchorPosition::TopLeft,
+            cef_menu_anchor_position_t::CEF_MENU_ANCHOR_TOPRIGHT => MenuAnchorPosition::TopRight,
+            cef_menu_anchor_position_t::CEF_MENU_ANCHOR_BOTTOMCENTER => {
+                MenuAnchorPosition::BottomCenter
+            },
+        }
+    }
+}
+
+impl From<MenuAnchorPosition> for cef_menu_anchor_position_t {
+    fn from(value: MenuAnchorPosition) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&MenuAnchorPosition> for cef_menu_anchor_position_t {
+    fn from(value: &MenuAnchorPosition) -> Self {
+        match value {
+            MenuAnchorPosition::TopLeft => cef_menu_anchor_position_t::CEF_MENU_ANCHOR_TOPLEFT,
+            MenuAnchorPosition::TopRight => cef_menu_anchor_position_t::CEF_MENU_ANCHOR_TOPRIGHT,
+            MenuAnchorPosition::BottomCenter => {
+                cef_menu_anchor_position_t::CEF_MENU_ANCHOR_BOTTOMCENTER
+            },
+        }
+    }
+}
+
+/// Chrome toolbar types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChromeToolbarType {
+    /// No toolbar.
+    None,
+
+    /// The normal toolbar.
+    Normal,
+
+    /// The location bar only.
+    Location
+}
+
+impl From<cef_chrome_toolbar_type_t> for ChromeToolbarType {
+    fn from(value: cef_chrome_toolbar_type_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_chrome_toolbar_type_t> for ChromeToolbarType {
+    fn from(value: &cef_chrome_toolbar_type_t) -> Self {
+        match value {
+            cef_chrome_toolbar_type_t::CEF_CTT_NONE => ChromeToolbarType::None,
+            cef_chrome_toolbar_type_t::CEF_CTT_NORMAL => ChromeToolbarType::Normal,
+            cef_chrome_toolbar_type_t::CEF_CTT_LOCATION => ChromeToolbarType::Location
+        }
+    }
+}
+
+impl From<ChromeToolbarType> for cef_chrome_toolbar_type_t {
+    fn from(value: ChromeToolbarType) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&ChromeToolbarType> for cef_chrome_toolbar_type_t {
+    fn from(value: &ChromeToolbarType) -> Self {
+        match value {
+            ChromeToolbarType::None => cef_chrome_toolbar_type_t::CEF_CTT_NONE,
+            ChromeToolbarType::Normal => cef_chrome_toolbar_type_t::CEF_CTT_NORMAL,
+            ChromeToolbarType::Location => cef_chrome_toolbar_type_t::CEF_CTT_LOCATION
+        }
+    }
+}
+
+/// Specifies the gesture commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GestureCommand {
+    /// Navigate back.
+    Back,
+
+    /// Navigate forward.
+    Forward
+}
+
+impl From<cef_gesture_command_t> for GestureCommand {
+    fn from(value: cef_gesture_command_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_gesture_command_t> for GestureCommand {
+    fn from(value: &cef_gesture_command_t) -> Self {
+        match value {
+            cef_gesture_command_t::CEF_GESTURE_COMMAND_BACK => GestureCommand::Back,
+            cef_gesture_command_t::CEF_GESTURE_COMMAND_FORWARD => GestureCommand::Forward
+        }
+    }
+}
+
+impl From<GestureCommand> for cef_gesture_command_t {
+    fn from(value: GestureCommand) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&GestureCommand> for cef_gesture_command_t {
+    fn from(value: &GestureCommand) -> Self {
+        match value {
+            GestureCommand::Back => cef_gesture_command_t::CEF_GESTURE_COMMAND_BACK,
+            GestureCommand::Forward => cef_gesture_command_t::CEF_GESTURE_COMMAND_FORWARD
+        }
+    }
+}
+
+/// Specifies the button display state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ButtonState {
+    Normal,
+    Hovered,
+    Pressed,
+    Disabled
+}
+
+impl From<cef_button_state_t> for ButtonState {
+    fn from(value: cef_button_state_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_button_state_t> for ButtonState {
+    fn from(value: &cef_button_state_t) -> Self {
+        match value {
+            cef_button_state_t::CEF_BUTTON_STATE_NORMAL => ButtonState::Normal,
+            cef_button_state_t::CEF_BUTTON_STATE_HOVERED => ButtonState::Hovered,
+            cef_button_state_t::CEF_BUTTON_STATE_PRESSED => ButtonState::Pressed,
+            cef_button_state_t::CEF_BUTTON_STATE_DISABLED => ButtonState::Disabled
+        }
+    }
+}
+
+impl From<ButtonState> for cef_button_state_t {
+    fn from(value: ButtonState) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&ButtonState> for cef_button_state_t {
+    fn from(value: &ButtonState) -> Self {
+        match value {
+            ButtonState::Normal => cef_button_state_t::CEF_BUTTON_STATE_NORMAL,
+            ButtonState::Hovered => cef_button_state_t::CEF_BUTTON_STATE_HOVERED,
+            ButtonState::Pressed => cef_button_state_t::CEF_BUTTON_STATE_PRESSED,
+            ButtonState::Disabled => cef_button_state_t::CEF_BUTTON_STATE_DISABLED
+        }
+    }
+}
+
+/// Text style types. Should be kept in sync with gfx::TextStyle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextStyle {
+    Bold,
+    Italic,
+    Strike,
+    DiagonalStrike,
+    Underline
+}
+
+impl From<cef_text_style_t> for TextStyle {
+    fn from(value: cef_text_style_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_text_style_t> for TextStyle {
+    fn from(value: &cef_text_style_t) -> Self {
+        match value {
+            cef_text_style_t::CEF_TEXT_STYLE_BOLD => TextStyle::Bold,
+            cef_text_style_t::CEF_TEXT_STYLE_ITALIC => TextStyle::Italic,
+            cef_text_style_t::CEF_TEXT_STYLE_STRIKE => TextStyle::Strike,
+            cef_text_style_t::CEF_TEXT_STYLE_DIAGONAL_STRIKE => TextStyle::DiagonalStrike,
+            cef_text_style_t::CEF_TEXT_STYLE_UNDERLINE => TextStyle::Underline
+        }
+    }
+}
+
+impl From<TextStyle> for cef_text_style_t {
+    fn from(value: TextStyle) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&TextStyle> for cef_text_style_t {
+    fn from(value: &TextStyle) -> Self {
+        match value {
+            TextStyle::Bold => cef_text_style_t::CEF_TEXT_STYLE_BOLD,
+            TextStyle::Italic => cef_text_style_t::CEF_TEXT_STYLE_ITALIC,
+            TextStyle::Strike => cef_text_style_t::CEF_TEXT_STYLE_STRIKE,
+            TextStyle::DiagonalStrike => cef_text_style_t::CEF_TEXT_STYLE_DIAGONAL_STRIKE,
+            TextStyle::Underline => cef_text_style_t::CEF_TEXT_STYLE_UNDERLINE
+        }
+    }
+}
+
+/// Represents commands available to TextField.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextFieldCommand {
+    Cut,
+    Copy,
+    Paste,
+    Undo,
+    Delete,
+    SelectAll
+}
+
+impl From<cef_text_field_commands_t> for TextFieldCommand {
+    fn from(value: cef_text_field_commands_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_text_field_commands_t> for TextFieldCommand {
+    fn from(value: &cef_text_field_commands_t) -> Self {
+        match value {
+            cef_text_field_commands_t::CEF_TFC_CUT => TextFieldCommand::Cut,
+            cef_text_field_commands_t::CEF_TFC_COPY => TextFieldCommand::Copy,
+            cef_text_field_commands_t::CEF_TFC_PASTE => TextFieldCommand::Paste,
+            cef_text_field_commands_t::CEF_TFC_UNDO => TextFieldCommand::Undo,
+            cef_text_field_commands_t::CEF_TFC_DELETE => TextFieldCommand::Delete,
+            cef_text_field_commands_t::CEF_TFC_SELECT_ALL => TextFieldCommand::SelectAll
+        }
+    }
+}
+
+impl From<TextFieldCommand> for cef_text_field_commands_t {
+    fn from(value: TextFieldCommand) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&TextFieldCommand> for cef_text_field_commands_t {
+    fn from(value: &TextFieldCommand) -> Self {
+        match value {
+            TextFieldCommand::Cut => cef_text_field_commands_t::CEF_TFC_CUT,
+            TextFieldCommand::Copy => cef_text_field_commands_t::CEF_TFC_COPY,
+            TextFieldCommand::Paste => cef_text_field_commands_t::CEF_TFC_PASTE,
+            TextFieldCommand::Undo => cef_text_field_commands_t::CEF_TFC_UNDO,
+            TextFieldCommand::Delete => cef_text_field_commands_t::CEF_TFC_DELETE,
+            TextFieldCommand::SelectAll => cef_text_field_commands_t::CEF_TFC_SELECT_ALL
+        }
+    }
+}
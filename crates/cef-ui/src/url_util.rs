@@ -0,0 +1,328 @@
+use crate::{free_cef_string, BinaryValue, CefString, CefStringList};
+use bitflags::bitflags;
+use cef_ui_sys::{
+    cef_base64decode, cef_base64encode, cef_create_url, cef_format_url_for_security_display,
+    cef_get_extensions_for_mime_type, cef_get_mime_type, cef_parse_url, cef_resolve_url,
+    cef_uri_unescape_rule_t, cef_uri_unescape_rule_t_UU_NONE, cef_uri_unescape_rule_t_UU_NORMAL,
+    cef_uri_unescape_rule_t_UU_PATH_SEPARATORS, cef_uri_unescape_rule_t_UU_REPLACE_PLUS_WITH_SPACE,
+    cef_uri_unescape_rule_t_UU_SPACES,
+    cef_uri_unescape_rule_t_UU_URL_SPECIAL_CHARS_EXCEPT_PATH_SEPARATORS, cef_uridecode,
+    cef_uriencode, cef_urlparts_t
+};
+use std::{
+    ffi::{c_int, c_void},
+    mem::zeroed
+};
+
+bitflags! {
+    /// URI unescape rules passed to uri_decode.
+    #[allow(non_upper_case_globals)]
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct UriUnescapeRule: cef_uri_unescape_rule_t {
+        /// Don't unescape anything at all.
+        const None = cef_uri_unescape_rule_t_UU_NONE;
+
+        /// Don't unescape anything special, but all normal unescaping will
+        /// happen. This is a placeholder and can't be combined with other flags
+        /// (since it's just the absence of them). All other unescape rules
+        /// imply "normal" in addition to their special meaning. Things like
+        /// escaped letters, digits, and most symbols will get unescaped with
+        /// this mode.
+        const Normal = cef_uri_unescape_rule_t_UU_NORMAL;
+
+        /// Convert %20 to spaces. In some places where we're showing URLs, we
+        /// may want this. In places where the URL may be copied and pasted out,
+        /// then you wouldn't want this since it might not be interpreted in one
+        /// piece by other applications.
+        const Spaces = cef_uri_unescape_rule_t_UU_SPACES;
+
+        /// Unescapes '/' and '\\'. If these characters were unescaped, the
+        /// resulting URL won't be the same as the source one. Moreover, they
+        /// are dangerous for this reason, so they should only be used for
+        /// display purposes.
+        const PathSeparators = cef_uri_unescape_rule_t_UU_PATH_SEPARATORS;
+
+        /// Unescapes various characters that will change the meaning of URLs,
+        /// including '%', '+', '&', '#'. Does not unescape path separators. If
+        /// these characters were unescaped, the resulting URL won't be the same
+        /// as the source one. This flag is used when generating final output
+        /// like filenames for URLs where we won't be interpreting as a URL and
+        /// want to do as much unescaping as possible.
+        const UrlSpecialCharsExceptPathSeparators =
+            cef_uri_unescape_rule_t_UU_URL_SPECIAL_CHARS_EXCEPT_PATH_SEPARATORS;
+
+        /// URL queries use "+" for space. This flag controls that replacement.
+        const ReplacePlusWithSpace = cef_uri_unescape_rule_t_UU_REPLACE_PLUS_WITH_SPACE;
+    }
+}
+
+impl From<cef_uri_unescape_rule_t> for UriUnescapeRule {
+    fn from(value: cef_uri_unescape_rule_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_uri_unescape_rule_t> for UriUnescapeRule {
+    fn from(value: &cef_uri_unescape_rule_t) -> Self {
+        Self::from_bits_truncate(*value)
+    }
+}
+
+impl From<UriUnescapeRule> for cef_uri_unescape_rule_t {
+    fn from(value: UriUnescapeRule) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&UriUnescapeRule> for cef_uri_unescape_rule_t {
+    fn from(value: &UriUnescapeRule) -> Self {
+        value.bits()
+    }
+}
+
+/// URL component parts.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UrlParts {
+    /// The complete URL specification.
+    pub spec: String,
+
+    /// Scheme component not including the colon (e.g., "http").
+    pub scheme: String,
+
+    /// User name component.
+    pub username: String,
+
+    /// Password component.
+    pub password: String,
+
+    /// Host component. This may be a hostname, an IPv4 address or an IPv6
+    /// literal surrounded by square brackets (e.g., "[2001:db8::1]").
+    pub host: String,
+
+    /// Port number component.
+    pub port: String,
+
+    /// Origin contains just the scheme, host, and port from a URL. Equivalent
+    /// to clearing any username and password, replacing the path with a slash,
+    /// and clearing everything after that. This value will be empty for
+    /// non-standard URLs.
+    pub origin: String,
+
+    /// Path component including the first slash following the host.
+    pub path: String,
+
+    /// Query string component (i.e., everything following the '?').
+    pub query: String,
+
+    /// Fragment (hash) identifier component (i.e., the string following the
+    /// '#').
+    pub fragment: String
+}
+
+impl From<cef_urlparts_t> for UrlParts {
+    fn from(value: cef_urlparts_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_urlparts_t> for UrlParts {
+    fn from(value: &cef_urlparts_t) -> Self {
+        Self {
+            spec:     CefString::from_ptr_unchecked(&value.spec).into(),
+            scheme:   CefString::from_ptr_unchecked(&value.scheme).into(),
+            username: CefString::from_ptr_unchecked(&value.username).into(),
+            password: CefString::from_ptr_unchecked(&value.password).into(),
+            host:     CefString::from_ptr_unchecked(&value.host).into(),
+            port:     CefString::from_ptr_unchecked(&value.port).into(),
+            origin:   CefString::from_ptr_unchecked(&value.origin).into(),
+            path:     CefString::from_ptr_unchecked(&value.path).into(),
+            query:    CefString::from_ptr_unchecked(&value.query).into(),
+            fragment: CefString::from_ptr_unchecked(&value.fragment).into()
+        }
+    }
+}
+
+/// Owns a cef_urlparts_t and frees its strings when dropped.
+struct RawUrlParts(cef_urlparts_t);
+
+impl Default for RawUrlParts {
+    fn default() -> Self {
+        Self(unsafe { zeroed() })
+    }
+}
+
+impl From<&UrlParts> for RawUrlParts {
+    fn from(value: &UrlParts) -> Self {
+        Self(cef_urlparts_t {
+            spec:     CefString::new(&value.spec).into_raw(),
+            scheme:   CefString::new(&value.scheme).into_raw(),
+            username: CefString::new(&value.username).into_raw(),
+            password: CefString::new(&value.password).into_raw(),
+            host:     CefString::new(&value.host).into_raw(),
+            port:     CefString::new(&value.port).into_raw(),
+            origin:   CefString::new(&value.origin).into_raw(),
+            path:     CefString::new(&value.path).into_raw(),
+            query:    CefString::new(&value.query).into_raw(),
+            fragment: CefString::new(&value.fragment).into_raw()
+        })
+    }
+}
+
+impl Drop for RawUrlParts {
+    fn drop(&mut self) {
+        free_cef_string(&mut self.0.spec);
+        free_cef_string(&mut self.0.scheme);
+        free_cef_string(&mut self.0.username);
+        free_cef_string(&mut self.0.password);
+        free_cef_string(&mut self.0.host);
+        free_cef_string(&mut self.0.port);
+        free_cef_string(&mut self.0.origin);
+        free_cef_string(&mut self.0.path);
+        free_cef_string(&mut self.0.query);
+        free_cef_string(&mut self.0.fragment);
+    }
+}
+
+/// Combines specified |base_url| and |relative_url| into a resolved URL.
+/// Returns None if one of the URLs is empty or invalid.
+pub fn resolve_url(base_url: &str, relative_url: &str) -> Option<String> {
+    let base_url = CefString::new(base_url);
+    let relative_url = CefString::new(relative_url);
+    let mut resolved_url = CefString::default();
+
+    match unsafe {
+        cef_resolve_url(
+            base_url.as_ptr(),
+            relative_url.as_ptr(),
+            resolved_url.as_mut_ptr()
+        )
+    } {
+        0 => None,
+        _ => Some(resolved_url.into())
+    }
+}
+
+/// Parse the specified |url| into its component parts. Returns None if the URL
+/// is empty or invalid.
+pub fn parse_url(url: &str) -> Option<UrlParts> {
+    let url = CefString::new(url);
+    let mut parts = RawUrlParts::default();
+
+    match unsafe { cef_parse_url(url.as_ptr(), &mut parts.0) } {
+        0 => None,
+        _ => Some(UrlParts::from(&parts.0))
+    }
+}
+
+/// Creates a URL from the specified |parts|, which must contain a non-empty
+/// spec or a non-empty host and path (at a minimum), but not both. Returns None
+/// if |parts| isn't initialized as described.
+pub fn create_url(parts: &UrlParts) -> Option<String> {
+    let parts = RawUrlParts::from(parts);
+    let mut url = CefString::default();
+
+    match unsafe { cef_create_url(&parts.0, url.as_mut_ptr()) } {
+        0 => None,
+        _ => Some(url.into())
+    }
+}
+
+/// This is a convenience function for formatting a URL in a concise and human-
+/// friendly way to help users make security-related decisions (or in other
+/// circumstances when people need to distinguish sites, origins, or otherwise-
+/// simplified URLs from each other). Internationalized domain names (IDN) may
+/// be presented in Unicode if the conversion is considered safe. The returned
+/// value will (a) omit the path for standard schemes, excepting file and
+/// filesystem, and (b) omit the port if it is the default for the scheme. Do
+/// not use this for URLs which will be parsed or sent to other applications.
+pub fn format_url_for_security_display(origin_url: &str) -> String {
+    let origin_url = CefString::new(origin_url);
+
+    unsafe {
+        CefString::from_userfree_ptr(cef_format_url_for_security_display(origin_url.as_ptr()))
+            .map(|s| s.into())
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the mime type for the specified file extension or None if unknown.
+pub fn get_mime_type(extension: &str) -> Option<String> {
+    let extension = CefString::new(extension);
+
+    unsafe { CefString::from_userfree_ptr(cef_get_mime_type(extension.as_ptr())) }
+        .filter(|s| !s.is_empty())
+        .map(|s| s.into())
+}
+
+/// Get the extensions associated with the given mime type. This should be
+/// passed in lower case. There could be multiple extensions for a given mime
+/// type, like "html,htm" for "text/html", or "txt,text,html,..." for "text/*".
+pub fn get_extensions_for_mime_type(mime_type: &str) -> Vec<String> {
+    let mime_type = CefString::new(mime_type);
+    let mut extensions = CefStringList::new();
+
+    unsafe {
+        cef_get_extensions_for_mime_type(mime_type.as_ptr(), extensions.as_mut_ptr());
+    }
+
+    extensions.into()
+}
+
+/// Encodes |data| as a base64 string.
+pub fn base64_encode(data: &[u8]) -> String {
+    unsafe {
+        CefString::from_userfree_ptr(cef_base64encode(data.as_ptr() as *const c_void, data.len()))
+            .map(|s| s.into())
+            .unwrap_or_default()
+    }
+}
+
+/// Decodes the base64 encoded string |data|. The returned value will be None
+/// if the decoding fails.
+pub fn base64_decode(data: &str) -> Option<Vec<u8>> {
+    let data = CefString::new(data);
+    let value = unsafe { BinaryValue::from_ptr(cef_base64decode(data.as_ptr())) }?;
+    let mut buffer = vec![0; value.get_size().ok()?];
+
+    value
+        .get_data(&mut buffer, 0)
+        .ok()?;
+
+    Some(buffer)
+}
+
+/// Escapes characters in |text| which are unsuitable for use as a query
+/// parameter value. Everything except alphanumerics and -_.!~*'() will be
+/// converted to "%XX". If |use_plus| is true spaces will change to "+". The
+/// result is basically the same as encodeURIComponent in Javacript.
+pub fn uri_encode(text: &str, use_plus: bool) -> String {
+    let text = CefString::new(text);
+
+    unsafe {
+        CefString::from_userfree_ptr(cef_uriencode(text.as_ptr(), use_plus as c_int))
+            .map(|s| s.into())
+            .unwrap_or_default()
+    }
+}
+
+/// Unescapes |text| and returns the result. Unescaping consists of looking for
+/// the exact pattern "%XX" where each X is a hex digit and converting to the
+/// character with the numerical value of those digits (e.g. "i%20=%203%3b"
+/// unescapes to "i = 3;"). If |convert_to_utf8| is true this function will
+/// attempt to interpret the initial decoded result as UTF-8. If the result is
+/// convertable into UTF-8 it will be returned as converted. Otherwise the
+/// initial decoded result will be returned. The |unescape_rule| parameter
+/// supports further customization the decoding process.
+pub fn uri_decode(text: &str, convert_to_utf8: bool, unescape_rule: UriUnescapeRule) -> String {
+    let text = CefString::new(text);
+
+    unsafe {
+        CefString::from_userfree_ptr(cef_uridecode(
+            text.as_ptr(),
+            convert_to_utf8 as c_int,
+            unescape_rule.into()
+        ))
+        .map(|s| s.into())
+        .unwrap_or_default()
+    }
+}
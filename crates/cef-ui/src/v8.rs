@@ -0,0 +1,1229 @@
+use crate::{
+    currently_on, ref_counted_ptr, try_c, BinaryValue, Browser, CefString, CefStringList, CefTime,
+    DictionaryValue, Frame, ListValue, RefCountedPtr, TaskRunner, ThreadId, Value, Wrappable,
+    Wrapped
+};
+use anyhow::{anyhow, Result};
+use cef_ui_sys::{
+    cef_basetime_t, cef_register_extension, cef_string_t, cef_v8_propertyattribute_t,
+    cef_v8context_get_current_context, cef_v8context_get_entered_context, cef_v8context_in_context,
+    cef_v8context_t, cef_v8exception_t, cef_v8handler_t, cef_v8stack_frame_t,
+    cef_v8stack_trace_get_current, cef_v8stack_trace_t, cef_v8value_create_array,
+    cef_v8value_create_bool, cef_v8value_create_date, cef_v8value_create_double,
+    cef_v8value_create_function, cef_v8value_create_int, cef_v8value_create_null,
+    cef_v8value_create_object, cef_v8value_create_promise, cef_v8value_create_string,
+    cef_v8value_create_uint, cef_v8value_create_undefined, cef_v8value_t
+};
+use chrono::{DateTime, Utc};
+use std::{
+    ffi::c_int,
+    mem::zeroed,
+    ptr::{null, null_mut},
+    slice::from_raw_parts,
+    sync::atomic::{AtomicBool, Ordering}
+};
+
+/// Whether a V8 context has been created in this process. Extensions must be
+/// registered before this happens.
+pub(crate) static V8_CONTEXT_CREATED: AtomicBool = AtomicBool::new(false);
+
+/// Register a new V8 extension with the specified JavaScript extension code and
+/// handler. Functions implemented by the handler are prototyped using the
+/// keyword 'native'. The calling of a native function is restricted to the
+/// scope in which the prototype of the native function is defined. This
+/// function may only be called on the render process main thread before any V8
+/// context has been created, i.e. from on_web_kit_initialized.
+///
+/// Example JavaScript extension code: <pre>
+///   // create the 'example' global object if it doesn't already exist.
+///   if (!example)
+///     example = {};
+///   // create the 'example.test' global object if it doesn't already exist.
+///   if (!example.test)
+///     example.test = {};
+///   (function() {
+///     // Define the function 'example.test.myfunction'.
+///     example.test.myfunction = function() {
+///       // Call CefV8Handler::Execute() with the function name 'MyFunction'
+///       // and no arguments.
+///       native function MyFunction();
+///       return MyFunction();
+///     };
+///     // Define the getter function for parameter 'example.test.myparam'.
+///     example.test.__defineGetter__('myparam', function() {
+///       // Call CefV8Handler::Execute() with the function name 'GetMyParam'
+///       // and no arguments.
+///       native function GetMyParam();
+///       return GetMyParam();
+///     });
+///     // Define the setter function for parameter 'example.test.myparam'.
+///     example.test.__defineSetter__('myparam', function(b) {
+///       // Call CefV8Handler::Execute() with the function name 'SetMyParam'
+///       // and a single argument.
+///       native function SetMyParam();
+///       if(b) SetMyParam(b);
+///     });
+///
+///     // Extension definitions can also contain normal JavaScript variables
+///     // and functions.
+///     var myint = 0;
+///     example.test.increment = function() {
+///       myint += 1;
+///       return myint;
+///     };
+///   })();
+/// </pre>
+///
+/// Example usage in the page: <pre>
+///   // Call the function.
+///   example.test.myfunction();
+///   // Set the parameter.
+///   example.test.myparam = value;
+///   // Get the parameter.
+///   value = example.test.myparam;
+///   // Call another function.
+///   example.test.increment();
+/// </pre>
+pub fn register_v8_extension(name: &str, javascript_code: &str, handler: V8Handler) -> Result<()> {
+    if !currently_on(ThreadId::Renderer) {
+        return Err(anyhow!(
+            "V8 extensions must be registered on the render process main thread!"
+        ));
+    }
+
+    if V8_CONTEXT_CREATED.load(Ordering::SeqCst) {
+        return Err(anyhow!(
+            "V8 extensions must be registered before any V8 context is created!"
+        ));
+    }
+
+    let name = CefString::new(name);
+    let javascript_code = CefString::new(javascript_code);
+
+    match unsafe {
+        cef_register_extension(name.as_ptr(), javascript_code.as_ptr(), handler.into_raw())
+    } {
+        0 => Err(anyhow!("Failed to register V8 extension.")),
+        _ => Ok(())
+    }
+}
+
+/// V8 property attribute values.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum V8PropertyAttribute {
+    /// Writeable, Enumerable, Configurable
+    None,
+
+    /// Not writeable
+    ReadOnly,
+
+    /// Not enumerable
+    DontEnum,
+
+    /// Not configurable
+    DontDelete
+}
+
+impl From<cef_v8_propertyattribute_t> for V8PropertyAttribute {
+    fn from(value: cef_v8_propertyattribute_t) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&cef_v8_propertyattribute_t> for V8PropertyAttribute {
+    fn from(value: &cef_v8_propertyattribute_t) -> Self {
+        match value {
+            cef_v8_propertyattribute_t::V8_PROPERTY_ATTRIBUTE_NONE => Self::None,
+            cef_v8_propertyattribute_t::V8_PROPERTY_ATTRIBUTE_READONLY => Self::ReadOnly,
+            cef_v8_propertyattribute_t::V8_PROPERTY_ATTRIBUTE_DONTENUM => Self::DontEnum,
+            cef_v8_propertyattribute_t::V8_PROPERTY_ATTRIBUTE_DONTDELETE => Self::DontDelete
+        }
+    }
+}
+
+impl From<V8PropertyAttribute> for cef_v8_propertyattribute_t {
+    fn from(value: V8PropertyAttribute) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&V8PropertyAttribute> for cef_v8_propertyattribute_t {
+    fn from(value: &V8PropertyAttribute) -> Self {
+        match value {
+            V8PropertyAttribute::None => Self::V8_PROPERTY_ATTRIBUTE_NONE,
+            V8PropertyAttribute::ReadOnly => Self::V8_PROPERTY_ATTRIBUTE_READONLY,
+            V8PropertyAttribute::DontEnum => Self::V8_PROPERTY_ATTRIBUTE_DONTENUM,
+            V8PropertyAttribute::DontDelete => Self::V8_PROPERTY_ATTRIBUTE_DONTDELETE
+        }
+    }
+}
+
+// Structure representing a V8 context handle. V8 handles can only be accessed
+// from the thread on which they are created. Valid threads for creating a V8
+// handle include the render process main thread (TID_RENDERER) and WebWorker
+// threads. A task runner for posting tasks on the associated thread can be
+// retrieved via the cef_v8context_t::get_task_runner() function.
+ref_counted_ptr!(V8Context, cef_v8context_t);
+
+impl V8Context {
+    /// Returns the current (top) context object in the V8 context stack.
+    pub fn get_current_context() -> Option<V8Context> {
+        unsafe { V8Context::from_ptr(cef_v8context_get_current_context()) }
+    }
+
+    /// Returns the entered (bottom) context object in the V8 context stack.
+    pub fn get_entered_context() -> Option<V8Context> {
+        unsafe { V8Context::from_ptr(cef_v8context_get_entered_context()) }
+    }
+
+    /// Returns true (1) if V8 is currently inside a context.
+    pub fn in_context() -> bool {
+        unsafe { cef_v8context_in_context() != 0 }
+    }
+
+    /// Returns the task runner associated with this context. V8 handles can only
+    /// be accessed from the thread on which they are created. This function can
+    /// be called on any render process thread.
+    pub fn get_task_runner(&self) -> Result<Option<TaskRunner>> {
+        try_c!(self, get_task_runner, {
+            Ok(TaskRunner::from_ptr(get_task_runner(self.as_ptr())))
+        })
+    }
+
+    /// Returns true (1) if the underlying handle is valid and it can be accessed
+    /// on the current thread. Do not call any other functions if this function
+    /// returns false (0).
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, { Ok(is_valid(self.as_ptr()) != 0) })
+    }
+
+    /// Returns the browser for this context. This function will return an NULL
+    /// reference for WebWorker contexts.
+    pub fn get_browser(&self) -> Result<Option<Browser>> {
+        try_c!(self, get_browser, {
+            Ok(Browser::from_ptr(get_browser(self.as_ptr())))
+        })
+    }
+
+    /// Returns the frame for this context. This function will return an NULL
+    /// reference for WebWorker contexts.
+    pub fn get_frame(&self) -> Result<Option<Frame>> {
+        try_c!(self, get_frame, {
+            Ok(Frame::from_ptr(get_frame(self.as_ptr())))
+        })
+    }
+
+    /// Returns the global object for this context. The context must be entered
+    /// before calling this function.
+    pub fn get_global(&self) -> Result<V8Value> {
+        try_c!(self, get_global, {
+            Ok(V8Value::from_ptr_unchecked(get_global(self.as_ptr())))
+        })
+    }
+
+    /// Enter this context. A context must be explicitly entered before creating a
+    /// V8 Object, Array, Function or Date asynchronously. exit() must be called
+    /// the same number of times as enter() before releasing this context. V8
+    /// objects belong to the context in which they are created. Returns true (1)
+    /// if the scope was entered successfully.
+    pub fn enter(&self) -> Result<bool> {
+        try_c!(self, enter, { Ok(enter(self.as_ptr()) != 0) })
+    }
+
+    /// Exit this context. Call this function only after calling enter(). Returns
+    /// true (1) if the scope was exited successfully.
+    pub fn exit(&self) -> Result<bool> {
+        try_c!(self, exit, { Ok(exit(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this object is pointing to the same handle as |that|
+    /// object.
+    pub fn is_same(&self, that: V8Context) -> Result<bool> {
+        try_c!(self, is_same, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    /// Execute a string of JavaScript code in this V8 context. The |script_url|
+    /// parameter is the URL where the script in question can be found, if any.
+    /// The |start_line| parameter is the base line number to use for error
+    /// reporting. On success the resulting value is returned. On failure the
+    /// exception is returned instead.
+    pub fn eval(
+        &self,
+        code: &str,
+        script_url: &str,
+        start_line: i32
+    ) -> Result<std::result::Result<V8Value, V8Exception>> {
+        try_c!(self, eval, {
+            let code = CefString::new(code);
+            let script_url = CefString::new(script_url);
+            let mut retval: *mut cef_v8value_t = null_mut();
+            let mut exception: *mut cef_v8exception_t = null_mut();
+
+            eval(
+                self.as_ptr(),
+                code.as_ptr(),
+                script_url.as_ptr(),
+                start_line as c_int,
+                &mut retval,
+                &mut exception
+            );
+
+            let retval = V8Value::from_ptr(retval);
+            let exception = V8Exception::from_ptr(exception);
+
+            match (retval, exception) {
+                (Some(retval), None) => Ok(Ok(retval)),
+                (_, Some(exception)) => Ok(Err(exception)),
+                (None, None) => Err(anyhow!(
+                    "V8 eval returned neither a value nor an exception."
+                ))
+            }
+        })
+    }
+}
+
+/// Structure that should be implemented to handle V8 function calls. The
+/// functions of this structure will be called on the thread associated with the
+/// V8 function.
+pub trait V8HandlerCallbacks: Send + Sync + 'static {
+    /// Handle execution of the function identified by |name|. |object| is the
+    /// receiver ('this' object) of the function. |arguments| is the list of
+    /// arguments passed to the function. If execution succeeds set |retval| to
+    /// the function return value. If execution fails set |exception| to the
+    /// exception that will be thrown. Return true (1) if execution was handled.
+    fn execute(
+        &mut self,
+        name: &str,
+        object: V8Value,
+        arguments: Vec<V8Value>,
+        retval: &mut Option<V8Value>,
+        exception: &mut Option<String>
+    ) -> bool;
+}
+
+// Structure that should be implemented to handle V8 function calls. The
+// functions of this structure will be called on the thread associated with the
+// V8 function.
+ref_counted_ptr!(V8Handler, cef_v8handler_t);
+
+impl V8Handler {
+    pub fn new<C: V8HandlerCallbacks>(delegate: C) -> Self {
+        Self(V8HandlerWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct V8HandlerWrapper(Box<dyn V8HandlerCallbacks>);
+
+impl V8HandlerWrapper {
+    pub fn new<C: V8HandlerCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Handle execution of the function identified by |name|. |object| is the
+    /// receiver ('this' object) of the function. |arguments| is the list of
+    /// arguments passed to the function. If execution succeeds set |retval| to
+    /// the function return value. If execution fails set |exception| to the
+    /// exception that will be thrown. Return true (1) if execution was handled.
+    unsafe extern "C" fn c_execute(
+        this: *mut cef_v8handler_t,
+        name: *const cef_string_t,
+        object: *mut cef_v8value_t,
+        arguments_count: usize,
+        arguments: *const *mut cef_v8value_t,
+        retval: *mut *mut cef_v8value_t,
+        exception: *mut cef_string_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let name: String = CefString::from_ptr_unchecked(name).into();
+        let object = V8Value::from_ptr_unchecked(object);
+        let arguments = match arguments.is_null() {
+            true => Vec::new(),
+            false => from_raw_parts(arguments, arguments_count)
+                .iter()
+                .filter_map(|argument| V8Value::from_ptr(*argument))
+                .collect()
+        };
+        let mut local_retval = None;
+        let mut local_exception = None;
+
+        let ret = this.0.execute(
+            &name,
+            object,
+            arguments,
+            &mut local_retval,
+            &mut local_exception
+        );
+
+        if let Some(local_retval) = local_retval {
+            *retval = local_retval.into_raw();
+        }
+
+        if let Some(local_exception) = local_exception {
+            if let Some(exception) = CefString::from_ptr_mut(exception) {
+                exception.set(&local_exception);
+            }
+        }
+
+        ret as c_int
+    }
+}
+
+impl Wrappable for V8HandlerWrapper {
+    type Cef = cef_v8handler_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_v8handler_t> {
+        RefCountedPtr::wrap(
+            cef_v8handler_t {
+                base:    unsafe { zeroed() },
+                execute: Some(Self::c_execute)
+            },
+            self
+        )
+    }
+}
+
+// Structure representing a V8 exception. The functions of this structure may
+// be called on any render process thread.
+ref_counted_ptr!(V8Exception, cef_v8exception_t);
+
+impl V8Exception {
+    /// Returns the exception message.
+    pub fn get_message(&self) -> Result<String> {
+        try_c!(self, get_message, {
+            let s = get_message(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the line of source code that the exception occurred within.
+    pub fn get_source_line(&self) -> Result<String> {
+        try_c!(self, get_source_line, {
+            let s = get_source_line(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the resource name for the script from where the function causing
+    /// the error originates.
+    pub fn get_script_resource_name(&self) -> Result<String> {
+        try_c!(self, get_script_resource_name, {
+            let s = get_script_resource_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the 1-based number of the line where the error occurred or 0 if
+    /// the line number is unknown.
+    pub fn get_line_number(&self) -> Result<i32> {
+        try_c!(self, get_line_number, {
+            Ok(get_line_number(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns the index within the script of the first character where the error
+    /// occurred.
+    pub fn get_start_position(&self) -> Result<i32> {
+        try_c!(self, get_start_position, {
+            Ok(get_start_position(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns the index within the script of the last character where the error
+    /// occurred.
+    pub fn get_end_position(&self) -> Result<i32> {
+        try_c!(self, get_end_position, {
+            Ok(get_end_position(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns the index within the line of the first character where the error
+    /// occurred.
+    pub fn get_start_column(&self) -> Result<i32> {
+        try_c!(self, get_start_column, {
+            Ok(get_start_column(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns the index within the line of the last character where the error
+    /// occurred.
+    pub fn get_end_column(&self) -> Result<i32> {
+        try_c!(self, get_end_column, {
+            Ok(get_end_column(self.as_ptr()) as i32)
+        })
+    }
+}
+
+// Structure representing a V8 value handle. V8 handles can only be accessed
+// from the thread on which they are created. Valid threads for creating a V8
+// handle include the render process main thread (TID_RENDERER) and WebWorker
+// threads. A task runner for posting tasks on the associated thread can be
+// retrieved via the cef_v8context_t::get_task_runner() function.
+ref_counted_ptr!(V8Value, cef_v8value_t);
+
+impl V8Value {
+    /// Create a new cef_v8value_t object of type undefined.
+    pub fn new_undefined() -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_undefined()) }
+    }
+
+    /// Create a new cef_v8value_t object of type null.
+    pub fn new_null() -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_null()) }
+    }
+
+    /// Create a new cef_v8value_t object of type bool.
+    pub fn new_bool(value: bool) -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_bool(value as c_int)) }
+    }
+
+    /// Create a new cef_v8value_t object of type int.
+    pub fn new_int(value: i32) -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_int(value)) }
+    }
+
+    /// Create a new cef_v8value_t object of type unsigned int.
+    pub fn new_uint(value: u32) -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_uint(value)) }
+    }
+
+    /// Create a new cef_v8value_t object of type double.
+    pub fn new_double(value: f64) -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_double(value)) }
+    }
+
+    /// Create a new cef_v8value_t object of type Date. This function should only
+    /// be called from within the scope of a cef_render_process_handler_t,
+    /// cef_v8handler_t or cef_v8accessor_t callback, or in combination with
+    /// calling enter() and exit() on a stored cef_v8context_t reference.
+    pub fn new_date(date: DateTime<Utc>) -> Result<Self> {
+        let date = cef_basetime_t::try_from(CefTime::from(date)).map_err(|e| anyhow!(e))?;
+
+        unsafe { Ok(Self::from_ptr_unchecked(cef_v8value_create_date(date))) }
+    }
+
+    /// Create a new cef_v8value_t object of type string.
+    pub fn new_string(value: &str) -> Self {
+        let value = CefString::new(value);
+
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_string(value.as_ptr())) }
+    }
+
+    // TODO: Support accessors and interceptors!
+
+    /// Create a new cef_v8value_t object of type object. This function should
+    /// only be called from within the scope of a cef_render_process_handler_t,
+    /// cef_v8handler_t or cef_v8accessor_t callback, or in combination with
+    /// calling enter() and exit() on a stored cef_v8context_t reference.
+    pub fn new_object() -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_object(null_mut(), null_mut())) }
+    }
+
+    /// Create a new cef_v8value_t object of type array with the specified
+    /// |length|. If |length| is negative the returned array will have length 0.
+    /// This function should only be called from within the scope of a
+    /// cef_render_process_handler_t, cef_v8handler_t or cef_v8accessor_t
+    /// callback, or in combination with calling enter() and exit() on a stored
+    /// cef_v8context_t reference.
+    pub fn new_array(length: i32) -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_array(length as c_int)) }
+    }
+
+    /// Create a new cef_v8value_t object of type function. This function should
+    /// only be called from within the scope of a cef_render_process_handler_t,
+    /// cef_v8handler_t or cef_v8accessor_t callback, or in combination with
+    /// calling enter() and exit() on a stored cef_v8context_t reference.
+    pub fn new_function(name: &str, handler: V8Handler) -> Self {
+        let name = CefString::new(name);
+
+        unsafe {
+            Self::from_ptr_unchecked(cef_v8value_create_function(
+                name.as_ptr(),
+                handler.into_raw()
+            ))
+        }
+    }
+
+    /// Create a new cef_v8value_t object of type Promise. This function should
+    /// only be called from within the scope of a cef_render_process_handler_t,
+    /// cef_v8handler_t or cef_v8accessor_t callback, or in combination with
+    /// calling enter() and exit() on a stored cef_v8context_t reference.
+    pub fn new_promise() -> Self {
+        unsafe { Self::from_ptr_unchecked(cef_v8value_create_promise()) }
+    }
+
+    /// Returns true (1) if the underlying handle is valid and it can be accessed
+    /// on the current thread. Do not call any other functions if this function
+    /// returns false (0).
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, { Ok(is_valid(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is undefined.
+    pub fn is_undefined(&self) -> Result<bool> {
+        try_c!(self, is_undefined, { Ok(is_undefined(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is null.
+    pub fn is_null(&self) -> Result<bool> {
+        try_c!(self, is_null, { Ok(is_null(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is bool.
+    pub fn is_bool(&self) -> Result<bool> {
+        try_c!(self, is_bool, { Ok(is_bool(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is int.
+    pub fn is_int(&self) -> Result<bool> {
+        try_c!(self, is_int, { Ok(is_int(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is unsigned int.
+    pub fn is_uint(&self) -> Result<bool> {
+        try_c!(self, is_uint, { Ok(is_uint(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is double.
+    pub fn is_double(&self) -> Result<bool> {
+        try_c!(self, is_double, { Ok(is_double(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is Date.
+    pub fn is_date(&self) -> Result<bool> {
+        try_c!(self, is_date, { Ok(is_date(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is string.
+    pub fn is_string(&self) -> Result<bool> {
+        try_c!(self, is_string, { Ok(is_string(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is object.
+    pub fn is_object(&self) -> Result<bool> {
+        try_c!(self, is_object, { Ok(is_object(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is array.
+    pub fn is_array(&self) -> Result<bool> {
+        try_c!(self, is_array, { Ok(is_array(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is an ArrayBuffer.
+    pub fn is_array_buffer(&self) -> Result<bool> {
+        try_c!(self, is_array_buffer, {
+            Ok(is_array_buffer(self.as_ptr()) != 0)
+        })
+    }
+
+    /// True if the value type is function.
+    pub fn is_function(&self) -> Result<bool> {
+        try_c!(self, is_function, { Ok(is_function(self.as_ptr()) != 0) })
+    }
+
+    /// True if the value type is a Promise.
+    pub fn is_promise(&self) -> Result<bool> {
+        try_c!(self, is_promise, { Ok(is_promise(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if this object is pointing to the same handle as |that|
+    /// object.
+    pub fn is_same(&self, that: V8Value) -> Result<bool> {
+        try_c!(self, is_same, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    /// Return a bool value.
+    pub fn get_bool_value(&self) -> Result<bool> {
+        try_c!(self, get_bool_value, {
+            Ok(get_bool_value(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Return an int value.
+    pub fn get_int_value(&self) -> Result<i32> {
+        try_c!(self, get_int_value, { Ok(get_int_value(self.as_ptr())) })
+    }
+
+    /// Return an unsigned int value.
+    pub fn get_uint_value(&self) -> Result<u32> {
+        try_c!(self, get_uint_value, { Ok(get_uint_value(self.as_ptr())) })
+    }
+
+    /// Return a double value.
+    pub fn get_double_value(&self) -> Result<f64> {
+        try_c!(self, get_double_value, {
+            Ok(get_double_value(self.as_ptr()))
+        })
+    }
+
+    /// Return a Date value.
+    pub fn get_date_value(&self) -> Result<Option<DateTime<Utc>>> {
+        try_c!(self, get_date_value, {
+            let base_time = get_date_value(self.as_ptr());
+
+            Ok(CefTime::try_from(base_time)
+                .ok()
+                .map(CefTime::into))
+        })
+    }
+
+    /// Return a string value.
+    pub fn get_string_value(&self) -> Result<String> {
+        try_c!(self, get_string_value, {
+            let s = get_string_value(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns true (1) if this is a user created object.
+    pub fn is_user_created(&self) -> Result<bool> {
+        try_c!(self, is_user_created, {
+            Ok(is_user_created(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if the last function call resulted in an exception. This
+    /// attribute exists only in the scope of the current CEF value object.
+    pub fn has_exception(&self) -> Result<bool> {
+        try_c!(self, has_exception, {
+            Ok(has_exception(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the exception resulting from the last function call. This
+    /// attribute exists only in the scope of the current CEF value object.
+    pub fn get_exception(&self) -> Result<Option<V8Exception>> {
+        try_c!(self, get_exception, {
+            Ok(V8Exception::from_ptr(get_exception(self.as_ptr())))
+        })
+    }
+
+    /// Clears the last exception and returns true (1) on success.
+    pub fn clear_exception(&self) -> Result<bool> {
+        try_c!(self, clear_exception, {
+            Ok(clear_exception(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if this object will re-throw future exceptions. This
+    /// attribute exists only in the scope of the current CEF value object.
+    pub fn will_rethrow_exceptions(&self) -> Result<bool> {
+        try_c!(self, will_rethrow_exceptions, {
+            Ok(will_rethrow_exceptions(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Set whether this object will re-throw future exceptions. By default
+    /// exceptions are not re-thrown. If a exception is re-thrown the current
+    /// context should not be accessed again until after the exception has been
+    /// caught and not re-thrown. Returns true (1) on success. This attribute
+    /// exists only in the scope of the current CEF value object.
+    pub fn set_rethrow_exceptions(&self, rethrow: bool) -> Result<bool> {
+        try_c!(self, set_rethrow_exceptions, {
+            Ok(set_rethrow_exceptions(self.as_ptr(), rethrow as c_int) != 0)
+        })
+    }
+
+    /// Returns true (1) if the object has a value with the specified identifier.
+    pub fn has_value_bykey(&self, key: &str) -> Result<bool> {
+        try_c!(self, has_value_bykey, {
+            let key = CefString::new(key);
+
+            Ok(has_value_bykey(self.as_ptr(), key.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if the object has a value with the specified identifier.
+    pub fn has_value_byindex(&self, index: i32) -> Result<bool> {
+        try_c!(self, has_value_byindex, {
+            Ok(has_value_byindex(self.as_ptr(), index as c_int) != 0)
+        })
+    }
+
+    /// Deletes the value with the specified identifier and returns true (1) on
+    /// success. Returns false (0) if this function is called incorrectly or an
+    /// exception is thrown. For read-only and don't-delete values this function
+    /// will return true (1) even though deletion failed.
+    pub fn delete_value_bykey(&self, key: &str) -> Result<bool> {
+        try_c!(self, delete_value_bykey, {
+            let key = CefString::new(key);
+
+            Ok(delete_value_bykey(self.as_ptr(), key.as_ptr()) != 0)
+        })
+    }
+
+    /// Deletes the value with the specified identifier and returns true (1) on
+    /// success. Returns false (0) if this function is called incorrectly,
+    /// deletion fails or an exception is thrown. For read-only and don't-delete
+    /// values this function will return true (1) even though deletion failed.
+    pub fn delete_value_byindex(&self, index: i32) -> Result<bool> {
+        try_c!(self, delete_value_byindex, {
+            Ok(delete_value_byindex(self.as_ptr(), index as c_int) != 0)
+        })
+    }
+
+    /// Returns the value with the specified identifier on success. Returns NULL
+    /// if this function is called incorrectly or an exception is thrown.
+    pub fn get_value_bykey(&self, key: &str) -> Result<Option<V8Value>> {
+        try_c!(self, get_value_bykey, {
+            let key = CefString::new(key);
+
+            Ok(V8Value::from_ptr(get_value_bykey(
+                self.as_ptr(),
+                key.as_ptr()
+            )))
+        })
+    }
+
+    /// Returns the value with the specified identifier on success. Returns NULL
+    /// if this function is called incorrectly or an exception is thrown.
+    pub fn get_value_byindex(&self, index: i32) -> Result<Option<V8Value>> {
+        try_c!(self, get_value_byindex, {
+            Ok(V8Value::from_ptr(get_value_byindex(
+                self.as_ptr(),
+                index as c_int
+            )))
+        })
+    }
+
+    /// Associates a value with the specified identifier and returns true (1) on
+    /// success. Returns false (0) if this function is called incorrectly or an
+    /// exception is thrown. For read-only values this function will return true
+    /// (1) even though assignment failed.
+    pub fn set_value_bykey(
+        &self,
+        key: &str,
+        value: V8Value,
+        attribute: V8PropertyAttribute
+    ) -> Result<bool> {
+        try_c!(self, set_value_bykey, {
+            let key = CefString::new(key);
+
+            Ok(set_value_bykey(
+                self.as_ptr(),
+                key.as_ptr(),
+                value.into_raw(),
+                attribute.into()
+            ) != 0)
+        })
+    }
+
+    /// Associates a value with the specified identifier and returns true (1) on
+    /// success. Returns false (0) if this function is called incorrectly or an
+    /// exception is thrown. For read-only values this function will return true
+    /// (1) even though assignment failed.
+    pub fn set_value_byindex(&self, index: i32, value: V8Value) -> Result<bool> {
+        try_c!(self, set_value_byindex, {
+            Ok(set_value_byindex(self.as_ptr(), index as c_int, value.into_raw()) != 0)
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Registers an identifier and returns true (1) on success. Access to the
+    // /// identifier will be forwarded to the cef_v8accessor_t instance passed to
+    // /// cef_v8value_t::cef_v8value_create_object(). Returns false (0) if this
+    // /// function is called incorrectly or an exception is thrown. For read-only
+    // /// values this function will return true (1) even though assignment failed.
+    // ///
+    // int(CEF_CALLBACK* set_value_byaccessor)(
+    // struct _cef_v8value_t* self,
+    // const cef_string_t* key,
+    // cef_v8_accesscontrol_t settings,
+    // cef_v8_propertyattribute_t attribute);
+
+    /// Read the keys for the object's values into the specified vector. Integer-
+    /// based keys will also be returned as strings.
+    pub fn get_keys(&self) -> Result<Option<Vec<String>>> {
+        try_c!(self, get_keys, {
+            let mut keys = CefStringList::new();
+
+            let keys = match get_keys(self.as_ptr(), keys.as_mut_ptr()) {
+                0 => None,
+                _ => Some(keys.into())
+            };
+
+            Ok(keys)
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Sets the user data for this object and returns true (1) on success.
+    // /// Returns false (0) if this function is called incorrectly. This function
+    // /// can only be called on user created objects.
+    // ///
+    // int(CEF_CALLBACK* set_user_data)(struct _cef_v8value_t* self,
+    // struct _cef_base_ref_counted_t* user_data);
+    //
+    // ///
+    // /// Returns the user data, if any, assigned to this object.
+    // ///
+    // struct _cef_base_ref_counted_t*(CEF_CALLBACK* get_user_data)(
+    // struct _cef_v8value_t* self);
+
+    /// Returns the amount of externally allocated memory registered for the
+    /// object.
+    pub fn get_externally_allocated_memory(&self) -> Result<i32> {
+        try_c!(self, get_externally_allocated_memory, {
+            Ok(get_externally_allocated_memory(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Adjusts the amount of registered external memory for the object. Used to
+    /// give V8 an indication of the amount of externally allocated memory that is
+    /// kept alive by JavaScript objects. V8 uses this information to decide when
+    /// to perform global garbage collection. Each cef_v8value_t tracks the amount
+    /// of external memory associated with it and automatically decreases the
+    /// global total by the appropriate amount on its destruction.
+    /// |change_in_bytes| specifies the number of bytes to adjust by. This function
+    /// returns the number of bytes associated with the object after the
+    /// adjustment. This function can only be called on user created objects.
+    pub fn adjust_externally_allocated_memory(&self, change_in_bytes: i32) -> Result<i32> {
+        try_c!(self, adjust_externally_allocated_memory, {
+            Ok(adjust_externally_allocated_memory(self.as_ptr(), change_in_bytes as c_int) as i32)
+        })
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn get_array_length(&self) -> Result<i32> {
+        try_c!(self, get_array_length, {
+            Ok(get_array_length(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Prevent the ArrayBuffer from using it's memory block by setting the length
+    /// to zero. This operation cannot be undone. If the ArrayBuffer was created
+    /// with CreateArrayBuffer then
+    /// cef_v8array_buffer_release_callback_t::ReleaseBuffer will be called to
+    /// release the underlying buffer.
+    pub fn neuter_array_buffer(&self) -> Result<bool> {
+        try_c!(self, neuter_array_buffer, {
+            Ok(neuter_array_buffer(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns the length (in bytes) of the ArrayBuffer.
+    pub fn get_array_buffer_byte_length(&self) -> Result<usize> {
+        try_c!(self, get_array_buffer_byte_length, {
+            Ok(get_array_buffer_byte_length(self.as_ptr()))
+        })
+    }
+
+    /// Returns a copy of the contents of the ArrayBuffer. Returns NULL if
+    /// the value is not an ArrayBuffer.
+    pub fn get_array_buffer_data(&self) -> Result<Option<Vec<u8>>> {
+        try_c!(self, get_array_buffer_data, {
+            let data = get_array_buffer_data(self.as_ptr()) as *const u8;
+
+            if data.is_null() {
+                return Ok(None);
+            }
+
+            let length = self.get_array_buffer_byte_length()?;
+
+            Ok(Some(from_raw_parts(data, length).to_vec()))
+        })
+    }
+
+    /// Returns the function name.
+    pub fn get_function_name(&self) -> Result<String> {
+        try_c!(self, get_function_name, {
+            let s = get_function_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the function handler or NULL if not a CEF-created function.
+    pub fn get_function_handler(&self) -> Result<Option<V8Handler>> {
+        try_c!(self, get_function_handler, {
+            Ok(V8Handler::from_ptr(get_function_handler(self.as_ptr())))
+        })
+    }
+
+    /// Execute the function using the current V8 context. This function should
+    /// only be called from within the scope of a cef_v8handler_t or
+    /// cef_v8accessor_t callback, or in combination with calling enter() and
+    /// exit() on a stored cef_v8context_t reference. |object| is the receiver
+    /// ('this' object) of the function. If |object| is NULL the current context's
+    /// global object will be used. |arguments| is the list of arguments that will
+    /// be passed to the function. Returns the function return value on success.
+    /// Returns NULL if this function is called incorrectly or an exception is
+    /// thrown.
+    pub fn execute_function(
+        &self,
+        object: Option<V8Value>,
+        arguments: Vec<V8Value>
+    ) -> Result<Option<V8Value>> {
+        try_c!(self, execute_function, {
+            let object = object
+                .map(|object| object.into_raw())
+                .unwrap_or_else(null_mut);
+            let arguments: Vec<*mut cef_v8value_t> = arguments
+                .into_iter()
+                .map(|argument| argument.into_raw())
+                .collect();
+
+            Ok(V8Value::from_ptr(execute_function(
+                self.as_ptr(),
+                object,
+                arguments.len(),
+                match arguments.is_empty() {
+                    true => null(),
+                    false => arguments.as_ptr()
+                }
+            )))
+        })
+    }
+
+    /// Execute the function using the specified V8 context. |object| is the
+    /// receiver ('this' object) of the function. If |object| is NULL the
+    /// specified context's global object will be used. |arguments| is the list of
+    /// arguments that will be passed to the function. Returns the function return
+    /// value on success. Returns NULL if this function is called incorrectly or an
+    /// exception is thrown.
+    pub fn execute_function_with_context(
+        &self,
+        context: V8Context,
+        object: Option<V8Value>,
+        arguments: Vec<V8Value>
+    ) -> Result<Option<V8Value>> {
+        try_c!(self, execute_function_with_context, {
+            let object = object
+                .map(|object| object.into_raw())
+                .unwrap_or_else(null_mut);
+            let arguments: Vec<*mut cef_v8value_t> = arguments
+                .into_iter()
+                .map(|argument| argument.into_raw())
+                .collect();
+
+            Ok(V8Value::from_ptr(execute_function_with_context(
+                self.as_ptr(),
+                context.into_raw(),
+                object,
+                arguments.len(),
+                match arguments.is_empty() {
+                    true => null(),
+                    false => arguments.as_ptr()
+                }
+            )))
+        })
+    }
+
+    /// Resolve the Promise using the current V8 context. This function should
+    /// only be called from within the scope of a cef_v8handler_t or
+    /// cef_v8accessor_t callback, or in combination with calling enter() and
+    /// exit() on a stored cef_v8context_t reference. |arg| is the argument passed
+    /// to the resolved promise. Returns true (1) on success. Returns false (0) if
+    /// this function is called incorrectly or an exception is thrown.
+    pub fn resolve_promise(&self, arg: Option<V8Value>) -> Result<bool> {
+        try_c!(self, resolve_promise, {
+            let arg = arg
+                .map(|arg| arg.into_raw())
+                .unwrap_or_else(null_mut);
+
+            Ok(resolve_promise(self.as_ptr(), arg) != 0)
+        })
+    }
+
+    /// Reject the Promise using the current V8 context. This function should only
+    /// be called from within the scope of a cef_v8handler_t or cef_v8accessor_t
+    /// callback, or in combination with calling enter() and exit() on a stored
+    /// cef_v8context_t reference. Returns true (1) on success. Returns false (0)
+    /// if this function is called incorrectly or an exception is thrown.
+    pub fn reject_promise(&self, error_message: &str) -> Result<bool> {
+        try_c!(self, reject_promise, {
+            let error_message = CefString::new(error_message);
+
+            Ok(reject_promise(self.as_ptr(), error_message.as_ptr()) != 0)
+        })
+    }
+
+    /// Converts this to a Value that can be sent to another process. Arrays
+    /// become lists, objects become dictionaries, array buffers become binary
+    /// values and dates become RFC 3339 strings. Functions, promises and anything
+    /// nested deeper than |max_depth| are converted to null.
+    pub fn to_value(&self, max_depth: usize) -> Result<Value> {
+        let value = Value::new();
+
+        if max_depth == 0 || self.is_undefined()? || self.is_null()? {
+            value.set_null()?;
+        } else if self.is_bool()? {
+            value.set_bool(self.get_bool_value()?)?;
+        } else if self.is_int()? {
+            value.set_int(self.get_int_value()?)?;
+        } else if self.is_uint()? {
+            match i32::try_from(self.get_uint_value()?) {
+                Ok(uint) => value.set_int(uint)?,
+                Err(_) => value.set_double(self.get_uint_value()? as f64)?
+            };
+        } else if self.is_double()? {
+            value.set_double(self.get_double_value()?)?;
+        } else if self.is_string()? {
+            value.set_string(&self.get_string_value()?)?;
+        } else if self.is_date()? {
+            match self.get_date_value()? {
+                Some(date) => value.set_string(&date.to_rfc3339())?,
+                None => value.set_null()?
+            };
+        } else if self.is_array()? {
+            let list = ListValue::new();
+
+            for index in 0..self.get_array_length()? {
+                let item = match self.get_value_byindex(index)? {
+                    Some(item) => item.to_value(max_depth - 1)?,
+                    None => Value::new()
+                };
+
+                list.set_value(index as usize, item)?;
+            }
+
+            value.set_list(list)?;
+        } else if self.is_array_buffer()? {
+            match self.get_array_buffer_data()? {
+                Some(data) => value.set_binary(BinaryValue::new(&data))?,
+                None => value.set_null()?
+            };
+        } else if self.is_function()? || self.is_promise()? {
+            value.set_null()?;
+        } else if self.is_object()? {
+            let dictionary = DictionaryValue::new();
+
+            for key in self.get_keys()?.unwrap_or_default() {
+                let item = match self.get_value_bykey(&key)? {
+                    Some(item) => item.to_value(max_depth - 1)?,
+                    None => Value::new()
+                };
+
+                dictionary.set_value(&key, item)?;
+            }
+
+            value.set_dictionary(dictionary)?;
+        } else {
+            value.set_null()?;
+        }
+
+        Ok(value)
+    }
+}
+
+// Structure representing a V8 stack trace handle. V8 handles can only be
+// accessed from the thread on which they are created. Valid threads for
+// creating a V8 handle include the render process main thread (TID_RENDERER)
+// and WebWorker threads. A task runner for posting tasks on the associated
+// thread can be retrieved via the cef_v8context_t::get_task_runner() function.
+ref_counted_ptr!(V8StackTrace, cef_v8stack_trace_t);
+
+impl V8StackTrace {
+    /// Returns the stack trace for the currently active context. |frame_limit| is
+    /// the maximum number of frames that will be captured.
+    pub fn get_current(frame_limit: i32) -> Option<V8StackTrace> {
+        unsafe { V8StackTrace::from_ptr(cef_v8stack_trace_get_current(frame_limit as c_int)) }
+    }
+
+    /// Returns true (1) if the underlying handle is valid and it can be accessed
+    /// on the current thread. Do not call any other functions if this function
+    /// returns false (0).
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, { Ok(is_valid(self.as_ptr()) != 0) })
+    }
+
+    /// Returns the number of stack frames.
+    pub fn get_frame_count(&self) -> Result<i32> {
+        try_c!(self, get_frame_count, {
+            Ok(get_frame_count(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns the stack frame at the specified 0-based index.
+    pub fn get_frame(&self, index: i32) -> Result<Option<V8StackFrame>> {
+        try_c!(self, get_frame, {
+            Ok(V8StackFrame::from_ptr(get_frame(
+                self.as_ptr(),
+                index as c_int
+            )))
+        })
+    }
+}
+
+// Structure representing a V8 stack frame handle. V8 handles can only be
+// accessed from the thread on which they are created. Valid threads for
+// creating a V8 handle include the render process main thread (TID_RENDERER)
+// and WebWorker threads. A task runner for posting tasks on the associated
+// thread can be retrieved via the cef_v8context_t::get_task_runner() function.
+ref_counted_ptr!(V8StackFrame, cef_v8stack_frame_t);
+
+impl V8StackFrame {
+    /// Returns true (1) if the underlying handle is valid and it can be accessed
+    /// on the current thread. Do not call any other functions if this function
+    /// returns false (0).
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, { Ok(is_valid(self.as_ptr()) != 0) })
+    }
+
+    /// Returns the name of the resource script that contains the function.
+    pub fn get_script_name(&self) -> Result<String> {
+        try_c!(self, get_script_name, {
+            let s = get_script_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the name of the resource script that contains the function or the
+    /// sourceURL value if the script name is undefined and its source ends with a
+    /// "//@ sourceURL=..." string.
+    pub fn get_script_name_or_source_url(&self) -> Result<String> {
+        try_c!(self, get_script_name_or_source_url, {
+            let s = get_script_name_or_source_url(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the name of the function.
+    pub fn get_function_name(&self) -> Result<String> {
+        try_c!(self, get_function_name, {
+            let s = get_function_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr(s).map_or(String::new(), |s| s.into()))
+        })
+    }
+
+    /// Returns the 1-based line number for the function call or 0 if unknown.
+    pub fn get_line_number(&self) -> Result<i32> {
+        try_c!(self, get_line_number, {
+            Ok(get_line_number(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Returns the 1-based column offset on the line for the function call or 0
+    /// if unknown.
+    pub fn get_column(&self) -> Result<i32> {
+        try_c!(self, get_column, { Ok(get_column(self.as_ptr()) as i32) })
+    }
+
+    /// Returns true (1) if the function was compiled using eval().
+    pub fn is_eval(&self) -> Result<bool> {
+        try_c!(self, is_eval, { Ok(is_eval(self.as_ptr()) != 0) })
+    }
+
+    /// Returns true (1) if the function was called as a constructor via "new".
+    pub fn is_constructor(&self) -> Result<bool> {
+        try_c!(self, is_constructor, {
+            Ok(is_constructor(self.as_ptr()) != 0)
+        })
+    }
+}
@@ -0,0 +1,1161 @@
+use crate::{BinaryValue, DictionaryValue, ListValue, Value, ValueType};
+use serde::{
+    de::{
+        self, value::SeqDeserializer, DeserializeOwned, DeserializeSeed, EnumAccess,
+        IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor
+    },
+    forward_to_deserialize_any,
+    ser::{
+        self, Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct,
+        SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant
+    }
+};
+use std::{
+    fmt::{Display, Formatter},
+    vec::IntoIter
+};
+
+/// Errors that can occur when converting between Rust types and values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueError(String);
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl ser::Error for ValueError {
+    fn custom<T: Display>(msg: T) -> Self {
+        ValueError(msg.to_string())
+    }
+}
+
+impl de::Error for ValueError {
+    fn custom<T: Display>(msg: T) -> Self {
+        ValueError(msg.to_string())
+    }
+}
+
+impl From<anyhow::Error> for ValueError {
+    fn from(value: anyhow::Error) -> Self {
+        ValueError(value.to_string())
+    }
+}
+
+/// Converts a Rust type into a value. Structs and maps become dictionaries,
+/// sequences and tuples become lists and byte sequences (e.g. Vec<u8>) become
+/// binary values. Integers that don't fit in an i32 are stored as doubles.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, ValueError> {
+    value.serialize(ValueSerializer)
+}
+
+/// Converts a value into a Rust type.
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, ValueError> {
+    T::deserialize(ValueDeserializer::new(value))
+}
+
+/// Creates a new value and initializes it with the provided setter.
+fn new_value(f: impl FnOnce(&Value) -> anyhow::Result<bool>) -> Result<Value, ValueError> {
+    let value = Value::new();
+
+    match f(&value)? {
+        true => Ok(value),
+        false => Err(ValueError(String::from("Failed to set value.")))
+    }
+}
+
+/// Creates a new value holding an integer. Integers outside the range of an
+/// i32 are stored as doubles.
+fn new_integer(v: i64) -> Result<Value, ValueError> {
+    match i32::try_from(v) {
+        Ok(v) => new_value(|value| value.set_int(v)),
+        Err(_) => new_value(|value| value.set_double(v as f64))
+    }
+}
+
+/// Creates a new value holding a dictionary with a single entry that maps the
+/// variant name to its data.
+fn new_variant(variant: &str, inner: Value) -> Result<Value, ValueError> {
+    let dictionary = DictionaryValue::new();
+
+    dictionary.set_value(variant, inner)?;
+
+    new_value(|value| value.set_dictionary(dictionary))
+}
+
+/// Converts a serialized map key into a dictionary key.
+fn to_key(key: &Value) -> Result<String, ValueError> {
+    match key.get_type()? {
+        ValueType::String => Ok(key
+            .get_string()?
+            .unwrap_or_default()),
+        ValueType::Bool => Ok(key.get_bool()?.to_string()),
+        ValueType::Int => Ok(key.get_int()?.to_string()),
+        ValueType::Double => Ok(key.get_double()?.to_string()),
+        _ => Err(ValueError(String::from("Map keys must be strings.")))
+    }
+}
+
+/// Copies the contents of a binary value into a vector.
+fn binary_data(binary: Option<BinaryValue>) -> Result<Vec<u8>, ValueError> {
+    match binary {
+        Some(binary) => {
+            let mut data = vec![0; binary.get_size()?];
+
+            binary.get_data(&mut data, 0)?;
+
+            Ok(data)
+        },
+        None => Ok(Vec::new())
+    }
+}
+
+/// A serializer that converts Rust types into values.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = ValueError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = VariantSerializer<ListSerializer>;
+    type SerializeMap = DictionarySerializer;
+    type SerializeStruct = DictionarySerializer;
+    type SerializeStructVariant = VariantSerializer<DictionarySerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, ValueError> {
+        new_value(|value| value.set_bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, ValueError> {
+        new_integer(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, ValueError> {
+        new_integer(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, ValueError> {
+        new_integer(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, ValueError> {
+        new_integer(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, ValueError> {
+        new_integer(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, ValueError> {
+        new_integer(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, ValueError> {
+        new_integer(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, ValueError> {
+        match i64::try_from(v) {
+            Ok(v) => new_integer(v),
+            Err(_) => new_value(|value| value.set_double(v as f64))
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, ValueError> {
+        new_value(|value| value.set_double(v as f64))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, ValueError> {
+        new_value(|value| value.set_double(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, ValueError> {
+        new_value(|value| value.set_string(&v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, ValueError> {
+        new_value(|value| value.set_string(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, ValueError> {
+        new_value(|value| value.set_binary(BinaryValue::new(v)))
+    }
+
+    fn serialize_none(self) -> Result<Value, ValueError> {
+        new_value(|value| value.set_null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, ValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, ValueError> {
+        new_value(|value| value.set_null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, ValueError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str
+    ) -> Result<Value, ValueError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T
+    ) -> Result<Value, ValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T
+    ) -> Result<Value, ValueError> {
+        new_variant(variant, to_value(value)?)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqSerializer, ValueError> {
+        Ok(SeqSerializer::new())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<ListSerializer, ValueError> {
+        Ok(ListSerializer::new())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize
+    ) -> Result<ListSerializer, ValueError> {
+        Ok(ListSerializer::new())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize
+    ) -> Result<VariantSerializer<ListSerializer>, ValueError> {
+        Ok(VariantSerializer::new(variant, ListSerializer::new()))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<DictionarySerializer, ValueError> {
+        Ok(DictionarySerializer::new())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize
+    ) -> Result<DictionarySerializer, ValueError> {
+        Ok(DictionarySerializer::new())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize
+    ) -> Result<VariantSerializer<DictionarySerializer>, ValueError> {
+        Ok(VariantSerializer::new(variant, DictionarySerializer::new()))
+    }
+}
+
+/// Serializes tuples into lists.
+pub struct ListSerializer {
+    list:  ListValue,
+    index: usize
+}
+
+impl ListSerializer {
+    fn new() -> Self {
+        Self {
+            list:  ListValue::new(),
+            index: 0
+        }
+    }
+
+    fn push(&mut self, value: Value) -> Result<(), ValueError> {
+        self.list
+            .set_value(self.index, value)?;
+        self.index += 1;
+
+        Ok(())
+    }
+
+    fn into_value(self) -> Result<Value, ValueError> {
+        new_value(|value| value.set_list(self.list))
+    }
+}
+
+impl SerializeTuple for ListSerializer {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.push(to_value(value)?)
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        self.into_value()
+    }
+}
+
+impl SerializeTupleStruct for ListSerializer {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.push(to_value(value)?)
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        self.into_value()
+    }
+}
+
+/// Serializes sequences into lists. Sequences that consist entirely of bytes
+/// are serialized into binary values instead.
+pub struct SeqSerializer {
+    bytes: Option<Vec<u8>>,
+    list:  ListSerializer
+}
+
+impl SeqSerializer {
+    fn new() -> Self {
+        Self {
+            bytes: Some(Vec::new()),
+            list:  ListSerializer::new()
+        }
+    }
+}
+
+impl SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        if let Some(bytes) = self.bytes.as_mut() {
+            if let Ok(byte) = value.serialize(ByteSerializer) {
+                bytes.push(byte);
+
+                return Ok(());
+            }
+
+            // This isn't a byte sequence, so move the bytes seen so far into
+            // the list and serialize everything else as regular values.
+            for byte in self
+                .bytes
+                .take()
+                .unwrap_or_default()
+            {
+                self.list
+                    .push(new_integer(byte as i64)?)?;
+            }
+        }
+
+        self.list.push(to_value(value)?)
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        match self.bytes {
+            Some(bytes) if !bytes.is_empty() => {
+                ser::Serializer::serialize_bytes(ValueSerializer, &bytes)
+            },
+            _ => self.list.into_value()
+        }
+    }
+}
+
+/// Serializes maps and structs into dictionaries.
+pub struct DictionarySerializer {
+    dictionary: DictionaryValue,
+    key:        Option<String>
+}
+
+impl DictionarySerializer {
+    fn new() -> Self {
+        Self {
+            dictionary: DictionaryValue::new(),
+            key:        None
+        }
+    }
+
+    fn into_value(self) -> Result<Value, ValueError> {
+        new_value(|value| value.set_dictionary(self.dictionary))
+    }
+}
+
+impl SerializeMap for DictionarySerializer {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ValueError> {
+        self.key = Some(to_key(&to_value(key)?)?);
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ValueError(String::from("Map value serialized before its key.")))?;
+
+        self.dictionary
+            .set_value(&key, to_value(value)?)?;
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        self.into_value()
+    }
+}
+
+impl SerializeStruct for DictionarySerializer {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T
+    ) -> Result<(), ValueError> {
+        self.dictionary
+            .set_value(key, to_value(value)?)?;
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        self.into_value()
+    }
+}
+
+/// Serializes enum variants with data into a dictionary containing a single
+/// entry that maps the variant name to its data.
+pub struct VariantSerializer<S> {
+    variant: &'static str,
+    inner:   S
+}
+
+impl<S> VariantSerializer<S> {
+    fn new(variant: &'static str, inner: S) -> Self {
+        Self { variant, inner }
+    }
+}
+
+impl SerializeTupleVariant for VariantSerializer<ListSerializer> {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.inner.push(to_value(value)?)
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        new_variant(self.variant, self.inner.into_value()?)
+    }
+}
+
+impl SerializeStructVariant for VariantSerializer<DictionarySerializer> {
+    type Ok = Value;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T
+    ) -> Result<(), ValueError> {
+        SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Value, ValueError> {
+        new_variant(self.variant, self.inner.into_value()?)
+    }
+}
+
+/// Rejects everything except a u8. This is used to detect byte sequences.
+struct ByteSerializer;
+
+/// Generates serializer functions that reject their input.
+macro_rules! reject {
+    ($($name:ident($($arg:ty),*) -> $ret:ty;)*) => {
+        $(
+            fn $name(self, $(_: $arg),*) -> Result<$ret, ValueError> {
+                Err(ValueError(String::from("Not a byte.")))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for ByteSerializer {
+    type Ok = u8;
+    type Error = ValueError;
+    type SerializeSeq = Impossible<u8, ValueError>;
+    type SerializeTuple = Impossible<u8, ValueError>;
+    type SerializeTupleStruct = Impossible<u8, ValueError>;
+    type SerializeTupleVariant = Impossible<u8, ValueError>;
+    type SerializeMap = Impossible<u8, ValueError>;
+    type SerializeStruct = Impossible<u8, ValueError>;
+    type SerializeStructVariant = Impossible<u8, ValueError>;
+
+    fn serialize_u8(self, v: u8) -> Result<u8, ValueError> {
+        Ok(v)
+    }
+
+    reject! {
+        serialize_bool(bool) -> u8;
+        serialize_i8(i8) -> u8;
+        serialize_i16(i16) -> u8;
+        serialize_i32(i32) -> u8;
+        serialize_i64(i64) -> u8;
+        serialize_u16(u16) -> u8;
+        serialize_u32(u32) -> u8;
+        serialize_u64(u64) -> u8;
+        serialize_f32(f32) -> u8;
+        serialize_f64(f64) -> u8;
+        serialize_char(char) -> u8;
+        serialize_str(&str) -> u8;
+        serialize_bytes(&[u8]) -> u8;
+        serialize_none() -> u8;
+        serialize_unit() -> u8;
+        serialize_unit_struct(&'static str) -> u8;
+        serialize_unit_variant(&'static str, u32, &'static str) -> u8;
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant;
+        serialize_map(Option<usize>) -> Self::SerializeMap;
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant;
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<u8, ValueError> {
+        Err(ValueError(String::from("Not a byte.")))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _value: &T
+    ) -> Result<u8, ValueError> {
+        Err(ValueError(String::from("Not a byte.")))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T
+    ) -> Result<u8, ValueError> {
+        Err(ValueError(String::from("Not a byte.")))
+    }
+}
+
+/// A deserializer that converts values into Rust types.
+pub struct ValueDeserializer {
+    value: Value
+}
+
+impl ValueDeserializer {
+    /// Creates a new deserializer for the provided value.
+    pub fn new(value: Value) -> Self {
+        Self { value }
+    }
+
+    /// Integers that don't fit in an i32 are stored as doubles, so accept
+    /// doubles with no fractional part as integers.
+    fn deserialize_integer<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self.value.get_type()? {
+            ValueType::Double => {
+                let v = self.value.get_double()?;
+
+                match v.fract() == 0.0 {
+                    true if v < 0.0 => visitor.visit_i64(v as i64),
+                    true => visitor.visit_u64(v as u64),
+                    false => visitor.visit_f64(v)
+                }
+            },
+            _ => de::Deserializer::deserialize_any(self, visitor)
+        }
+    }
+}
+
+/// Generates deserializer functions that forward to deserialize_integer.
+macro_rules! deserialize_integer {
+    ($($name:ident)*) => {
+        $(
+            fn $name<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+                self.deserialize_integer(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self.value.get_type()? {
+            ValueType::Invalid | ValueType::Null => visitor.visit_unit(),
+            ValueType::Bool => visitor.visit_bool(self.value.get_bool()?),
+            ValueType::Int => visitor.visit_i32(self.value.get_int()?),
+            ValueType::Double => visitor.visit_f64(self.value.get_double()?),
+            ValueType::String => visitor.visit_string(
+                self.value
+                    .get_string()?
+                    .unwrap_or_default()
+            ),
+            ValueType::Binary => visitor.visit_byte_buf(binary_data(self.value.get_binary()?)?),
+            ValueType::Dictionary => match self.value.get_dictionary()? {
+                Some(dictionary) => visitor.visit_map(DictionaryAccess::new(dictionary)?),
+                None => visitor.visit_unit()
+            },
+            ValueType::List => match self.value.get_list()? {
+                Some(list) => visitor.visit_seq(ListAccess::new(list)?),
+                None => visitor.visit_unit()
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self.value.get_type()? {
+            ValueType::Invalid | ValueType::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self.value.get_type()? {
+            ValueType::Binary => {
+                let data = binary_data(self.value.get_binary()?)?;
+                let mut seq = SeqDeserializer::<_, ValueError>::new(data.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+
+                seq.end()?;
+
+                Ok(value)
+            },
+            _ => self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        match self.value.get_type()? {
+            ValueType::String => {
+                let variant: String = self
+                    .value
+                    .get_string()?
+                    .unwrap_or_default();
+
+                visitor.visit_enum(IntoDeserializer::<ValueError>::into_deserializer(variant))
+            },
+            ValueType::Dictionary => {
+                let dictionary = self
+                    .value
+                    .get_dictionary()?
+                    .ok_or_else(|| ValueError(String::from("Invalid enum dictionary.")))?;
+                let mut keys = dictionary.get_keys()?;
+
+                match (keys.pop(), keys.is_empty()) {
+                    (Some(variant), true) => {
+                        let value = dictionary
+                            .get_value(&variant)?
+                            .unwrap_or_else(Value::new);
+
+                        visitor.visit_enum(EnumDeserializer { variant, value })
+                    },
+                    _ => Err(ValueError(String::from(
+                        "Enum dictionaries must contain exactly one entry."
+                    )))
+                }
+            },
+            _ => Err(ValueError(String::from(
+                "Enums must be a string or a dictionary."
+            )))
+        }
+    }
+
+    deserialize_integer! {
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+    }
+
+    forward_to_deserialize_any! {
+        bool i128 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        map struct identifier ignored_any
+    }
+}
+
+/// Provides access to the entries of a dictionary.
+struct DictionaryAccess {
+    dictionary: DictionaryValue,
+    keys:       IntoIter<String>,
+    key:        Option<String>
+}
+
+impl DictionaryAccess {
+    fn new(dictionary: DictionaryValue) -> Result<Self, ValueError> {
+        let keys = dictionary.get_keys()?.into_iter();
+
+        Ok(Self {
+            dictionary,
+            keys,
+            key: None
+        })
+    }
+}
+
+impl<'de> MapAccess<'de> for DictionaryAccess {
+    type Error = ValueError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K
+    ) -> Result<Option<K::Value>, ValueError> {
+        match self.keys.next() {
+            Some(key) => {
+                self.key = Some(key.clone());
+
+                seed.deserialize(KeyDeserializer(key))
+                    .map(Some)
+            },
+            None => Ok(None)
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V
+    ) -> Result<V::Value, ValueError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ValueError(String::from("Map value deserialized before its key.")))?;
+        let value = self
+            .dictionary
+            .get_value(&key)?
+            .unwrap_or_else(Value::new);
+
+        seed.deserialize(ValueDeserializer::new(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Provides access to the items of a list.
+struct ListAccess {
+    list:  ListValue,
+    index: usize,
+    len:   usize
+}
+
+impl ListAccess {
+    fn new(list: ListValue) -> Result<Self, ValueError> {
+        let len = list.len()?;
+
+        Ok(Self {
+            list,
+            index: 0,
+            len
+        })
+    }
+}
+
+impl<'de> SeqAccess<'de> for ListAccess {
+    type Error = ValueError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T
+    ) -> Result<Option<T::Value>, ValueError> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+
+        let value = self
+            .list
+            .get_value(self.index)?
+            .unwrap_or_else(Value::new);
+
+        self.index += 1;
+
+        seed.deserialize(ValueDeserializer::new(value))
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// Deserializes dictionary keys. Keys are always strings, so integer and
+/// boolean keys are parsed from their string representation.
+struct KeyDeserializer(String);
+
+/// Generates deserializer functions that parse the key.
+macro_rules! deserialize_key {
+    ($($name:ident => $ty:ty, $visit:ident;)*) => {
+        $(
+            fn $name<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+                match self.0.parse::<$ty>() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => visitor.visit_string(self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_enum(IntoDeserializer::<ValueError>::into_deserializer(self.0))
+    }
+
+    deserialize_key! {
+        deserialize_bool => bool, visit_bool;
+        deserialize_i8 => i8, visit_i8;
+        deserialize_i16 => i16, visit_i16;
+        deserialize_i32 => i32, visit_i32;
+        deserialize_i64 => i64, visit_i64;
+        deserialize_u8 => u8, visit_u8;
+        deserialize_u16 => u16, visit_u16;
+        deserialize_u32 => u32, visit_u32;
+        deserialize_u64 => u64, visit_u64;
+        deserialize_f32 => f32, visit_f32;
+        deserialize_f64 => f64, visit_f64;
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Provides access to an enum variant stored in a dictionary.
+struct EnumDeserializer {
+    variant: String,
+    value:   Value
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer {
+    type Error = ValueError;
+    type Variant = VariantDeserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V
+    ) -> Result<(V::Value, VariantDeserializer), ValueError> {
+        let variant = IntoDeserializer::<ValueError>::into_deserializer(self.variant);
+        let variant = seed.deserialize(variant)?;
+
+        Ok((variant, VariantDeserializer { value: self.value }))
+    }
+}
+
+/// Provides access to the data of an enum variant.
+struct VariantDeserializer {
+    value: Value
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer {
+    type Error = ValueError;
+
+    fn unit_variant(self) -> Result<(), ValueError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T
+    ) -> Result<T::Value, ValueError> {
+        seed.deserialize(ValueDeserializer::new(self.value))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        de::Deserializer::deserialize_seq(ValueDeserializer::new(self.value), visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value, ValueError> {
+        de::Deserializer::deserialize_map(ValueDeserializer::new(self.value), visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point {
+        x: i32,
+        y: i32
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(String);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(Point, Point),
+        Rect {
+            origin: Point,
+            width:  u32,
+            height: u32
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scene {
+        name:     String,
+        visible:  bool,
+        opacity:  f64,
+        id:       u64,
+        parent:   Option<u32>,
+        label:    Option<Wrapper>,
+        shapes:   Vec<Shape>,
+        pixels:   Vec<u8>,
+        tags:     HashMap<String, Vec<String>>,
+        counts:   HashMap<u32, i64>,
+        children: Vec<Scene>
+    }
+
+    /// Serializes |value| and deserializes it again.
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + DeserializeOwned
+    {
+        from_value(to_value(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn structs_are_dictionaries() {
+        let point = Point { x: 1, y: -2 };
+        let value = to_value(&point).unwrap();
+
+        assert_eq!(value.get_type().unwrap(), ValueType::Dictionary);
+
+        let dictionary = value
+            .get_dictionary()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(dictionary.get_int("x").unwrap(), 1);
+        assert_eq!(dictionary.get_int("y").unwrap(), -2);
+        assert_eq!(from_value::<Point>(value).unwrap(), point);
+    }
+
+    #[test]
+    fn sequences_are_lists() {
+        let items = vec![String::from("a"), String::from("b")];
+        let value = to_value(&items).unwrap();
+
+        assert_eq!(value.get_type().unwrap(), ValueType::List);
+
+        let list = value.get_list().unwrap().unwrap();
+
+        assert_eq!(list.len().unwrap(), 2);
+        assert_eq!(list.get_string(1).unwrap(), Some(String::from("b")));
+        assert_eq!(from_value::<Vec<String>>(value).unwrap(), items);
+
+        assert_eq!(round_trip(&vec![1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(
+            round_trip(&(1, String::from("two"), 3.5)),
+            (1, String::from("two"), 3.5)
+        );
+        assert_eq!(round_trip(&Vec::<i32>::new()), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn bytes_are_binary() {
+        let bytes: Vec<u8> = vec![0, 1, 2, 254, 255];
+        let value = to_value(&bytes).unwrap();
+
+        assert_eq!(value.get_type().unwrap(), ValueType::Binary);
+        assert_eq!(
+            value
+                .get_binary()
+                .unwrap()
+                .unwrap()
+                .get_size()
+                .unwrap(),
+            bytes.len()
+        );
+        assert_eq!(from_value::<Vec<u8>>(value).unwrap(), bytes);
+
+        // Byte slices serialized with serialize_bytes are binary values too.
+        let value = ser::Serializer::serialize_bytes(ValueSerializer, &bytes).unwrap();
+
+        assert_eq!(value.get_type().unwrap(), ValueType::Binary);
+        assert_eq!(from_value::<Vec<u8>>(value).unwrap(), bytes);
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(round_trip(&Some(5)), Some(5));
+        assert_eq!(round_trip(&None::<i32>), None);
+        assert_eq!(
+            to_value(&None::<i32>)
+                .unwrap()
+                .get_type()
+                .unwrap(),
+            ValueType::Null
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let shapes = vec![
+            Shape::Empty,
+            Shape::Circle(1.5),
+            Shape::Line(Point { x: 0, y: 0 }, Point { x: 3, y: 4 }),
+            Shape::Rect {
+                origin: Point { x: 1, y: 1 },
+                width:  10,
+                height: 20
+            },
+        ];
+
+        for shape in shapes {
+            assert_eq!(round_trip(&shape), shape);
+        }
+
+        assert_eq!(
+            to_value(&Shape::Empty)
+                .unwrap()
+                .get_string()
+                .unwrap(),
+            Some(String::from("Empty"))
+        );
+        assert_eq!(
+            round_trip(&Wrapper(String::from("newtype"))),
+            Wrapper(String::from("newtype"))
+        );
+    }
+
+    #[test]
+    fn large_integers() {
+        assert_eq!(round_trip(&u32::MAX), u32::MAX);
+        assert_eq!(round_trip(&i64::MIN), i64::MIN);
+        assert_eq!(round_trip(&(1u64 << 40)), 1u64 << 40);
+    }
+
+    #[test]
+    fn nested_values() {
+        let scene = Scene {
+            name:     String::from("root"),
+            visible:  true,
+            opacity:  0.5,
+            id:       1 << 33,
+            parent:   None,
+            label:    Some(Wrapper(String::from("label"))),
+            shapes:   vec![Shape::Circle(2.0), Shape::Empty],
+            pixels:   vec![255; 16],
+            tags:     HashMap::from([(
+                String::from("kind"),
+                vec![String::from("a"), String::from("b")]
+            )]),
+            counts:   HashMap::from([(7, -1), (8, 1 << 40)]),
+            children: vec![Scene {
+                name:     String::from("child"),
+                visible:  false,
+                opacity:  1.0,
+                id:       2,
+                parent:   Some(1),
+                label:    None,
+                shapes:   Vec::new(),
+                pixels:   Vec::new(),
+                tags:     HashMap::new(),
+                counts:   HashMap::new(),
+                children: Vec::new()
+            }]
+        };
+
+        assert_eq!(round_trip(&scene), scene);
+    }
+}
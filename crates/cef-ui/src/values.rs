@@ -878,3 +878,98 @@ impl PartialEq for ListValue {
             .unwrap_or_else(|_| false)
     }
 }
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<&Value> for serde_json::Value {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Value) -> Result<Self> {
+        let value = match value.get_type()? {
+            ValueType::Invalid | ValueType::Null => serde_json::Value::Null,
+            ValueType::Bool => serde_json::Value::Bool(value.get_bool()?),
+            ValueType::Int => serde_json::Value::from(value.get_int()?),
+            ValueType::Double => serde_json::Number::from_f64(value.get_double()?)
+                .map_or(serde_json::Value::Null, serde_json::Value::Number),
+            ValueType::String => match value.get_string()? {
+                Some(s) => serde_json::Value::String(s),
+                None => serde_json::Value::String(String::new())
+            },
+            ValueType::Binary => match value.get_binary()? {
+                Some(binary) => serde_json::Value::try_from(&binary)?,
+                None => serde_json::Value::Null
+            },
+            ValueType::Dictionary => match value.get_dictionary()? {
+                Some(dictionary) => serde_json::Value::try_from(&dictionary)?,
+                None => serde_json::Value::Null
+            },
+            ValueType::List => match value.get_list()? {
+                Some(list) => serde_json::Value::try_from(&list)?,
+                None => serde_json::Value::Null
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<Value> for serde_json::Value {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<&BinaryValue> for serde_json::Value {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &BinaryValue) -> Result<Self> {
+        let mut data = vec![0; value.get_size()?];
+
+        value.get_data(&mut data, 0)?;
+
+        Ok(serde_json::Value::from(data))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<&DictionaryValue> for serde_json::Value {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &DictionaryValue) -> Result<Self> {
+        let mut map = serde_json::Map::new();
+
+        for key in value.get_keys()? {
+            let item = match value.get_value(&key)? {
+                Some(item) => serde_json::Value::try_from(&item)?,
+                None => serde_json::Value::Null
+            };
+
+            map.insert(key, item);
+        }
+
+        Ok(serde_json::Value::Object(map))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<&ListValue> for serde_json::Value {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &ListValue) -> Result<Self> {
+        let mut items = Vec::new();
+
+        for index in 0..value.len()? {
+            let item = match value.get_value(index)? {
+                Some(item) => serde_json::Value::try_from(&item)?,
+                None => serde_json::Value::Null
+            };
+
+            items.push(item);
+        }
+
+        Ok(serde_json::Value::Array(items))
+    }
+}
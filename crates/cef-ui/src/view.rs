@@ -0,0 +1,719 @@
+use crate::{
+    ref_counted_ptr, try_c, BrowserView, Button, CefString, Color, Insets, Panel, Point, Rect,
+    RefCountedPtr, ScrollView, Size, Textfield, ThreadId, Window, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{cef_point_t, cef_rect_t, cef_size_t, cef_view_delegate_t, cef_view_t};
+use std::{ffi::c_int, mem::zeroed};
+
+/// Implement this structure to handle view events. All size and position
+/// values are in density independent pixels (DIP) unless otherwise indicated.
+/// The functions of this structure will be called on the browser process UI
+/// thread unless otherwise indicated.
+pub trait ViewDelegateCallbacks: Send + Sync + 'static {
+    /// Return the preferred size for |view|. The Layout will use this information
+    /// to determine the display size. Return None to use the default.
+    fn get_preferred_size(&mut self, view: View) -> Option<Size>;
+
+    /// Return the minimum size for |view|. Return None to use the default.
+    fn get_minimum_size(&mut self, view: View) -> Option<Size>;
+
+    /// Return the maximum size for |view|. Return None to use the default.
+    fn get_maximum_size(&mut self, view: View) -> Option<Size>;
+
+    /// Return the height necessary to display |view| with the provided |width|.
+    /// Return None to use the default.
+    fn get_height_for_width(&mut self, view: View, width: i32) -> Option<i32>;
+
+    /// Called when the parent of |view| has changed. If |view| is being added to
+    /// |parent| then |added| will be true (1). If |view| is being removed from
+    /// |parent| then |added| will be false (0). If |view| is being reparented the
+    /// remove notification will be sent before the add notification. Do not
+    /// modify the view hierarchy in this callback.
+    fn on_parent_view_changed(&mut self, view: View, added: bool, parent: View);
+
+    /// Called when a child of |view| has changed. If |child| is being added to
+    /// |view| then |added| will be true (1). If |child| is being removed from
+    /// |view| then |added| will be false (0). If |child| is being reparented the
+    /// remove notification will be sent to the old parent before the add
+    /// notification is sent to the new parent. Do not modify the view hierarchy
+    /// in this callback.
+    fn on_child_view_changed(&mut self, view: View, added: bool, child: View);
+
+    /// Called when |view| is added or removed from the cef_window_t.
+    fn on_window_changed(&mut self, view: View, added: bool);
+
+    /// Called when the layout of |view| has changed.
+    fn on_layout_changed(&mut self, view: View, new_bounds: &Rect);
+
+    /// Called when |view| gains focus.
+    fn on_focus(&mut self, view: View);
+
+    /// Called when |view| loses focus.
+    fn on_blur(&mut self, view: View);
+}
+
+// Implement this structure to handle view events. All size and position values
+// are in density independent pixels (DIP) unless otherwise indicated. The
+// functions of this structure will be called on the browser process UI thread
+// unless otherwise indicated.
+ref_counted_ptr!(ViewDelegate, cef_view_delegate_t);
+
+impl ViewDelegate {
+    pub fn new<C: ViewDelegateCallbacks>(delegate: C) -> Self {
+        Self(ViewDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct ViewDelegateWrapper(Box<dyn ViewDelegateCallbacks>);
+
+impl ViewDelegateWrapper {
+    pub fn new<C: ViewDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+}
+
+impl ViewDelegateWrappable for ViewDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for ViewDelegateWrapper {
+    type Cef = cef_view_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_view_delegate_t> {
+        RefCountedPtr::wrap(view_delegate::<Self>(), self)
+    }
+}
+
+/// Implemented by the wrappers of every view delegate so that the
+/// cef_view_delegate_t callbacks can be shared between them.
+pub(crate) trait ViewDelegateWrappable: Wrappable {
+    /// Returns the view delegate callbacks.
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks;
+}
+
+/// Returns a cef_view_delegate_t that forwards to the wrapper |W|. The wrapped
+/// CEF structure must start with a cef_view_delegate_t.
+pub(crate) fn view_delegate<W: ViewDelegateWrappable>() -> cef_view_delegate_t {
+    cef_view_delegate_t {
+        base:                   unsafe { zeroed() },
+        get_preferred_size:     Some(c_get_preferred_size::<W>),
+        get_minimum_size:       Some(c_get_minimum_size::<W>),
+        get_maximum_size:       Some(c_get_maximum_size::<W>),
+        get_height_for_width:   Some(c_get_height_for_width::<W>),
+        on_parent_view_changed: Some(c_on_parent_view_changed::<W>),
+        on_child_view_changed:  Some(c_on_child_view_changed::<W>),
+        on_window_changed:      Some(c_on_window_changed::<W>),
+        on_layout_changed:      Some(c_on_layout_changed::<W>),
+        on_focus:               Some(c_on_focus::<W>),
+        on_blur:                Some(c_on_blur::<W>)
+    }
+}
+
+/// Return the preferred size for |view|. The Layout will use this information
+/// to determine the display size.
+unsafe extern "C" fn c_get_preferred_size<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t
+) -> cef_size_t {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate()
+        .get_preferred_size(view)
+        .map(|size| size.into())
+        .unwrap_or_else(|| zeroed())
+}
+
+/// Return the minimum size for |view|.
+unsafe extern "C" fn c_get_minimum_size<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t
+) -> cef_size_t {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate()
+        .get_minimum_size(view)
+        .map(|size| size.into())
+        .unwrap_or_else(|| zeroed())
+}
+
+/// Return the maximum size for |view|.
+unsafe extern "C" fn c_get_maximum_size<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t
+) -> cef_size_t {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate()
+        .get_maximum_size(view)
+        .map(|size| size.into())
+        .unwrap_or_else(|| zeroed())
+}
+
+/// Return the height necessary to display |view| with the provided |width|. If
+/// not specified the result of get_preferred_size().height will be used by
+/// default. Override if |view|'s preferred height depends upon the width (for
+/// example, with Labels).
+unsafe extern "C" fn c_get_height_for_width<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t,
+    width: c_int
+) -> c_int {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate()
+        .get_height_for_width(view, width as i32)
+        .unwrap_or(0) as c_int
+}
+
+/// Called when the parent of |view| has changed.
+unsafe extern "C" fn c_on_parent_view_changed<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t,
+    added: c_int,
+    parent: *mut cef_view_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+    let parent = View::from_ptr_unchecked(parent);
+
+    this.view_delegate()
+        .on_parent_view_changed(view, added != 0, parent);
+}
+
+/// Called when a child of |view| has changed.
+unsafe extern "C" fn c_on_child_view_changed<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t,
+    added: c_int,
+    child: *mut cef_view_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+    let child = View::from_ptr_unchecked(child);
+
+    this.view_delegate()
+        .on_child_view_changed(view, added != 0, child);
+}
+
+/// Called when |view| is added or removed from the cef_window_t.
+unsafe extern "C" fn c_on_window_changed<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t,
+    added: c_int
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate()
+        .on_window_changed(view, added != 0);
+}
+
+/// Called when the layout of |view| has changed.
+unsafe extern "C" fn c_on_layout_changed<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t,
+    new_bounds: *const cef_rect_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+    let new_bounds: Rect = (*new_bounds).into();
+
+    this.view_delegate()
+        .on_layout_changed(view, &new_bounds);
+}
+
+/// Called when |view| gains focus.
+unsafe extern "C" fn c_on_focus<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate().on_focus(view);
+}
+
+/// Called when |view| loses focus.
+unsafe extern "C" fn c_on_blur<W: ViewDelegateWrappable>(
+    this: *mut cef_view_delegate_t,
+    view: *mut cef_view_t
+) {
+    let this: &mut W = Wrapped::wrappable(this.cast());
+    let view = View::from_ptr_unchecked(view);
+
+    this.view_delegate().on_blur(view);
+}
+
+/// Implements Deref from a view type to the view type that it extends. Views
+/// are single inheritance hierarchies in CEF, so a pointer to a derived view
+/// structure is also a valid pointer to its base structure.
+#[macro_export]
+macro_rules! view_deref {
+    ($derived:ty, $base:ty) => {
+        impl std::ops::Deref for $derived {
+            type Target = $base;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*(self as *const Self as *const Self::Target) }
+            }
+        }
+    };
+}
+
+// A View is a rectangle within the views View hierarchy. It is the base
+// structure for all Views. All size and position values are in density
+// independent pixels (DIP) unless otherwise indicated. Methods must be called
+// on the browser process UI thread unless otherwise indicated.
+ref_counted_ptr!(View, cef_view_t);
+
+impl View {
+    /// Returns this View as a BrowserView or NULL if this is not a BrowserView.
+    pub fn as_browser_view(&self) -> Result<Option<BrowserView>> {
+        try_c!(self, as_browser_view, ThreadId::Ui, {
+            Ok(BrowserView::from_ptr(as_browser_view(self.as_ptr())))
+        })
+    }
+
+    /// Returns this View as a Button or NULL if this is not a Button.
+    pub fn as_button(&self) -> Result<Option<Button>> {
+        try_c!(self, as_button, ThreadId::Ui, {
+            Ok(Button::from_ptr(as_button(self.as_ptr())))
+        })
+    }
+
+    /// Returns this View as a Panel or NULL if this is not a Panel.
+    pub fn as_panel(&self) -> Result<Option<Panel>> {
+        try_c!(self, as_panel, ThreadId::Ui, {
+            Ok(Panel::from_ptr(as_panel(self.as_ptr())))
+        })
+    }
+
+    /// Returns this View as a ScrollView or NULL if this is not a ScrollView.
+    pub fn as_scroll_view(&self) -> Result<Option<ScrollView>> {
+        try_c!(self, as_scroll_view, ThreadId::Ui, {
+            Ok(ScrollView::from_ptr(as_scroll_view(self.as_ptr())))
+        })
+    }
+
+    /// Returns this View as a Textfield or NULL if this is not a Textfield.
+    pub fn as_textfield(&self) -> Result<Option<Textfield>> {
+        try_c!(self, as_textfield, ThreadId::Ui, {
+            Ok(Textfield::from_ptr(as_textfield(self.as_ptr())))
+        })
+    }
+
+    /// Returns the type of this View as a string. Used primarily for testing
+    /// purposes.
+    pub fn get_type_string(&self) -> Result<String> {
+        try_c!(self, get_type_string, ThreadId::Ui, {
+            let s = get_type_string(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Returns a string representation of this View which includes the type and
+    /// various type-specific identifying attributes. If |include_children| is
+    /// true (1) any child Views will also be included. Used primarily for testing
+    /// purposes.
+    pub fn to_string(&self, include_children: bool) -> Result<String> {
+        try_c!(self, to_string, ThreadId::Ui, {
+            let s = to_string(self.as_ptr(), include_children as c_int);
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Returns true (1) if this View is valid.
+    pub fn is_valid(&self) -> Result<bool> {
+        try_c!(self, is_valid, ThreadId::Ui, {
+            Ok(is_valid(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if this View is currently attached to another View. A
+    /// View can only be attached to one View at a time.
+    pub fn is_attached(&self) -> Result<bool> {
+        try_c!(self, is_attached, ThreadId::Ui, {
+            Ok(is_attached(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if this View is the same as |that| View.
+    pub fn is_same(&self, that: View) -> Result<bool> {
+        try_c!(self, is_same, ThreadId::Ui, {
+            Ok(is_same(self.as_ptr(), that.into_raw()) != 0)
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Returns the delegate associated with this View, if any.
+    // ///
+    // struct _cef_view_delegate_t*(CEF_CALLBACK* get_delegate)(
+    // struct _cef_view_t* self);
+
+    /// Returns the top-level Window hosting this View, if any.
+    pub fn get_window(&self) -> Result<Option<Window>> {
+        try_c!(self, get_window, ThreadId::Ui, {
+            Ok(Window::from_ptr(get_window(self.as_ptr())))
+        })
+    }
+
+    /// Returns the ID for this View.
+    pub fn get_id(&self) -> Result<i32> {
+        try_c!(self, get_id, ThreadId::Ui, {
+            Ok(get_id(self.as_ptr()) as i32)
+        })
+    }
+
+    /// Sets the ID for this View. ID should be unique within the subtree that you
+    /// intend to search for it. 0 is the default ID for views.
+    pub fn set_id(&self, id: i32) -> Result<()> {
+        try_c!(self, set_id, ThreadId::Ui, {
+            Ok(set_id(self.as_ptr(), id as c_int))
+        })
+    }
+
+    /// Returns the group id of this View, or -1 if not set.
+    pub fn get_group_id(&self) -> Result<i32> {
+        try_c!(self, get_group_id, ThreadId::Ui, {
+            Ok(get_group_id(self.as_ptr()) as i32)
+        })
+    }
+
+    /// A group id is used to tag Views which are part of the same logical group.
+    /// Focus can be moved between views with the same group using the arrow keys.
+    /// The group id is immutable once it's set.
+    pub fn set_group_id(&self, group_id: i32) -> Result<()> {
+        try_c!(self, set_group_id, ThreadId::Ui, {
+            Ok(set_group_id(self.as_ptr(), group_id as c_int))
+        })
+    }
+
+    /// Returns the View that contains this View, if any.
+    pub fn get_parent_view(&self) -> Result<Option<View>> {
+        try_c!(self, get_parent_view, ThreadId::Ui, {
+            Ok(View::from_ptr(get_parent_view(self.as_ptr())))
+        })
+    }
+
+    /// Recursively descends the view tree starting at this View, and returns the
+    /// first child that it encounters with the given ID. Returns NULL if no
+    /// matching child view is found.
+    pub fn get_view_for_id(&self, id: i32) -> Result<Option<View>> {
+        try_c!(self, get_view_for_id, ThreadId::Ui, {
+            Ok(View::from_ptr(get_view_for_id(self.as_ptr(), id as c_int)))
+        })
+    }
+
+    /// Sets the bounds (size and position) of this View. |bounds| is in parent
+    /// coordinates, or DIP screen coordinates if there is no parent.
+    pub fn set_bounds(&self, bounds: &Rect) -> Result<()> {
+        try_c!(self, set_bounds, ThreadId::Ui, {
+            let bounds: cef_rect_t = bounds.into();
+
+            Ok(set_bounds(self.as_ptr(), &bounds))
+        })
+    }
+
+    /// Returns the bounds (size and position) of this View in parent coordinates,
+    /// or DIP screen coordinates if there is no parent.
+    pub fn get_bounds(&self) -> Result<Rect> {
+        try_c!(self, get_bounds, ThreadId::Ui, {
+            Ok(get_bounds(self.as_ptr()).into())
+        })
+    }
+
+    /// Returns the bounds (size and position) of this View in DIP screen
+    /// coordinates.
+    pub fn get_bounds_in_screen(&self) -> Result<Rect> {
+        try_c!(self, get_bounds_in_screen, ThreadId::Ui, {
+            Ok(get_bounds_in_screen(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the size of this View without changing the position. |size| in
+    /// parent coordinates, or DIP screen coordinates if there is no parent.
+    pub fn set_size(&self, size: &Size) -> Result<()> {
+        try_c!(self, set_size, ThreadId::Ui, {
+            let size: cef_size_t = size.into();
+
+            Ok(set_size(self.as_ptr(), &size))
+        })
+    }
+
+    /// Returns the size of this View in parent coordinates, or DIP screen
+    /// coordinates if there is no parent.
+    pub fn get_size(&self) -> Result<Size> {
+        try_c!(self, get_size, ThreadId::Ui, {
+            Ok(get_size(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the position of this View without changing the size. |position| is
+    /// in parent coordinates, or DIP screen coordinates if there is no parent.
+    pub fn set_position(&self, position: &Point) -> Result<()> {
+        try_c!(self, set_position, ThreadId::Ui, {
+            let position: cef_point_t = position.into();
+
+            Ok(set_position(self.as_ptr(), &position))
+        })
+    }
+
+    /// Returns the position of this View. Position is in parent coordinates, or
+    /// DIP screen coordinates if there is no parent.
+    pub fn get_position(&self) -> Result<Point> {
+        try_c!(self, get_position, ThreadId::Ui, {
+            Ok(get_position(self.as_ptr()).into())
+        })
+    }
+
+    /// Sets the insets for this View. |insets| is in parent coordinates, or DIP
+    /// screen coordinates if there is no parent.
+    pub fn set_insets(&self, insets: &Insets) -> Result<()> {
+        try_c!(self, set_insets, ThreadId::Ui, {
+            let insets = insets.into();
+
+            Ok(set_insets(self.as_ptr(), &insets))
+        })
+    }
+
+    /// Returns the insets for this View in parent coordinates, or DIP screen
+    /// coordinates if there is no parent.
+    pub fn get_insets(&self) -> Result<Insets> {
+        try_c!(self, get_insets, ThreadId::Ui, {
+            Ok(get_insets(self.as_ptr()).into())
+        })
+    }
+
+    /// Returns the size this View would like to be if enough space is available.
+    /// Size is in parent coordinates, or DIP screen coordinates if there is no
+    /// parent.
+    pub fn get_preferred_size(&self) -> Result<Size> {
+        try_c!(self, get_preferred_size, ThreadId::Ui, {
+            Ok(get_preferred_size(self.as_ptr()).into())
+        })
+    }
+
+    /// Size this View to its preferred size. Size is in parent coordinates, or
+    /// DIP screen coordinates if there is no parent.
+    pub fn size_to_preferred_size(&self) -> Result<()> {
+        try_c!(self, size_to_preferred_size, ThreadId::Ui, {
+            Ok(size_to_preferred_size(self.as_ptr()))
+        })
+    }
+
+    /// Returns the minimum size for this View. Size is in parent coordinates, or
+    /// DIP screen coordinates if there is no parent.
+    pub fn get_minimum_size(&self) -> Result<Size> {
+        try_c!(self, get_minimum_size, ThreadId::Ui, {
+            Ok(get_minimum_size(self.as_ptr()).into())
+        })
+    }
+
+    /// Returns the maximum size for this View. Size is in parent coordinates, or
+    /// DIP screen coordinates if there is no parent.
+    pub fn get_maximum_size(&self) -> Result<Size> {
+        try_c!(self, get_maximum_size, ThreadId::Ui, {
+            Ok(get_maximum_size(self.as_ptr()).into())
+        })
+    }
+
+    /// Returns the height necessary to display this View with the provided width.
+    pub fn get_height_for_width(&self, width: i32) -> Result<i32> {
+        try_c!(self, get_height_for_width, ThreadId::Ui, {
+            Ok(get_height_for_width(self.as_ptr(), width as c_int) as i32)
+        })
+    }
+
+    /// Indicate that this View and all parent Views require a re-layout. This
+    /// ensures the next call to layout() will propagate to this View even if the
+    /// bounds of parent Views do not change.
+    pub fn invalidate_layout(&self) -> Result<()> {
+        try_c!(self, invalidate_layout, ThreadId::Ui, {
+            Ok(invalidate_layout(self.as_ptr()))
+        })
+    }
+
+    /// Sets whether this View is visible. Windows are hidden by default and other
+    /// views are visible by default. This View and any parent views must be set
+    /// as visible for this View to be drawn in a Window. If this View is set as
+    /// hidden then it and any child views will not be drawn and, if any of those
+    /// views currently have focus, then focus will also be cleared. Painting is
+    /// scheduled as needed. If this View is a Window then calling this function
+    /// is equivalent to calling the Window show() and hide() functions.
+    pub fn set_visible(&self, visible: bool) -> Result<()> {
+        try_c!(self, set_visible, ThreadId::Ui, {
+            Ok(set_visible(self.as_ptr(), visible as c_int))
+        })
+    }
+
+    /// Returns whether this View is visible. A view may be visible but still not
+    /// drawn in a Window if any parent views are hidden. If this View is a Window
+    /// then a return value of true (1) indicates that this Window is currently
+    /// visible to the user on-screen. If this View is not a Window then call
+    /// is_drawn() to determine whether this View and all parent views are visible
+    /// and will be drawn.
+    pub fn is_visible(&self) -> Result<bool> {
+        try_c!(self, is_visible, ThreadId::Ui, {
+            Ok(is_visible(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns whether this View is visible and drawn in a Window. A view is
+    /// drawn if it and all parent views are visible. If this View is a Window
+    /// then calling this function is equivalent to calling is_visible().
+    /// Otherwise, to determine if the containing Window is visible to the user
+    /// on-screen call is_visible() on the Window.
+    pub fn is_drawn(&self) -> Result<bool> {
+        try_c!(self, is_drawn, ThreadId::Ui, {
+            Ok(is_drawn(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Set whether this View is enabled. A disabled View does not receive
+    /// keyboard or mouse inputs. If |enabled| differs from the current value the
+    /// View will be repainted. Also, clears focus if the focused View is
+    /// disabled.
+    pub fn set_enabled(&self, enabled: bool) -> Result<()> {
+        try_c!(self, set_enabled, ThreadId::Ui, {
+            Ok(set_enabled(self.as_ptr(), enabled as c_int))
+        })
+    }
+
+    /// Returns whether this View is enabled.
+    pub fn is_enabled(&self) -> Result<bool> {
+        try_c!(self, is_enabled, ThreadId::Ui, {
+            Ok(is_enabled(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Sets whether this View is capable of taking focus. It will clear focus if
+    /// the focused View is set to be non-focusable. This is false (0) by default
+    /// so that a View used as a container does not get the focus.
+    pub fn set_focusable(&self, focusable: bool) -> Result<()> {
+        try_c!(self, set_focusable, ThreadId::Ui, {
+            Ok(set_focusable(self.as_ptr(), focusable as c_int))
+        })
+    }
+
+    /// Returns true (1) if this View is focusable, enabled and drawn.
+    pub fn is_focusable(&self) -> Result<bool> {
+        try_c!(self, is_focusable, ThreadId::Ui, {
+            Ok(is_focusable(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Return whether this View is focusable when the user requires full keyboard
+    /// access, even though it may not be normally focusable.
+    pub fn is_accessibility_focusable(&self) -> Result<bool> {
+        try_c!(self, is_accessibility_focusable, ThreadId::Ui, {
+            Ok(is_accessibility_focusable(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Request keyboard focus. If this View is focusable it will become the
+    /// focused View.
+    pub fn request_focus(&self) -> Result<()> {
+        try_c!(self, request_focus, ThreadId::Ui, {
+            Ok(request_focus(self.as_ptr()))
+        })
+    }
+
+    /// Sets the background color for this View.
+    pub fn set_background_color(&self, color: &Color) -> Result<()> {
+        try_c!(self, set_background_color, ThreadId::Ui, {
+            Ok(set_background_color(self.as_ptr(), color.into()))
+        })
+    }
+
+    /// Returns the background color for this View.
+    pub fn get_background_color(&self) -> Result<Color> {
+        try_c!(self, get_background_color, ThreadId::Ui, {
+            Ok(get_background_color(self.as_ptr()).into())
+        })
+    }
+
+    /// Convert |point| from this View's coordinate system to DIP screen
+    /// coordinates. This View must belong to a Window when calling this function.
+    /// Returns None if the conversion fails.
+    pub fn convert_point_to_screen(&self, point: &Point) -> Result<Option<Point>> {
+        try_c!(self, convert_point_to_screen, ThreadId::Ui, {
+            let mut point: cef_point_t = point.into();
+
+            Ok((convert_point_to_screen(self.as_ptr(), &mut point) != 0).then(|| point.into()))
+        })
+    }
+
+    /// Convert |point| to this View's coordinate system from DIP screen
+    /// coordinates. This View must belong to a Window when calling this function.
+    /// Returns None if the conversion fails.
+    pub fn convert_point_from_screen(&self, point: &Point) -> Result<Option<Point>> {
+        try_c!(self, convert_point_from_screen, ThreadId::Ui, {
+            let mut point: cef_point_t = point.into();
+
+            Ok((convert_point_from_screen(self.as_ptr(), &mut point) != 0).then(|| point.into()))
+        })
+    }
+
+    /// Convert |point| from this View's coordinate system to that of the Window.
+    /// This View must belong to a Window when calling this function. Returns None
+    /// if the conversion fails.
+    pub fn convert_point_to_window(&self, point: &Point) -> Result<Option<Point>> {
+        try_c!(self, convert_point_to_window, ThreadId::Ui, {
+            let mut point: cef_point_t = point.into();
+
+            Ok((convert_point_to_window(self.as_ptr(), &mut point) != 0).then(|| point.into()))
+        })
+    }
+
+    /// Convert |point| to this View's coordinate system from that of the Window.
+    /// This View must belong to a Window when calling this function. Returns None
+    /// if the conversion fails.
+    pub fn convert_point_from_window(&self, point: &Point) -> Result<Option<Point>> {
+        try_c!(self, convert_point_from_window, ThreadId::Ui, {
+            let mut point: cef_point_t = point.into();
+
+            Ok((convert_point_from_window(self.as_ptr(), &mut point) != 0).then(|| point.into()))
+        })
+    }
+
+    /// Convert |point| from this View's coordinate system to that |view|. |view|
+    /// needs to be in the same Window but not necessarily the same view
+    /// hierarchy. Returns None if the conversion fails.
+    pub fn convert_point_to_view(&self, view: View, point: &Point) -> Result<Option<Point>> {
+        try_c!(self, convert_point_to_view, ThreadId::Ui, {
+            let mut point: cef_point_t = point.into();
+
+            Ok(
+                (convert_point_to_view(self.as_ptr(), view.into_raw(), &mut point) != 0)
+                    .then(|| point.into())
+            )
+        })
+    }
+
+    /// Convert |point| to this View's coordinate system from that |view|. |view|
+    /// needs to be in the same Window but not necessarily the same view
+    /// hierarchy. Returns None if the conversion fails.
+    pub fn convert_point_from_view(&self, view: View, point: &Point) -> Result<Option<Point>> {
+        try_c!(self, convert_point_from_view, ThreadId::Ui, {
+            let mut point: cef_point_t = point.into();
+
+            Ok(
+                (convert_point_from_view(self.as_ptr(), view.into_raw(), &mut point) != 0)
+                    .then(|| point.into())
+            )
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{ref_counted_ptr, try_c};
+use anyhow::Result;
+use cef_ui_sys::{cef_waitable_event_create, cef_waitable_event_t};
+use std::{ffi::c_int, time::Duration};
+
+// WaitableEvent is a thread synchronization tool that allows one thread to wait
+// for another thread to finish some work. This is equivalent to using a
+// Lock+ConditionVariable to protect a simple boolean value. However, using
+// WaitableEvent in conjunction with a Lock to wait for a more complex state
+// change (e.g., for an item to be added to a queue) is not recommended. In that
+// case consider using a ConditionVariable instead of a WaitableEvent. It is
+// safe to create and/or signal a WaitableEvent from any thread. Blocking on a
+// WaitableEvent by calling the *wait() functions is not allowed on the browser
+// process UI or IO threads.
+ref_counted_ptr!(WaitableEvent, cef_waitable_event_t);
+
+impl WaitableEvent {
+    /// Create a new waitable event. If |automatic_reset| is true (1) then the
+    /// event state is automatically reset to un-signaled after a single waiting
+    /// thread has been released; otherwise, the state remains signaled until
+    /// reset() is called manually. If |initially_signaled| is true (1) then the
+    /// event will start in the signaled state.
+    pub fn new(automatic_reset: bool, initially_signaled: bool) -> Self {
+        unsafe {
+            Self::from_ptr_unchecked(cef_waitable_event_create(
+                automatic_reset as c_int,
+                initially_signaled as c_int
+            ))
+        }
+    }
+
+    /// Put the event in the un-signaled state.
+    pub fn reset(&self) -> Result<()> {
+        try_c!(self, reset, { Ok(reset(self.as_ptr())) })
+    }
+
+    /// Put the event in the signaled state. This causes any thread blocked on
+    /// Wait to be woken up.
+    pub fn signal(&self) -> Result<()> {
+        try_c!(self, signal, { Ok(signal(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if the event is in the signaled state, else false (0). If
+    /// the event was created with |automatic_reset| set to true (1) then calling
+    /// this function will also cause a reset.
+    pub fn is_signaled(&self) -> Result<bool> {
+        try_c!(self, is_signaled, { Ok(is_signaled(self.as_ptr()) != 0) })
+    }
+
+    /// Wait indefinitely for the event to be signaled. This function will not
+    /// return until after the call to signal() has completed. This function
+    /// cannot be called on the browser process UI or IO threads.
+    pub fn wait(&self) -> Result<()> {
+        try_c!(self, wait, { Ok(wait(self.as_ptr())) })
+    }
+
+    /// Wait up to |timeout| for the event to be signaled. Returns true (1) if the
+    /// event was signaled. A return value of false (0) does not necessarily mean
+    /// that |timeout| was exceeded. This function will not return until after
+    /// the call to signal() has completed. This function cannot be called on the
+    /// browser process UI or IO threads.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<bool> {
+        try_c!(self, timed_wait, {
+            let max_ms = i64::try_from(timeout.as_millis()).unwrap_or(i64::MAX);
+
+            Ok(timed_wait(self.as_ptr(), max_ms) != 0)
+        })
+    }
+}
@@ -0,0 +1,850 @@
+use crate::{
+    ref_counted_ptr, try_c, view_delegate, view_deref, BrowserView, CefString, Display,
+    DockingMode, EventFlags, Image, KeyEvent, MenuAnchorPosition, MenuModel, MouseButtonType,
+    NativeWindowHandle, OverlayController, Panel, Point, Rect, RefCountedPtr, ShowState, Size,
+    ThreadId, View, ViewDelegateCallbacks, ViewDelegateWrappable, Wrappable, Wrapped
+};
+use anyhow::Result;
+use cef_ui_sys::{
+    cef_key_event_t, cef_panel_delegate_t, cef_point_t, cef_rect_t, cef_show_state_t, cef_size_t,
+    cef_window_create_top_level, cef_window_delegate_t, cef_window_t
+};
+use std::{ffi::c_int, mem::zeroed, ptr::null_mut};
+
+/// Implement this structure to handle window events. The functions of this
+/// structure will be called on the browser process UI thread unless otherwise
+/// indicated.
+pub trait WindowDelegateCallbacks: ViewDelegateCallbacks {
+    /// Called when |window| is created.
+    fn on_window_created(&mut self, window: Window);
+
+    /// Called when |window| is closing.
+    fn on_window_closing(&mut self, window: Window);
+
+    /// Called when |window| is destroyed. Release all references to |window| and
+    /// do not attempt to execute any functions on |window| after this callback
+    /// returns.
+    fn on_window_destroyed(&mut self, window: Window);
+
+    /// Called when |window| is activated or deactivated.
+    fn on_window_activation_changed(&mut self, window: Window, active: bool);
+
+    /// Called when |window| bounds have changed. |new_bounds| will be in DIP
+    /// screen coordinates.
+    fn on_window_bounds_changed(&mut self, window: Window, new_bounds: &Rect);
+
+    /// Called when |window| is transitioning to or from fullscreen mode. On MacOS
+    /// the transition occurs asynchronously with |is_competed| set to false (0)
+    /// when the transition starts and true (1) after the transition completes. On
+    /// other platforms the transition occurs synchronously with |is_completed|
+    /// set to true (1) after the transition completes. With the Alloy runtime you
+    /// must also implement cef_display_handler_t::OnFullscreenModeChange to
+    /// handle fullscreen transitions initiated by browser content.
+    fn on_window_fullscreen_transition(&mut self, window: Window, is_completed: bool);
+
+    /// Return the parent for |window| or None if the |window| does not have a
+    /// parent. Windows with parents will not get a taskbar button. Set |is_menu|
+    /// to true (1) if |window| will be displayed as a menu, in which case it will
+    /// not be clipped to the parent window bounds. Set |can_activate_menu| to
+    /// false (0) if |is_menu| is true (1) and |window| should not be activated
+    /// (given keyboard focus) when displayed.
+    fn get_parent_window(
+        &mut self,
+        window: Window,
+        is_menu: &mut bool,
+        can_activate_menu: &mut bool
+    ) -> Option<Window>;
+
+    /// Returns true (1) if |window| should be created as a window modal dialog.
+    /// Only called when a Window is returned via get_parent_window() with
+    /// |is_menu| set to false (0). All controls in the parent Window will be
+    /// disabled while |window| is visible. This functionality is not supported
+    /// by all Linux window managers. Alternately, use
+    /// cef_window_t::show_as_browser_modal_dialog() for a browser modal dialog
+    /// that works on all platforms.
+    fn is_window_modal_dialog(&mut self, window: Window) -> bool;
+
+    /// Return the initial bounds for |window| in density independent pixel (DIP)
+    /// coordinates. If this function returns None then the window will be
+    /// sized to the preferred size of its contents and centered on the primary
+    /// display.
+    fn get_initial_bounds(&mut self, window: Window) -> Option<Rect>;
+
+    /// Return the initial show state for |window|.
+    fn get_initial_show_state(&mut self, window: Window) -> ShowState;
+
+    /// Return true (1) if |window| should be created without a frame or title
+    /// bar. The window will be resizable if can_resize() returns true (1). Use
+    /// cef_window_t::set_draggable_regions() to specify draggable regions.
+    fn is_frameless(&mut self, window: Window) -> bool;
+
+    /// Return true (1) if |window| should be created with standard window buttons
+    /// like close, minimize and zoom. This function is only supported on macOS.
+    fn with_standard_window_buttons(&mut self, window: Window) -> bool;
+
+    /// Return the titlebar height in DIPs for |window|, or None to use the
+    /// default. This function is only supported on macOS.
+    fn get_titlebar_height(&mut self, window: Window) -> Option<f32>;
+
+    /// Return true (1) if |window| can be resized.
+    fn can_resize(&mut self, window: Window) -> bool;
+
+    /// Return true (1) if |window| can be maximized.
+    fn can_maximize(&mut self, window: Window) -> bool;
+
+    /// Return true (1) if |window| can be minimized.
+    fn can_minimize(&mut self, window: Window) -> bool;
+
+    /// Return true (1) if |window| can be closed. This will be called for user-
+    /// initiated window close actions and when cef_window_t::close() is called.
+    fn can_close(&mut self, window: Window) -> bool;
+
+    /// Called when a keyboard accelerator registered with
+    /// cef_window_t::SetAccelerator is triggered. Return true (1) if the
+    /// accelerator was handled or false (0) otherwise.
+    fn on_accelerator(&mut self, window: Window, command_id: i32) -> bool;
+
+    /// Called after all other controls in the window have had a chance to handle
+    /// the event. |event| contains information about the keyboard event. Return
+    /// true (1) if the keyboard event was handled or false (0) otherwise.
+    fn on_key_event(&mut self, window: Window, event: KeyEvent) -> bool;
+}
+
+// Implement this structure to handle window events. The functions of this
+// structure will be called on the browser process UI thread unless otherwise
+// indicated.
+ref_counted_ptr!(WindowDelegate, cef_window_delegate_t);
+
+impl WindowDelegate {
+    pub fn new<C: WindowDelegateCallbacks>(delegate: C) -> Self {
+        Self(WindowDelegateWrapper::new(delegate).wrap())
+    }
+}
+
+/// Translates CEF -> Rust callbacks.
+struct WindowDelegateWrapper(Box<dyn WindowDelegateCallbacks>);
+
+impl WindowDelegateWrapper {
+    pub fn new<C: WindowDelegateCallbacks>(delegate: C) -> Self {
+        Self(Box::new(delegate))
+    }
+
+    /// Called when |window| is created.
+    unsafe extern "C" fn c_on_window_created(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.on_window_created(window);
+    }
+
+    /// Called when |window| is closing.
+    unsafe extern "C" fn c_on_window_closing(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.on_window_closing(window);
+    }
+
+    /// Called when |window| is destroyed. Release all references to |window| and
+    /// do not attempt to execute any functions on |window| after this callback
+    /// returns.
+    unsafe extern "C" fn c_on_window_destroyed(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.on_window_destroyed(window);
+    }
+
+    /// Called when |window| is activated or deactivated.
+    unsafe extern "C" fn c_on_window_activation_changed(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        active: c_int
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .on_window_activation_changed(window, active != 0);
+    }
+
+    /// Called when |window| bounds have changed. |new_bounds| will be in DIP
+    /// screen coordinates.
+    unsafe extern "C" fn c_on_window_bounds_changed(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        new_bounds: *const cef_rect_t
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+        let new_bounds: Rect = (*new_bounds).into();
+
+        this.0
+            .on_window_bounds_changed(window, &new_bounds);
+    }
+
+    /// Called when |window| is transitioning to or from fullscreen mode.
+    unsafe extern "C" fn c_on_window_fullscreen_transition(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        is_completed: c_int
+    ) {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .on_window_fullscreen_transition(window, is_completed != 0);
+    }
+
+    /// Return the parent for |window| or NULL if the |window| does not have a
+    /// parent.
+    unsafe extern "C" fn c_get_parent_window(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        is_menu: *mut c_int,
+        can_activate_menu: *mut c_int
+    ) -> *mut cef_window_t {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+        let mut local_is_menu = *is_menu != 0;
+        let mut local_can_activate_menu = *can_activate_menu != 0;
+
+        let ret =
+            this.0
+                .get_parent_window(window, &mut local_is_menu, &mut local_can_activate_menu);
+
+        *is_menu = local_is_menu as c_int;
+        *can_activate_menu = local_can_activate_menu as c_int;
+
+        ret.map(|window| window.into_raw())
+            .unwrap_or_else(null_mut)
+    }
+
+    /// Returns true (1) if |window| should be created as a window modal dialog.
+    unsafe extern "C" fn c_is_window_modal_dialog(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .is_window_modal_dialog(window) as c_int
+    }
+
+    /// Return the initial bounds for |window| in density independent pixel (DIP)
+    /// coordinates.
+    unsafe extern "C" fn c_get_initial_bounds(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> cef_rect_t {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .get_initial_bounds(window)
+            .map(|bounds| bounds.into())
+            .unwrap_or_else(|| zeroed())
+    }
+
+    /// Return the initial show state for |window|.
+    unsafe extern "C" fn c_get_initial_show_state(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> cef_show_state_t {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .get_initial_show_state(window)
+            .into()
+    }
+
+    /// Return true (1) if |window| should be created without a frame or title
+    /// bar.
+    unsafe extern "C" fn c_is_frameless(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.is_frameless(window) as c_int
+    }
+
+    /// Return true (1) if |window| should be created with standard window buttons
+    /// like close, minimize and zoom.
+    unsafe extern "C" fn c_with_standard_window_buttons(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .with_standard_window_buttons(window) as c_int
+    }
+
+    /// Return whether the titlebar height should be overridden, and sets the
+    /// height of the titlebar in |titlebar_height|.
+    unsafe extern "C" fn c_get_titlebar_height(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        titlebar_height: *mut f32
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        match this.0.get_titlebar_height(window) {
+            Some(height) => {
+                *titlebar_height = height;
+
+                1
+            },
+            None => 0
+        }
+    }
+
+    /// Return true (1) if |window| can be resized.
+    unsafe extern "C" fn c_can_resize(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.can_resize(window) as c_int
+    }
+
+    /// Return true (1) if |window| can be maximized.
+    unsafe extern "C" fn c_can_maximize(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.can_maximize(window) as c_int
+    }
+
+    /// Return true (1) if |window| can be minimized.
+    unsafe extern "C" fn c_can_minimize(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.can_minimize(window) as c_int
+    }
+
+    /// Return true (1) if |window| can be closed.
+    unsafe extern "C" fn c_can_close(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0.can_close(window) as c_int
+    }
+
+    /// Called when a keyboard accelerator registered with
+    /// cef_window_t::SetAccelerator is triggered.
+    unsafe extern "C" fn c_on_accelerator(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        command_id: c_int
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+
+        this.0
+            .on_accelerator(window, command_id as i32) as c_int
+    }
+
+    /// Called after all other controls in the window have had a chance to handle
+    /// the event.
+    unsafe extern "C" fn c_on_key_event(
+        this: *mut cef_window_delegate_t,
+        window: *mut cef_window_t,
+        event: *const cef_key_event_t
+    ) -> c_int {
+        let this: &mut Self = Wrapped::wrappable(this);
+        let window = Window::from_ptr_unchecked(window);
+        let event = KeyEvent::from_ptr_unchecked(event);
+
+        this.0.on_key_event(window, event) as c_int
+    }
+}
+
+impl ViewDelegateWrappable for WindowDelegateWrapper {
+    fn view_delegate(&mut self) -> &mut dyn ViewDelegateCallbacks {
+        self.0.as_mut()
+    }
+}
+
+impl Wrappable for WindowDelegateWrapper {
+    type Cef = cef_window_delegate_t;
+
+    /// Converts this to a smart pointer.
+    fn wrap(self) -> RefCountedPtr<cef_window_delegate_t> {
+        RefCountedPtr::wrap(
+            cef_window_delegate_t {
+                base:                            cef_panel_delegate_t {
+                    base: view_delegate::<Self>()
+                },
+                on_window_created:               Some(Self::c_on_window_created),
+                on_window_closing:               Some(Self::c_on_window_closing),
+                on_window_destroyed:             Some(Self::c_on_window_destroyed),
+                on_window_activation_changed:    Some(Self::c_on_window_activation_changed),
+                on_window_bounds_changed:        Some(Self::c_on_window_bounds_changed),
+                on_window_fullscreen_transition: Some(Self::c_on_window_fullscreen_transition),
+                get_parent_window:               Some(Self::c_get_parent_window),
+                is_window_modal_dialog:          Some(Self::c_is_window_modal_dialog),
+                get_initial_bounds:              Some(Self::c_get_initial_bounds),
+                get_initial_show_state:          Some(Self::c_get_initial_show_state),
+                is_frameless:                    Some(Self::c_is_frameless),
+                with_standard_window_buttons:    Some(Self::c_with_standard_window_buttons),
+                get_titlebar_height:             Some(Self::c_get_titlebar_height),
+                can_resize:                      Some(Self::c_can_resize),
+                can_maximize:                    Some(Self::c_can_maximize),
+                can_minimize:                    Some(Self::c_can_minimize),
+                can_close:                       Some(Self::c_can_close),
+                on_accelerator:                  Some(Self::c_on_accelerator),
+                on_key_event:                    Some(Self::c_on_key_event)
+            },
+            self
+        )
+    }
+}
+
+// A Window is a top-level Window/widget in the Views hierarchy. By default it
+// will have a non-client area with title bar, icon and buttons that supports
+// moving and resizing. All size and position values are in density independent
+// pixels (DIP) unless otherwise indicated. Methods must be called on the
+// browser process UI thread unless otherwise indicated.
+ref_counted_ptr!(Window, cef_window_t);
+
+view_deref!(Window, Panel);
+
+impl Window {
+    /// Create a new Window.
+    pub fn create_top_level(delegate: WindowDelegate) -> Option<Window> {
+        unsafe { Window::from_ptr(cef_window_create_top_level(delegate.into_raw())) }
+    }
+
+    /// Show the Window.
+    pub fn show(&self) -> Result<()> {
+        try_c!(self, show, ThreadId::Ui, { Ok(show(self.as_ptr())) })
+    }
+
+    /// Show the Window as a browser modal dialog relative to |browser_view|. A
+    /// parent Window must be returned via
+    /// cef_window_delegate_t::get_parent_window() and |browser_view| must belong
+    /// to that parent Window. While this Window is visible, |browser_view| will
+    /// be disabled while other controls in the parent Window remain enabled.
+    /// Navigating or destroying the |browser_view| will close this Window
+    /// automatically. Alternately, use show() and return true (1) from
+    /// cef_window_delegate_t::is_window_modal_dialog() for a window modal dialog
+    /// where all controls in the parent Window are disabled.
+    pub fn show_as_browser_modal_dialog(&self, browser_view: BrowserView) -> Result<()> {
+        try_c!(self, show_as_browser_modal_dialog, ThreadId::Ui, {
+            Ok(show_as_browser_modal_dialog(
+                self.as_ptr(),
+                browser_view.into_raw()
+            ))
+        })
+    }
+
+    /// Hide the Window.
+    pub fn hide(&self) -> Result<()> {
+        try_c!(self, hide, ThreadId::Ui, { Ok(hide(self.as_ptr())) })
+    }
+
+    /// Sizes the Window to |size| and centers it in the current display.
+    pub fn center_window(&self, size: &Size) -> Result<()> {
+        try_c!(self, center_window, ThreadId::Ui, {
+            let size: cef_size_t = size.into();
+
+            Ok(center_window(self.as_ptr(), &size))
+        })
+    }
+
+    /// Close the Window.
+    pub fn close(&self) -> Result<()> {
+        try_c!(self, close, ThreadId::Ui, { Ok(close(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if the Window has been closed.
+    pub fn is_closed(&self) -> Result<bool> {
+        try_c!(self, is_closed, ThreadId::Ui, {
+            Ok(is_closed(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Activate the Window, assuming it already exists and is visible.
+    pub fn activate(&self) -> Result<()> {
+        try_c!(self, activate, ThreadId::Ui, {
+            Ok(activate(self.as_ptr()))
+        })
+    }
+
+    /// Deactivate the Window, making the next Window in the Z order the active
+    /// Window.
+    pub fn deactivate(&self) -> Result<()> {
+        try_c!(self, deactivate, ThreadId::Ui, {
+            Ok(deactivate(self.as_ptr()))
+        })
+    }
+
+    /// Returns whether the Window is the currently active Window.
+    pub fn is_active(&self) -> Result<bool> {
+        try_c!(self, is_active, ThreadId::Ui, {
+            Ok(is_active(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Bring this Window to the top of other Windows in the Windowing system.
+    pub fn bring_to_top(&self) -> Result<()> {
+        try_c!(self, bring_to_top, ThreadId::Ui, {
+            Ok(bring_to_top(self.as_ptr()))
+        })
+    }
+
+    /// Set the Window to be on top of other Windows in the Windowing system.
+    pub fn set_always_on_top(&self, on_top: bool) -> Result<()> {
+        try_c!(self, set_always_on_top, ThreadId::Ui, {
+            Ok(set_always_on_top(self.as_ptr(), on_top as c_int))
+        })
+    }
+
+    /// Returns whether the Window has been set to be on top of other Windows in
+    /// the Windowing system.
+    pub fn is_always_on_top(&self) -> Result<bool> {
+        try_c!(self, is_always_on_top, ThreadId::Ui, {
+            Ok(is_always_on_top(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Maximize the Window.
+    pub fn maximize(&self) -> Result<()> {
+        try_c!(self, maximize, ThreadId::Ui, {
+            Ok(maximize(self.as_ptr()))
+        })
+    }
+
+    /// Minimize the Window.
+    pub fn minimize(&self) -> Result<()> {
+        try_c!(self, minimize, ThreadId::Ui, {
+            Ok(minimize(self.as_ptr()))
+        })
+    }
+
+    /// Restore the Window.
+    pub fn restore(&self) -> Result<()> {
+        try_c!(self, restore, ThreadId::Ui, { Ok(restore(self.as_ptr())) })
+    }
+
+    /// Set fullscreen Window state. The
+    /// cef_window_delegate_t::OnWindowFullscreenTransition function will be
+    /// called during the fullscreen transition for notification purposes.
+    pub fn set_fullscreen(&self, fullscreen: bool) -> Result<()> {
+        try_c!(self, set_fullscreen, ThreadId::Ui, {
+            Ok(set_fullscreen(self.as_ptr(), fullscreen as c_int))
+        })
+    }
+
+    /// Returns true (1) if the Window is maximized.
+    pub fn is_maximized(&self) -> Result<bool> {
+        try_c!(self, is_maximized, ThreadId::Ui, {
+            Ok(is_maximized(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if the Window is minimized.
+    pub fn is_minimized(&self) -> Result<bool> {
+        try_c!(self, is_minimized, ThreadId::Ui, {
+            Ok(is_minimized(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Returns true (1) if the Window is fullscreen.
+    pub fn is_fullscreen(&self) -> Result<bool> {
+        try_c!(self, is_fullscreen, ThreadId::Ui, {
+            Ok(is_fullscreen(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Set the Window title.
+    pub fn set_title(&self, title: &str) -> Result<()> {
+        try_c!(self, set_title, ThreadId::Ui, {
+            let title = CefString::new(title);
+
+            Ok(set_title(self.as_ptr(), title.as_ptr()))
+        })
+    }
+
+    /// Get the Window title.
+    pub fn get_title(&self) -> Result<String> {
+        try_c!(self, get_title, ThreadId::Ui, {
+            let s = get_title(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Set the Window icon. This should be a 16x16 icon suitable for use in the
+    /// Windows's title bar.
+    pub fn set_window_icon(&self, image: Image) -> Result<()> {
+        try_c!(self, set_window_icon, ThreadId::Ui, {
+            Ok(set_window_icon(self.as_ptr(), image.into_raw()))
+        })
+    }
+
+    /// Get the Window icon.
+    pub fn get_window_icon(&self) -> Result<Option<Image>> {
+        try_c!(self, get_window_icon, ThreadId::Ui, {
+            Ok(Image::from_ptr(get_window_icon(self.as_ptr())))
+        })
+    }
+
+    /// Set the Window App icon. This should be a larger icon for use in the host
+    /// environment app switching UI. On Windows, this is the ICON_BIG used in
+    /// Alt-Tab list and Windows taskbar. The Window icon will be used by default
+    /// if no Window App icon is specified.
+    pub fn set_window_app_icon(&self, image: Image) -> Result<()> {
+        try_c!(self, set_window_app_icon, ThreadId::Ui, {
+            Ok(set_window_app_icon(self.as_ptr(), image.into_raw()))
+        })
+    }
+
+    /// Get the Window App icon.
+    pub fn get_window_app_icon(&self) -> Result<Option<Image>> {
+        try_c!(self, get_window_app_icon, ThreadId::Ui, {
+            Ok(Image::from_ptr(get_window_app_icon(self.as_ptr())))
+        })
+    }
+
+    /// Add a View that will be overlayed on the Window contents with absolute
+    /// positioning and high z-order. Positioning is controlled by |docking_mode|
+    /// as described below. Setting |can_activate| to true (1) will allow the
+    /// overlay view to receive input focus. The returned cef_overlay_controller_t
+    /// object is used to control the overlay. Overlays are hidden by default.
+    ///
+    /// With CEF_DOCKING_MODE_CUSTOM:
+    ///   1. The overlay is initially hidden, sized to |view|'s preferred size,
+    ///      and positioned in the top-left corner.
+    ///   2. Optionally change the overlay position and/or size by calling
+    ///      cef_overlay_controller_t functions.
+    ///   3. Call cef_overlay_controller_t::set_visible(true) to show the overlay.
+    ///   4. The overlay will be automatically re-sized if |view|'s layout
+    ///      changes. Optionally change the overlay position and/or size when
+    ///      OnLayoutChanged is called on the Window's delegate to indicate a
+    ///      change in Window bounds.
+    ///
+    /// With other docking modes:
+    ///   1. The overlay is initially hidden, sized to |view|'s preferred size,
+    ///      and positioned based on |docking_mode|.
+    ///   2. Call cef_overlay_controller_t::set_visible(true) to show the overlay.
+    ///   3. The overlay will be automatically re-sized if |view|'s layout changes
+    ///      and re-positioned as appropriate when the Window resizes.
+    ///
+    /// Overlays created by this function will receive a higher z-order then any
+    /// child Views added previously. It is therefore recommended to call this
+    /// function last after all other child Views have been added so that the
+    /// overlay displays as the top-most child of the Window.
+    pub fn add_overlay_view(
+        &self,
+        view: View,
+        docking_mode: DockingMode,
+        can_activate: bool
+    ) -> Result<OverlayController> {
+        try_c!(self, add_overlay_view, ThreadId::Ui, {
+            Ok(OverlayController::from_ptr_unchecked(add_overlay_view(
+                self.as_ptr(),
+                view.into_raw(),
+                docking_mode.into(),
+                can_activate as c_int
+            )))
+        })
+    }
+
+    /// Show a menu with contents |menu_model|. |screen_point| specifies the menu
+    /// position in screen coordinates. |anchor_position| specifies how the menu
+    /// will be anchored relative to |screen_point|.
+    pub fn show_menu(
+        &self,
+        menu_model: MenuModel,
+        screen_point: &Point,
+        anchor_position: MenuAnchorPosition
+    ) -> Result<()> {
+        try_c!(self, show_menu, ThreadId::Ui, {
+            let screen_point: cef_point_t = screen_point.into();
+
+            Ok(show_menu(
+                self.as_ptr(),
+                menu_model.into_raw(),
+                &screen_point,
+                anchor_position.into()
+            ))
+        })
+    }
+
+    /// Cancel the menu that is currently showing, if any.
+    pub fn cancel_menu(&self) -> Result<()> {
+        try_c!(self, cancel_menu, ThreadId::Ui, {
+            Ok(cancel_menu(self.as_ptr()))
+        })
+    }
+
+    /// Returns the Display that most closely intersects the bounds of this
+    /// Window. May return NULL if this Window is not currently displayed.
+    pub fn get_display(&self) -> Result<Option<Display>> {
+        try_c!(self, get_display, ThreadId::Ui, {
+            Ok(Display::from_ptr(get_display(self.as_ptr())))
+        })
+    }
+
+    /// Returns the bounds (size and position) of this Window's client area.
+    /// Position is in screen coordinates.
+    pub fn get_client_area_bounds_in_screen(&self) -> Result<Rect> {
+        try_c!(self, get_client_area_bounds_in_screen, ThreadId::Ui, {
+            Ok(get_client_area_bounds_in_screen(self.as_ptr()).into())
+        })
+    }
+
+    // TODO: Fix this!
+
+    // ///
+    // /// Set the regions where mouse events will be intercepted by this Window to
+    // /// support drag operations. Call this function with an NULL vector to clear
+    // /// the draggable regions. The draggable region bounds should be in window
+    // /// coordinates.
+    // ///
+    // void(CEF_CALLBACK* set_draggable_regions)(
+    // struct _cef_window_t* self,
+    // size_t regionsCount,
+    // cef_draggable_region_t const* regions);
+
+    /// Retrieve the platform window handle for this Window.
+    pub fn get_window_handle(&self) -> Result<NativeWindowHandle> {
+        try_c!(self, get_window_handle, ThreadId::Ui, {
+            NativeWindowHandle::try_from(get_window_handle(self.as_ptr()))
+        })
+    }
+
+    /// Simulate a key press. |key_code| is the VKEY_* value from Chromium's
+    /// ui/events/keycodes/keyboard_codes.h header (VK_* values on Windows).
+    /// |event_flags| is some combination of EVENTFLAG_SHIFT_DOWN,
+    /// EVENTFLAG_CONTROL_DOWN and/or EVENTFLAG_ALT_DOWN. This function is exposed
+    /// primarily for testing purposes.
+    pub fn send_key_press(&self, key_code: i32, event_flags: EventFlags) -> Result<()> {
+        try_c!(self, send_key_press, ThreadId::Ui, {
+            Ok(send_key_press(
+                self.as_ptr(),
+                key_code as c_int,
+                event_flags.bits()
+            ))
+        })
+    }
+
+    /// Simulate a mouse move. The mouse cursor will be moved to the specified
+    /// (screen_x, screen_y) position. This function is exposed primarily for
+    /// testing purposes.
+    pub fn send_mouse_move(&self, screen_x: i32, screen_y: i32) -> Result<()> {
+        try_c!(self, send_mouse_move, ThreadId::Ui, {
+            Ok(send_mouse_move(
+                self.as_ptr(),
+                screen_x as c_int,
+                screen_y as c_int
+            ))
+        })
+    }
+
+    /// Simulate mouse down and/or mouse up events. |button| is the mouse button
+    /// type. If |mouse_down| is true (1) a mouse down event will be sent. If
+    /// |mouse_up| is true (1) a mouse up event will be sent. If both are true (1)
+    /// a mouse down event will be sent followed by a mouse up event (equivalent
+    /// to clicking the mouse button). The events will be sent using the current
+    /// cursor position so make sure to call send_mouse_move() first to position
+    /// the mouse. This function is exposed primarily for testing purposes.
+    pub fn send_mouse_events(
+        &self,
+        button: MouseButtonType,
+        mouse_down: bool,
+        mouse_up: bool
+    ) -> Result<()> {
+        try_c!(self, send_mouse_events, ThreadId::Ui, {
+            Ok(send_mouse_events(
+                self.as_ptr(),
+                button.into(),
+                mouse_down as c_int,
+                mouse_up as c_int
+            ))
+        })
+    }
+
+    /// Set the keyboard accelerator for the specified |command_id|. |key_code|
+    /// can be any virtual key or character value. Required modifier keys are
+    /// specified by |shift_pressed|, |ctrl_pressed| and/or |alt_pressed|.
+    /// cef_window_delegate_t::OnAccelerator will be called if the keyboard
+    /// combination is triggered while this window has focus.
+    ///
+    /// The |high_priority| value will be considered if a child cef_browser_view_t
+    /// has focus when the keyboard combination is triggered. If |high_priority|
+    /// is true (1) then the key event will not be forwarded to the web content
+    /// (`keydown` event handler) or cef_keyboard_handler_t first. If
+    /// |high_priority| is false (0) then the behavior will depend on the
+    /// cef_browser_view_t::SetPreferAccelerators configuration.
+    pub fn set_accelerator(
+        &self,
+        command_id: i32,
+        key_code: i32,
+        shift_pressed: bool,
+        ctrl_pressed: bool,
+        alt_pressed: bool,
+        high_priority: bool
+    ) -> Result<()> {
+        try_c!(self, set_accelerator, ThreadId::Ui, {
+            Ok(set_accelerator(
+                self.as_ptr(),
+                command_id as c_int,
+                key_code as c_int,
+                shift_pressed as c_int,
+                ctrl_pressed as c_int,
+                alt_pressed as c_int,
+                high_priority as c_int
+            ))
+        })
+    }
+
+    /// Remove the keyboard accelerator for the specified |command_id|.
+    pub fn remove_accelerator(&self, command_id: i32) -> Result<()> {
+        try_c!(self, remove_accelerator, ThreadId::Ui, {
+            Ok(remove_accelerator(self.as_ptr(), command_id as c_int))
+        })
+    }
+
+    /// Remove all keyboard accelerators.
+    pub fn remove_all_accelerators(&self) -> Result<()> {
+        try_c!(self, remove_all_accelerators, ThreadId::Ui, {
+            Ok(remove_all_accelerators(self.as_ptr()))
+        })
+    }
+}
+
+impl From<Window> for View {
+    fn from(value: Window) -> Self {
+        unsafe { View::from_ptr_unchecked(value.into_raw().cast()) }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{ref_counted_ptr, try_c, CefString, CefTime, StreamReader};
+use anyhow::Result;
+use cef_ui_sys::{cef_zip_reader_create, cef_zip_reader_t};
+use chrono::{DateTime, Utc};
+use std::{
+    ffi::{c_int, c_void},
+    io::{self, Read},
+    ptr::null
+};
+
+// Structure that supports the reading of zip archives via the zlib unzip API.
+// The functions of this structure should only be called on the thread that
+// creates the object.
+//
+// The contents of the currently opened file can be read through the
+// std::io::Read trait.
+ref_counted_ptr!(ZipReader, cef_zip_reader_t);
+
+impl ZipReader {
+    /// Create a new ZipReader object. The returned object's functions can only
+    /// be called from the thread that created the object.
+    pub fn create(stream: StreamReader) -> Option<ZipReader> {
+        unsafe { ZipReader::from_ptr(cef_zip_reader_create(stream.into_raw())) }
+    }
+
+    /// Moves the cursor to the first file in the archive. Returns true (1) if the
+    /// cursor position was set successfully.
+    pub fn move_to_first_file(&self) -> Result<bool> {
+        try_c!(self, move_to_first_file, {
+            Ok(move_to_first_file(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Moves the cursor to the next file in the archive. Returns true (1) if the
+    /// cursor position was set successfully.
+    pub fn move_to_next_file(&self) -> Result<bool> {
+        try_c!(self, move_to_next_file, {
+            Ok(move_to_next_file(self.as_ptr()) != 0)
+        })
+    }
+
+    /// Moves the cursor to the specified file in the archive. If |caseSensitive|
+    /// is true (1) then the search will be case sensitive. Returns true (1) if
+    /// the cursor position was set successfully.
+    pub fn move_to_file(&self, file_name: &str, case_sensitive: bool) -> Result<bool> {
+        try_c!(self, move_to_file, {
+            let file_name = CefString::new(file_name);
+
+            Ok(move_to_file(self.as_ptr(), file_name.as_ptr(), case_sensitive as c_int) != 0)
+        })
+    }
+
+    /// Closes the archive. This should be called directly to ensure that cleanup
+    /// occurs on the correct thread.
+    pub fn close(&self) -> Result<bool> {
+        try_c!(self, close, { Ok(close(self.as_ptr()) != 0) })
+    }
+
+    /// Returns the name of the file.
+    pub fn get_file_name(&self) -> Result<String> {
+        try_c!(self, get_file_name, {
+            let s = get_file_name(self.as_ptr());
+
+            Ok(CefString::from_userfree_ptr_unchecked(s).into())
+        })
+    }
+
+    /// Returns the uncompressed size of the file.
+    pub fn get_file_size(&self) -> Result<i64> {
+        try_c!(self, get_file_size, { Ok(get_file_size(self.as_ptr())) })
+    }
+
+    /// Returns the last modified timestamp for the file.
+    pub fn get_file_last_modified(&self) -> Result<Option<DateTime<Utc>>> {
+        try_c!(self, get_file_last_modified, {
+            let base_time = get_file_last_modified(self.as_ptr());
+
+            Ok(CefTime::try_from(base_time)
+                .ok()
+                .map(CefTime::into))
+        })
+    }
+
+    /// Opens the file for reading of uncompressed data. A read password may
+    /// optionally be specified.
+    pub fn open_file(&self, password: Option<&str>) -> Result<bool> {
+        try_c!(self, open_file, {
+            let password = password.map(CefString::new);
+            let password = password
+                .as_ref()
+                .map(|password| password.as_ptr())
+                .unwrap_or_else(null);
+
+            Ok(open_file(self.as_ptr(), password) != 0)
+        })
+    }
+
+    /// Closes the file.
+    pub fn close_file(&self) -> Result<bool> {
+        try_c!(self, close_file, { Ok(close_file(self.as_ptr()) != 0) })
+    }
+
+    /// Read uncompressed file contents into the specified buffer. Returns < 0 if
+    /// an error occurred, 0 if at the end of file, or the number of bytes read.
+    pub fn read_file(&self, buffer: &mut [u8]) -> Result<i32> {
+        try_c!(self, read_file, {
+            Ok(read_file(
+                self.as_ptr(),
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len()
+            ) as i32)
+        })
+    }
+
+    /// Returns the current offset in the uncompressed file contents.
+    pub fn tell(&self) -> Result<i64> {
+        try_c!(self, tell, { Ok(tell(self.as_ptr())) })
+    }
+
+    /// Returns true (1) if at end of the file contents.
+    pub fn eof(&self) -> Result<bool> {
+        try_c!(self, eof, { Ok(eof(self.as_ptr()) != 0) })
+    }
+}
+
+impl Read for ZipReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.read_file(buf) {
+            Ok(count) if count >= 0 => Ok(count as usize),
+            Ok(_) => Err(io::Error::other("failed to read zip file contents")),
+            Err(e) => Err(io::Error::other(e))
+        }
+    }
+}